@@ -22,7 +22,70 @@ pub fn minecraft_packet_derive(input: TokenStream) -> TokenStream {
             _ => return quote!(compile_error!("Too many lifetimes");).into(),
         };
 
+    // Borrowed fields are taken from the unstructured data, so the generated values live as long as it
+    let arbitrary_lifetime = match &lifetime {
+        Some(lifetime) => lifetime.clone(),
+        None => quote! {'a},
+    };
+
     let name = input.ident;
+    let visibility = input.vis;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    // Packets are stored in an [Owned] along with the bytes they borrow, which requires them to be yokeable.
+    // The lifetime can only be swapped if the type is covariant in it, which the compiler checks in `transform`.
+    let ownable_implementation = match &lifetime {
+        Some(_) => quote! {
+            #[automatically_derived]
+            unsafe impl<'yoke> yoke::Yokeable<'yoke> for #name<'static> {
+                type Output = #name<'yoke>;
+
+                fn transform(&'yoke self) -> &'yoke Self::Output {
+                    self
+                }
+
+                fn transform_owned(self) -> Self::Output {
+                    self
+                }
+
+                unsafe fn make(from: Self::Output) -> Self {
+                    std::mem::transmute::<#name<'yoke>, #name<'static>>(from)
+                }
+
+                fn transform_mut<F>(&'yoke mut self, f: F)
+                where
+                    F: 'static + for<'b> FnOnce(&'b mut Self::Output),
+                {
+                    unsafe { f(std::mem::transmute::<&mut Self, &mut Self::Output>(self)) }
+                }
+            }
+        },
+        None => quote! {
+            #[automatically_derived]
+            unsafe impl<'yoke> yoke::Yokeable<'yoke> for #name {
+                type Output = #name;
+
+                fn transform(&'yoke self) -> &'yoke Self::Output {
+                    self
+                }
+
+                fn transform_owned(self) -> Self::Output {
+                    self
+                }
+
+                unsafe fn make(from: Self::Output) -> Self {
+                    from
+                }
+
+                fn transform_mut<F>(&'yoke mut self, f: F)
+                where
+                    F: 'static + for<'b> FnOnce(&'b mut Self::Output),
+                {
+                    f(self)
+                }
+            }
+        },
+    };
 
     match input.data {
         Data::Struct(data) => match data.fields {
@@ -30,22 +93,42 @@ pub fn minecraft_packet_derive(input: TokenStream) -> TokenStream {
                 let fields = fields.named.into_iter().map(|field| field.ident.unwrap());
                 let fields2 = fields.clone();
                 let fields3 = fields.clone();
+                let fields4 = fields.clone();
+                let fields5 = fields.clone();
+                let field_strings = fields.clone().map(|field| field.to_string());
 
                 quote! {
                     #[automatically_derived]
                     impl#lifetime_impl MinecraftPacketPart#lifetime_impl for #name#lifetime_struct {
-                        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+                        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
                             #(self.#fields.serialize_minecraft_packet_part(output)?;)*
                             Ok(())
                         }
+
+                        fn serialized_size(&self) -> usize {
+                            0 #(+ self.#fields4.serialized_size())*
+                        }
                         
-                        fn deserialize_minecraft_packet_part(input: &#lifetime [u8]) -> Result<(Self, &#lifetime [u8]), &'static str> {
-                            #(let (#fields2, input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)?;)*
+                        fn deserialize_minecraft_packet_part(input: &#lifetime [u8]) -> Result<(Self, &#lifetime [u8]), ParseError> {
+                            #(let (#fields2, input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)
+                                .map_err(|e: ParseError| e.at(input.len()).in_field(#field_strings))?;)*
                             Ok((#name {
                                 #(#fields3,)*
                             }, input))
                         }
                     }
+
+                    #[cfg(feature = "arbitrary")]
+                    #[automatically_derived]
+                    impl#lifetime_impl ::arbitrary::Arbitrary#lifetime_impl for #name#lifetime_struct {
+                        fn arbitrary(u: &mut ::arbitrary::Unstructured<#arbitrary_lifetime>) -> ::arbitrary::Result<Self> {
+                            Ok(#name {
+                                #(#fields5: ::arbitrary::Arbitrary::arbitrary(u)?,)*
+                            })
+                        }
+                    }
+
+                    #ownable_implementation
                 }
             }
             Fields::Unnamed(_) => todo!("unnamed fields"),
@@ -79,10 +162,17 @@ pub fn minecraft_packet_derive(input: TokenStream) -> TokenStream {
                 "The {} ID is outside the definition range.",
                 name.to_string()
             );
+            let name_string = name.to_string();
+
+            let kind_name = format_ident!("{}Kind", name);
 
             // Process variants one by one
             let mut serialization_arms = Vec::new();
+            let mut size_arms = Vec::new();
             let mut deserialization_arms = Vec::new();
+            let mut arbitrary_arms = Vec::new();
+            let mut kind_variants = Vec::new();
+            let mut kind_ids = Vec::new();
             let mut next_discriminant = 0;
             for variant in variants {
                 // Collect variant data
@@ -116,6 +206,8 @@ pub fn minecraft_packet_derive(input: TokenStream) -> TokenStream {
                 ));
                 next_discriminant = discriminant + 1;
                 let variant_name = variant.ident;
+                kind_variants.push(variant_name.clone());
+                kind_ids.push(Lit::Int(LitInt::new(&discriminant.to_string(), Span::call_site().into())));
                 let fields = variant.fields;
                 let fields = match fields {
                     Fields::Named(fields) => fields.named,
@@ -142,19 +234,42 @@ pub fn minecraft_packet_derive(input: TokenStream) -> TokenStream {
                 };
                 serialization_arms.push(serialization_arm);
 
+                // Build a size arm
+                let field_names = fields.iter().map(|field| field.ident.as_ref().unwrap());
+                let field_names2 = fields.iter().map(|field| field.ident.as_ref().unwrap());
+                let tag_size = match varint {
+                    true => quote! { VarInt(#discriminant_lit).serialized_size() },
+                    false => quote! { std::mem::size_of::<#tag_type_ident>() },
+                };
+                size_arms.push(quote! {
+                    #name::#variant_name{#(#field_names2, )*} => #tag_size #(+ #field_names.serialized_size())*,
+                });
+
                 // Build a deserialization arm
                 let field_names = fields.iter().map(|field| field.ident.as_ref().unwrap());
                 let field_names2 = fields.iter().map(|field| field.ident.as_ref().unwrap());
+                let field_strings = fields.iter().map(|field| field.ident.as_ref().unwrap().to_string());
                 let field_types = fields.iter().map(|field| &field.ty);
+                let variant_string = format!("{}::{}", name, variant_name);
+                let discriminant_id = kind_ids.last().unwrap();
                 let deserialization_arm = quote! {
-                    #discriminant_lit => {
-                        #(let (#field_names, input) = <#field_types>::deserialize_minecraft_packet_part(input)?;)*
+                    #discriminant_id => {
+                        #(let (#field_names, input) = <#field_types>::deserialize_minecraft_packet_part(input)
+                            .map_err(|e| e.at(input.len()).in_field(#field_strings).in_field(#variant_string))?;)*
                         Ok((#name::#variant_name {
                             #(#field_names2, )*
                         }, input))
                     },
                 };
                 deserialization_arms.push(deserialization_arm);
+
+                // Build a generation arm
+                let field_names = fields.iter().map(|field| field.ident.as_ref().unwrap());
+                arbitrary_arms.push(quote! {
+                    #kind_name::#variant_name => #name::#variant_name {
+                        #(#field_names: ::arbitrary::Arbitrary::arbitrary(u)?, )*
+                    },
+                });
             }
 
             // Gather serialization arms
@@ -168,17 +283,112 @@ pub fn minecraft_packet_derive(input: TokenStream) -> TokenStream {
             // Gather deserialization arms
             let deserialization_implementation = match varint {
                 true => quote! {
-                    let (id, input) = VarInt::deserialize_minecraft_packet_part(input)?;
-                    match id.0 {
-                        #(#deserialization_arms)*
-                        _ => Err(#unmatched_message),
-                    }
+                    let enum_input = input;
+                    let (id, input) = VarInt::deserialize_minecraft_packet_part(input)
+                        .map_err(|e| e.at(enum_input.len()).in_field(#name_string))?;
+                    Self::deserialize_variant(id.0, input).map_err(|e| e.at(enum_input.len()))
                 },
                 false => quote! {
-                    let (id, input) = #tag_type_ident::deserialize_minecraft_packet_part(input)?;
-                    match id {
-                        #(#deserialization_arms)*
-                        _ => Err(#unmatched_message),
+                    let enum_input = input;
+                    let (id, input) = #tag_type_ident::deserialize_minecraft_packet_part(input)
+                        .map_err(|e| e.at(enum_input.len()).in_field(#name_string))?;
+                    Self::deserialize_variant(id as i32, input).map_err(|e| e.at(enum_input.len()))
+                }
+            };
+            let variant_deserialization_implementation = quote! {
+                match id {
+                    #(#deserialization_arms)*
+                    _ => Err(ParseError::new(#unmatched_message).in_field(#name_string)),
+                }
+            };
+
+            // Build the fieldless kind enum
+            let kind_doc = format!("The variants of [{}], without their fields.", name);
+            let kind_variant_docs = kind_variants.iter().map(|variant| format!("See [{}::{}].", name, variant));
+            let kind_variant_strings = kind_variants.iter().map(|variant| variant.to_string()).collect::<Vec<_>>();
+            let metadata_implementation = quote! {
+                #[doc = #kind_doc]
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+                #visibility enum #kind_name {
+                    #(
+                        #[doc = #kind_variant_docs]
+                        #kind_variants,
+                    )*
+                }
+
+                #[automatically_derived]
+                impl #kind_name {
+                    /// All the variants, in the order of their ids.
+                    pub const ALL: &'static [#kind_name] = &[#(#kind_name::#kind_variants, )*];
+
+                    /// The id written before the fields of this variant.
+                    pub fn packet_id(self) -> i32 {
+                        match self {
+                            #(#kind_name::#kind_variants => #kind_ids,)*
+                        }
+                    }
+
+                    /// The name of this variant.
+                    pub fn name(self) -> &'static str {
+                        match self {
+                            #(#kind_name::#kind_variants => #kind_variant_strings,)*
+                        }
+                    }
+
+                    /// The variant with this id, if any.
+                    pub fn from_packet_id(id: i32) -> Option<#kind_name> {
+                        match id {
+                            #(#kind_ids => Some(#kind_name::#kind_variants),)*
+                            _ => None,
+                        }
+                    }
+                }
+
+                #[automatically_derived]
+                impl #impl_generics #name #type_generics #where_clause {
+                    /// The variant of this value, without its fields.
+                    pub fn kind(&self) -> #kind_name {
+                        match self {
+                            #(#name::#kind_variants { .. } => #kind_name::#kind_variants,)*
+                        }
+                    }
+
+                    /// The id written before the fields of this value.
+                    /// For packets, this is the id of the packet enum, which may differ from the one of the [protocol version](crate::packets::version::ProtocolVersion) in use.
+                    pub fn packet_id(&self) -> i32 {
+                        self.kind().packet_id()
+                    }
+
+                    /// The name of the variant of this value.
+                    pub fn name(&self) -> &'static str {
+                        self.kind().name()
+                    }
+                }
+            };
+
+            // Generate values of a chosen variant, so that property tests can cover every variant
+            let arbitrary_method_lifetime = match &lifetime {
+                Some(_) => None,
+                None => Some(quote! {<'a>}),
+            };
+            let arbitrary_implementation = quote! {
+                #[cfg(feature = "arbitrary")]
+                #[automatically_derived]
+                impl #impl_generics #name #type_generics #where_clause {
+                    /// Generates an arbitrary value of this variant from unstructured data, for property tests and fuzzing.
+                    pub fn arbitrary_variant#arbitrary_method_lifetime(kind: #kind_name, u: &mut ::arbitrary::Unstructured<#arbitrary_lifetime>) -> ::arbitrary::Result<Self> {
+                        Ok(match kind {
+                            #(#arbitrary_arms)*
+                        })
+                    }
+                }
+
+                #[cfg(feature = "arbitrary")]
+                #[automatically_derived]
+                impl#lifetime_impl ::arbitrary::Arbitrary#lifetime_impl for #name#lifetime_struct {
+                    fn arbitrary(u: &mut ::arbitrary::Unstructured<#arbitrary_lifetime>) -> ::arbitrary::Result<Self> {
+                        let kind = *u.choose(#kind_name::ALL)?;
+                        Self::arbitrary_variant(kind, u)
                     }
                 }
             };
@@ -187,14 +397,33 @@ pub fn minecraft_packet_derive(input: TokenStream) -> TokenStream {
             {quote! {
                 #[automatically_derived]
                 impl#lifetime_impl MinecraftPacketPart#lifetime_impl for #name#lifetime_struct {
-                    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+                    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
                         #serialization_implementation
                     }
 
-                    fn deserialize_minecraft_packet_part(input: &#lifetime [u8]) -> Result<(Self, &#lifetime [u8]), &'static str> {
+                    fn serialized_size(&self) -> usize {
+                        match self {
+                            #(#size_arms)*
+                        }
+                    }
+
+                    fn deserialize_minecraft_packet_part(input: &#lifetime [u8]) -> Result<(Self, &#lifetime [u8]), ParseError> {
                         #deserialization_implementation
                     }
                 }
+
+                #[automatically_derived]
+                impl#lifetime_impl MinecraftPacketEnum#lifetime_impl for #name#lifetime_struct {
+                    fn deserialize_variant(id: i32, input: &#lifetime [u8]) -> Result<(Self, &#lifetime [u8]), ParseError> {
+                        #variant_deserialization_implementation
+                    }
+                }
+
+                #metadata_implementation
+
+                #arbitrary_implementation
+
+                #ownable_implementation
             }}.into()
         },
         _ => quote!(compile_error!("Unsupported data structure");).into(),
@@ -221,6 +450,7 @@ pub fn minecraft_enum(attr: TokenStream, input: TokenStream) -> TokenStream {
         "The {} ID is outside the definition range.",
         name.to_string()
     );
+    let name_string = name.to_string();
 
     // Analyse enum variants
     let mut variant_name = Vec::new();
@@ -254,36 +484,57 @@ pub fn minecraft_enum(attr: TokenStream, input: TokenStream) -> TokenStream {
         variant_name.push(variant.ident);
         variant_value.push(discriminant);
     }
+    let variant_index = 0..variant_name.len();
+    let variant_count = variant_name.len();
 
     // Construct the serialize_minecraft_packet_part method
+    // The value is matched instead of casted, so that the enum doesn't need to be Copy
     let append_implementation = match argument_type.as_str() {
         "u8" => quote! {
-            output.push(self as u8);
+            output.push(match self {
+                #(#name::#variant_name => #variant_value,)*
+            });
             Ok(())
         },
         "VarInt" => quote! {
-            VarInt(self as i32).serialize_minecraft_packet_part(output)
+            VarInt(match self {
+                #(#name::#variant_name => #variant_value,)*
+            }).serialize_minecraft_packet_part(output)
+        },
+        _ => quote! {
+            (match self {
+                #(#name::#variant_name => #variant_value,)*
+            }).serialize_minecraft_packet_part(output)
+        },
+    };
+    let size_implementation = match argument_type.as_str() {
+        "VarInt" => quote! {
+            VarInt(match self {
+                #(#name::#variant_name => #variant_value,)*
+            }).serialized_size()
         },
         _ => quote! {
-            (self as #representation_ident).serialize_minecraft_packet_part(output)
+            std::mem::size_of::<#representation_ident>()
         },
     };
 
     // Construct the deserialize_minecraft_packet_part method
     let build_implementation = match argument_type.as_str() {
         "VarInt" => quote! {
+            let enum_input = input;
             let (id, input) = VarInt::deserialize_minecraft_packet_part(input)?;
             let value = match id.0 {
                 #(#variant_value => #name::#variant_name,)*
-                _ => return Err(#unmatched_message),
+                _ => return Err(ParseError::new(#unmatched_message).at(enum_input.len()).in_field(#name_string)),
             };
             Ok((value, input))
         },
         _ => quote! {
+            let enum_input = input;
             let (id, input) = #representation_ident::deserialize_minecraft_packet_part(input)?;
             let value = match id {
                 #(#variant_value => #name::#variant_name,)*
-                _ => return Err(#unmatched_message),
+                _ => return Err(ParseError::new(#unmatched_message).at(enum_input.len()).in_field(#name_string)),
             };
             Ok((value, input))
         },
@@ -296,13 +547,28 @@ pub fn minecraft_enum(attr: TokenStream, input: TokenStream) -> TokenStream {
 
         #[automatically_derived]
         impl<'a> MinecraftPacketPart<'a> for #name {
-            fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+            fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
                 #append_implementation
             }
 
-            fn deserialize_minecraft_packet_part(input: &'a [u8]) -> Result<(Self, &'a [u8]), &'static str> {
+            fn serialized_size(&self) -> usize {
+                #size_implementation
+            }
+
+            fn deserialize_minecraft_packet_part(input: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError> {
                 #build_implementation
             }
         }
+
+        #[cfg(feature = "arbitrary")]
+        #[automatically_derived]
+        impl<'a> ::arbitrary::Arbitrary<'a> for #name {
+            fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+                Ok(match u.choose_index(#variant_count)? {
+                    #(#variant_index => #name::#variant_name,)*
+                    _ => unreachable!(),
+                })
+            }
+        }
     }}.into()
 }
@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use minecraft_protocol::components::chunk::Chunk;
+
+fuzz_target!(|data: &[u8]| {
+    let chunks = match Chunk::from_data(data) {
+        Ok(chunks) => chunks,
+        Err(_) => return,
+    };
+    let serialized = match Chunk::into_data(chunks) {
+        Ok(serialized) => serialized,
+        Err(_) => return,
+    };
+    let reparsed = Chunk::from_data(&serialized).expect("Serialized chunks must be parsable");
+    let reserialized = Chunk::into_data(reparsed).expect("Parsed chunks must be serializable");
+    assert_eq!(reserialized, serialized);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use minecraft_protocol::packets::handshake::ServerboundPacket;
+use minecraft_protocol_fuzz::round_trip;
+
+fuzz_target!(|data: &[u8]| {
+    round_trip::<ServerboundPacket<'static>>(data);
+});
@@ -0,0 +1,20 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use minecraft_protocol::nbt::parse_nbt;
+
+fuzz_target!(|data: &[u8]| {
+    let tag = match parse_nbt(data) {
+        Ok((tag, _)) => tag,
+        Err(_) => return,
+    };
+    let mut serialized = Vec::new();
+    tag.serialize(&mut serialized);
+    let (reparsed, rest) = parse_nbt(&serialized).expect("A serialized tag must be parsable");
+    assert!(rest.is_empty());
+    assert_eq!(tag.serialized_size(), serialized.len());
+
+    let mut reserialized = Vec::new();
+    reparsed.serialize(&mut reserialized);
+    assert_eq!(reserialized, serialized);
+});
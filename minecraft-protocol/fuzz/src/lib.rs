@@ -0,0 +1,37 @@
+//! Checks shared by the fuzz targets.
+//!
+//! Run a target with `cargo fuzz run <target>` from the `minecraft-protocol` directory.
+
+use minecraft_protocol::{packets::version::ProtocolVersion, MinecraftPacketPart, Owned, Yokeable};
+
+/// Parses a packet and checks that it can be serialized and parsed again, for every supported protocol version.
+///
+/// `P` is the `'static` version of the packet type, such as `ClientboundPacket<'static>`.
+pub fn round_trip<P: for<'a> Yokeable<'a>>(data: &[u8])
+where
+    for<'a> <P as Yokeable<'a>>::Output: MinecraftPacketPart<'a>,
+{
+    for version in ProtocolVersion::ALL {
+        version.scope(|| round_trip_in_scope::<P>(data));
+    }
+}
+
+fn round_trip_in_scope<P: for<'a> Yokeable<'a>>(data: &[u8])
+where
+    for<'a> <P as Yokeable<'a>>::Output: MinecraftPacketPart<'a>,
+{
+    let packet = match Owned::<P>::deserialize(data.to_vec()) {
+        Ok(packet) => packet,
+        Err(_) => return,
+    };
+    // Some parsed values cannot be sent, such as lists that are too long for their length prefix
+    let serialized = match packet.get().serialize_minecraft_packet() {
+        Ok(serialized) => serialized,
+        Err(_) => return,
+    };
+    assert_eq!(packet.get().serialized_size(), serialized.len(), "The size of the packet was not computed correctly");
+
+    let reparsed = Owned::<P>::deserialize(serialized.clone()).expect("A serialized packet must be parsable");
+    let reserialized = reparsed.get().serialize_minecraft_packet().expect("A parsed packet must be serializable");
+    assert_eq!(reserialized, serialized);
+}
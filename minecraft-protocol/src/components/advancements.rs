@@ -2,6 +2,7 @@ use crate::*;
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Advancement<'a> {
     /// The identifier of the parent advancement
     pub parent_id: Option<Identifier<'a>>,
@@ -15,6 +16,8 @@ pub struct Advancement<'a> {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AdvancementDisplay<'a> {
     pub title: Chat<'a>,
     pub description: Chat<'a>,
@@ -34,14 +37,25 @@ pub type AdvancementProgress<'a> = Map<'a, Identifier<'a>, CriterionProgress, Va
 pub type CriterionProgress = Option<i64>;
 
 impl<'a> MinecraftPacketPart<'a> for AdvancementDisplay<'a> {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialized_size(&self) -> usize {
+        self.title.serialized_size()
+            + self.description.serialized_size()
+            + self.icon.serialized_size()
+            + self.frame_type.serialized_size()
+            + 4
+            + self.background_texture.map(|background_texture| background_texture.serialized_size()).unwrap_or(0)
+            + 4
+            + 4
+    }
+
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
         self.title.serialize_minecraft_packet_part(output)?;
         self.description.serialize_minecraft_packet_part(output)?;
         self.icon.serialize_minecraft_packet_part(output)?;
         self.frame_type.serialize_minecraft_packet_part(output)?;
-        let flags = (self.background_texture.is_some() as u8)
-            + ((self.show_toast as u8) << 1)
-            + ((self.hidden as u8) << 2);
+        let flags = (self.background_texture.is_some() as i32)
+            + ((self.show_toast as i32) << 1)
+            + ((self.hidden as i32) << 2);
         flags.serialize_minecraft_packet_part(output)?;
         if let Some(background_texture) = self.background_texture {
             background_texture.serialize_minecraft_packet_part(output)?;
@@ -52,7 +66,7 @@ impl<'a> MinecraftPacketPart<'a> for AdvancementDisplay<'a> {
     }
     fn deserialize_minecraft_packet_part(
         input: &'a [u8],
-    ) -> Result<(Self, &'a [u8]), &'static str> {
+    ) -> Result<(Self, &'a [u8]), ParseError> {
         let (title, input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)?;
         let (description, input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)?;
         let (icon, input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)?;
@@ -91,6 +105,7 @@ impl<'a> MinecraftPacketPart<'a> for AdvancementDisplay<'a> {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum AdvancementFrameType {
     Task,
     Challenge,
@@ -100,6 +115,7 @@ pub enum AdvancementFrameType {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum StatisticCategory {
     Mined,
     Crafted,
@@ -115,6 +131,7 @@ pub enum StatisticCategory {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum StatisticId {
     LeaveGame = 0,
     PlayOneMinute,
@@ -193,6 +210,7 @@ pub enum StatisticId {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Statistic {
     pub category: StatisticCategory,
     /// Used when `category` is [StatisticCategory::Custom].
@@ -204,12 +222,18 @@ pub struct Statistic {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AdvancementTabPacket<'a> {
     tab_id: Option<Identifier<'a>>,
 }
 
 impl<'a> MinecraftPacketPart<'a> for AdvancementTabPacket<'a> {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialized_size(&self) -> usize {
+        1 + self.tab_id.map(|tab_id| tab_id.serialized_size()).unwrap_or(0)
+    }
+
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
         self.tab_id
             .is_none()
             .serialize_minecraft_packet_part(output)?;
@@ -220,7 +244,7 @@ impl<'a> MinecraftPacketPart<'a> for AdvancementTabPacket<'a> {
     }
     fn deserialize_minecraft_packet_part(
         input: &'a [u8],
-    ) -> Result<(Self, &'a [u8]), &'static str> {
+    ) -> Result<(Self, &'a [u8]), ParseError> {
         let (present, input) = VarInt::deserialize_minecraft_packet_part(input)?;
         let (tab_id, input) = if present.0 == 0 {
             let (tab_id, input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)?;
@@ -3,6 +3,7 @@ use crate::*;
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(u8)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Animation {
     SwingMainArm = 0,
     TakeDamage, /// Not in the wiki maybe disapeared?
@@ -15,6 +16,7 @@ pub enum Animation {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(i32)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Effect {
     DispenserDispenses = 1000,
     DispenserFailToDispense,
@@ -3,6 +3,7 @@ use crate::*;
 /// One eligible value to insert
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Match<'a> {
     /// The value. Note that for instance this doesn't include a leading / on commands.
     pub value: &'a str,
@@ -2,6 +2,7 @@ use crate::*;
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ChunkBiomeData<'a> {
     /// Chunk coordinate (block coordinate divided by 16, rounded down)
     pub chunk_x: i32, 
@@ -4,6 +4,7 @@ use crate::{*, nbt::NbtTag};
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct BlockEntity {
     /// The packed section coordinates are relative to the chunk they are in values 0-15 are valid.
     /// ```python
@@ -22,6 +23,7 @@ pub struct BlockEntity {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum PartialDiggingState {
     Started,
     Cancelled,
@@ -32,6 +34,7 @@ pub enum PartialDiggingState {
 #[derive(PartialEq)]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum DiggingState {
     Started,
     Cancelled,
@@ -45,6 +48,7 @@ pub enum DiggingState {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(u8)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum BlockFace {
     Bottom,
     Top,
@@ -56,6 +60,7 @@ pub enum BlockFace {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct MultiBlockChange<'a> {
     /// Chunk section coordinate (encoded chunk x and z with each 22 bits, and section y with 20 bits, from left to right).
     ///
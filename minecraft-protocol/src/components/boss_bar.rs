@@ -3,6 +3,7 @@ use crate::*;
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum BossBarAction<'a> {
     Add {
         title: Chat<'a>,
@@ -34,6 +35,7 @@ pub enum BossBarAction<'a> {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Color {
     Pink,
     Blue,
@@ -47,6 +49,7 @@ pub enum Color {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Division {
     NoDivision,
     SixNotches,
@@ -4,6 +4,7 @@ use crate::{*, packets::serializer::BitSet};
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(u8)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Position {
     /// A common chat (chat box)
     Chat,
@@ -17,6 +18,7 @@ pub enum Position {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ChatMode {
     Enabled,
     CommandsOnly,
@@ -26,6 +28,7 @@ pub enum ChatMode {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ChatAction {
     Add,
     Remove,
@@ -33,17 +36,48 @@ pub enum ChatAction {
 }
 
 #[cfg_attr(test, derive(PartialEq))]
-#[derive(Debug, MinecraftPacketPart)]
-pub struct PreviousMessage<'a> {
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PreviousMessage {
     /// The message Id + 1, used for validating message signature. The next field is present only when value of this field is equal to 0.
     pub message_id: VarInt,
-    /// The previous message's signature. Contains the same type of data as Message Signature bytes (256 bytes) above. Not length-prefxied.
-    pub signature: RawBytes<'a>,
+    /// The previous message's signature, when [PreviousMessage::message_id] is 0. Contains the same type of data as Message Signature bytes (256 bytes) above. Not length-prefixed.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::packets::serde_impls::serialize_optional_bytes"))]
+    pub signature: Option<[u8; 256]>,
+}
+
+impl<'a> MinecraftPacketPart<'a> for PreviousMessage {
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        self.message_id.serialize_minecraft_packet_part(output)?;
+        match (self.message_id.0, &self.signature) {
+            (0, Some(signature)) => signature.serialize_minecraft_packet_part(output),
+            (0, None) => Err("The signature of a previous message with id 0 is missing."),
+            (_, None) => Ok(()),
+            (_, Some(_)) => Err("Only previous messages with id 0 have a signature."),
+        }
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.message_id.serialized_size() + self.signature.as_ref().map(|signature| signature.len()).unwrap_or(0)
+    }
+
+    fn deserialize_minecraft_packet_part(input: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError> {
+        let (message_id, input) = VarInt::deserialize_minecraft_packet_part(input)?;
+        let (signature, input) = match message_id.0 {
+            0 => {
+                let (signature, input) = <[u8; 256]>::deserialize_minecraft_packet_part(input)?;
+                (Some(signature), input)
+            }
+            _ => (None, input),
+        };
+        Ok((PreviousMessage { message_id, signature }, input))
+    }
 }
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum FilterType<'a> {
     /// No filters applied
     PassThrough,
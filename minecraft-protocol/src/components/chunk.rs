@@ -5,6 +5,7 @@ use crate::{nbt::NbtTag, *, components::blocks::BlockEntity};
 /// Note that the Notchian client requires an [ClientboundPacket::UpdateViewPosition](crate::packets::play_clientbound::ClientboundPacket::UpdateViewPosition) packet when it crosses a chunk border, otherwise it'll only display `render distance + 2` chunks around the chunk it spawned in.
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(MinecraftPacketPart, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ChunkData<'a> {
     /// Chunk coordinate (block coordinate divided by 16, rounded down).
     pub chunk_x: i32,
@@ -51,6 +52,7 @@ pub struct ChunkData<'a> {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum PalettedData<const LBITS: u8, const HBITS: u8, const DBITS: u8, const TRUNC: usize> {
     Paletted {
         palette: Vec<u32>,
@@ -65,7 +67,7 @@ pub enum PalettedData<const LBITS: u8, const HBITS: u8, const DBITS: u8, const T
 }
 
 impl<'a, const LBITS: u8, const HBITS: u8, const DBITS: u8, const TRUNC: usize> MinecraftPacketPart<'a> for PalettedData<LBITS, HBITS, DBITS, TRUNC> {
-    fn deserialize_minecraft_packet_part(input: &'a [u8]) -> Result<(Self, &'a [u8]), &'static str> {
+    fn deserialize_minecraft_packet_part(input: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError> {
         let (mut bits_per_entry, new_input) = u8::deserialize_minecraft_packet_part(input)?;
 
         Ok(match bits_per_entry {
@@ -73,7 +75,7 @@ impl<'a, const LBITS: u8, const HBITS: u8, const DBITS: u8, const TRUNC: usize>
                 let (value, new_input) = VarInt::deserialize_minecraft_packet_part(new_input)?;
                 let (longs, new_input) = <Array<u64, VarInt>>::deserialize_minecraft_packet_part(new_input)?;
                 if !longs.items.is_empty() {
-                    return Err("non-empty longs array for 0 bits per entry");
+                    return Err("non-empty longs array for 0 bits per entry".into());
                 }
                 (PalettedData::Single { value: value.0 as u32 }, new_input)
             },
@@ -131,22 +133,47 @@ impl<'a, const LBITS: u8, const HBITS: u8, const DBITS: u8, const TRUNC: usize>
         })
     }
 
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialized_size(&self) -> usize {
+        // The entries are packed into longs, prefixed by their number
+        fn longs_size(entries: usize, bits_per_entry: u32) -> usize {
+            let longs = entries.div_ceil((64 / bits_per_entry) as usize);
+            VarInt::from(longs).serialized_size() + longs * 8
+        }
+
+        match self {
+            PalettedData::Single { value } => 1 + VarInt::from(*value as usize).serialized_size() + 1,
+            PalettedData::Paletted { palette, indexed } => {
+                let bits_per_entry = (64 - palette.len().saturating_sub(1).leading_zeros()).max(1);
+                if bits_per_entry > HBITS as u32 {
+                    return 1 + longs_size(indexed.len(), DBITS as u32);
+                }
+                let palette_size = palette.iter().map(|id| VarInt::from(*id as usize).serialized_size()).sum::<usize>();
+                1 + VarInt::from(palette.len()).serialized_size() + palette_size + longs_size(indexed.len(), bits_per_entry.max(LBITS as u32))
+            },
+            PalettedData::Raw { values } => 1 + longs_size(values.len(), DBITS as u32),
+        }
+    }
+
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
         match self {
             PalettedData::Single { value } => {
                 0u8.serialize_minecraft_packet_part(output)?;
-                VarInt::from(value as usize).serialize_minecraft_packet_part(output)?;
+                VarInt::from(*value as usize).serialize_minecraft_packet_part(output)?;
                 VarInt::from(0).serialize_minecraft_packet_part(output)?;
                 Ok(())
             },
             PalettedData::Paletted { palette, indexed } => {
-                let mut bits_per_entry = 64 - (palette.len()-1).leading_zeros();
+                if palette.is_empty() {
+                    return Err("The palette cannot be empty");
+                }
+                // Zero bits per entry would mean a single value, so a palette of one value still uses one bit
+                let mut bits_per_entry = (64 - (palette.len()-1).leading_zeros()).max(1);
 
                 // If the palette is too big, drop it and use raw data
                 if bits_per_entry > HBITS as u32 {
                     let mut values = Vec::new();
                     for index in indexed.iter() {
-                        values.push(palette[*index as usize]);
+                        values.push(*palette.get(*index as usize).ok_or("The index is outside the palette")?);
                     }
                     return PalettedData::<LBITS, HBITS, DBITS, TRUNC>::Raw{values}.serialize_minecraft_packet_part(output);
                 }
@@ -157,7 +184,7 @@ impl<'a, const LBITS: u8, const HBITS: u8, const DBITS: u8, const TRUNC: usize>
                 }
                 let entries_per_long = 64 / bits_per_entry;
 
-                let palette: Vec<VarInt> = palette.into_iter().map(|id| VarInt::from(id as usize)).collect::<Vec<VarInt>>();
+                let palette: Vec<VarInt> = palette.iter().map(|id| VarInt::from(*id as usize)).collect::<Vec<VarInt>>();
                 let palette: Array<VarInt, VarInt> = Array::from(palette);
                 palette.serialize_minecraft_packet_part(output)?;
 
@@ -165,6 +192,9 @@ impl<'a, const LBITS: u8, const HBITS: u8, const DBITS: u8, const TRUNC: usize>
                 for entries in indexed.chunks(entries_per_long as usize) {
                     let mut long = 0;
                     for entry in entries.iter().rev() {
+                        if *entry as usize >= palette.items.len() {
+                            return Err("The index is outside the palette");
+                        }
                         long <<= bits_per_entry;
                         long += *entry as u64;
                     }
@@ -184,6 +214,9 @@ impl<'a, const LBITS: u8, const HBITS: u8, const DBITS: u8, const TRUNC: usize>
                 for entries in values.chunks(entries_per_long as usize) {
                     let mut long = 0;
                     for entry in entries.iter().rev() {
+                        if *entry >> bits_per_entry != 0 {
+                            return Err("The value does not fit in the bits per entry");
+                        }
                         long <<= bits_per_entry;
                         long += *entry as u64;
                     }
@@ -203,6 +236,7 @@ impl<'a, const LBITS: u8, const HBITS: u8, const DBITS: u8, const TRUNC: usize>
 /// However, these are not the smallest unit data is stored in in the game; [chunk columns](ChunkData) are actually 24 [chunk sections](ChunkSection) aligned vertically.
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Chunk {
     pub block_count: i16,
     pub blocks: PalettedData<4, 8, 15, {16*16*16}>,
@@ -210,12 +244,12 @@ pub struct Chunk {
 }
 
 impl Chunk {
-    pub fn from_data(input: &[u8]) -> Result<Vec<Chunk>, &'static str> {
+    pub fn from_data(input: &[u8]) -> Result<Vec<Chunk>, ParseError> {
         let chunk_count = (-64..320).len() / 16;
-        let (chunks, input) = Chunk::deserialize_n(input, chunk_count)?;
+        let (chunks, rest) = Chunk::deserialize_n(input, chunk_count).map_err(|e| e.with_offset(input.len()))?;
 
-        if !input.is_empty() {
-            return Err("trailing data not parsed");
+        if !rest.is_empty() {
+            return Err(ParseError::new("trailing data not parsed").at(rest.len()).with_offset(input.len()));
         }
 
         Ok(chunks)
@@ -243,6 +277,7 @@ fn test() {
 
     let from_minecraft = Chunk::from_data(packet_data).unwrap();
     let reserialized = Chunk::into_data(from_minecraft.clone()).unwrap();
+    assert_eq!(from_minecraft.iter().map(|chunk| chunk.serialized_size()).sum::<usize>(), reserialized.len());
     let redeserialized = Chunk::from_data(&reserialized).unwrap();
     assert_eq!(format!("{from_minecraft:?}"), format!("{redeserialized:?}"));
 }
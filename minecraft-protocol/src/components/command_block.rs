@@ -3,6 +3,7 @@ use crate::*;
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CommandBlockMode {
     Sequence,
     Auto,
@@ -3,6 +3,7 @@ use crate::*;
 /// [Read about effects](https://minecraft.fandom.com/wiki/Effect)
 #[minecraft_enum(VarInt)]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Effect {
     Speed = 1,
     Slowness,
@@ -4,6 +4,7 @@ use crate::{nbt::NbtTag, *};
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct EntityAttribute<'a> {
     pub value: f64,
     pub modifiers: Array<'a, EntityAttributeModifier, VarInt>,
@@ -12,6 +13,7 @@ pub struct EntityAttribute<'a> {
 /// To make the sum of modifiers, apply all modifiers with `operation` [EntityAttributeModifierOperation::Add], then all with [EntityAttributeModifierOperation::AddProportion], and finally all with [EntityAttributeModifierOperation::Multiply].
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct EntityAttributeModifier {
     pub uuid: UUID,
     /// May be positive or negative
@@ -23,6 +25,7 @@ pub struct EntityAttributeModifier {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(u8)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum EntityAttributeModifierOperation {
     /// `value = base_value + modifier`
     Add,
@@ -35,6 +38,7 @@ pub enum EntityAttributeModifierOperation {
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum EntityInteraction {
     Interact {
         hand: super::slots::Hand,
@@ -51,6 +55,7 @@ pub enum EntityInteraction {
 
 #[minecraft_enum(VarInt)]
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum PlayerAction {
     StartSneaking,
     StopSneaking,
@@ -67,6 +72,7 @@ pub enum PlayerAction {
 
 #[minecraft_enum(VarInt)]
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Pose {
     Standing,
     FallFlying,
@@ -87,6 +93,7 @@ pub enum Pose {
 
 #[minecraft_enum(VarInt)]
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum SnifferState {
     Idling,
     FeelingHappy,
@@ -99,13 +106,18 @@ pub enum SnifferState {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EntityMetadata<'a> {
     pub items: BTreeMap<u8, EntityMetadataValue<'a>>,
 }
 
 impl<'a> MinecraftPacketPart<'a> for EntityMetadata<'a> {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
-        for (key, value) in self.items.into_iter() {
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        for (key, value) in self.items.iter() {
+            if *key == 0xff {
+                return Err("The index 0xff marks the end of entity metadata.");
+            }
             key.serialize_minecraft_packet_part(output)?;
             value.serialize_minecraft_packet_part(output)?;
         }
@@ -113,9 +125,13 @@ impl<'a> MinecraftPacketPart<'a> for EntityMetadata<'a> {
         Ok(())
     }
 
+    fn serialized_size(&self) -> usize {
+        self.items.values().map(|value| 1 + value.serialized_size()).sum::<usize>() + 1
+    }
+
     fn deserialize_minecraft_packet_part(
         mut input: &'a [u8],
-    ) -> Result<(Self, &'a [u8]), &'static str> {
+    ) -> Result<(Self, &'a [u8]), ParseError> {
         let mut items = BTreeMap::new();
         loop {
             let (key, new_input) = u8::deserialize_minecraft_packet_part(input)?;
@@ -136,6 +152,7 @@ impl<'a> MinecraftPacketPart<'a> for EntityMetadata<'a> {
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, Clone, MinecraftPacketPart)]
 #[discriminant(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum EntityMetadataValue<'a> {
     Byte {
         value: i8,
@@ -153,10 +170,10 @@ pub enum EntityMetadataValue<'a> {
         value: &'a str,
     },
     Chat {
-        chat: &'a str,
+        chat: Chat<'a>,
     },
     OptionChat {
-        chat: Option<&'a str>,
+        chat: Option<Chat<'a>>,
     },
     Slot {
         slot: super::slots::Slot,
@@ -3,6 +3,7 @@ use crate::*;
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(u8)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum GameState {
     /// Sends "You have no home bed or charged respawn anchor, or it was obstructed" to the client
     NoRespawn,
@@ -23,6 +24,7 @@ pub enum GameState {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ClientStatus {
     /// Sent when the client is ready to complete login and when the client is ready to respawn after death
     PerformRespawn,
@@ -3,6 +3,7 @@ use crate::*;
 #[derive(PartialEq)]
 #[minecraft_enum(u8)]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Gamemode {
     Survival,
     Creative,
@@ -13,6 +14,7 @@ pub enum Gamemode {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(i8)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum PreviousGamemode {
     None = -1,
 
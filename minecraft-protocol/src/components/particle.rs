@@ -3,6 +3,7 @@ use crate::*;
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, Clone, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Particle {
     AmbiantEntityEffect,
     AngryVillager,
@@ -2,12 +2,14 @@ use crate::*;
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PlayersInfos<'a> {
     pub players_infos: Vec<PlayerInfos<'a>>
 }
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PlayerInfos<'a> {
     pub player_uuid: UUID,
     pub actions: Vec<PlayerActions<'a>>,
@@ -17,6 +19,7 @@ pub struct PlayerInfos<'a> {
 #[repr(u8)]
 #[cfg_attr(test, derive(PartialEq))]
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum PlayerActions<'a> {
     AddPlayer(AddPlayersAction<'a>) = 0x01,
     InitializeChat(InitializeChatAction<'a>) = 0x02,
@@ -41,6 +44,7 @@ impl<'a> PlayerActions<'a> {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct AddPlayersAction<'a> {
     name: &'a str,
     properties: Array<'a, Property<'a>, VarInt>,
@@ -48,18 +52,21 @@ pub struct AddPlayersAction<'a> {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct InitializeChatAction<'a> {
     initialize_chat: Option<InitializeChat<'a>>,
 }
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UpdateGamemodesAction {
     gamemode: VarInt,
 }
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UpdateListedAction {
     /// Whether the player should be listed on the player list.
     listed: bool,
@@ -67,6 +74,7 @@ pub struct UpdateListedAction {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PingAction {
     /// Measured in milliseconds
     ping: VarInt,
@@ -74,6 +82,7 @@ pub struct PingAction {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UpdateDisplayNameAction<'a> {
     display_name: Option<Chat<'a>>,
 }
@@ -83,6 +92,7 @@ pub struct UpdateDisplayNameAction<'a> {
 /// An empty properties array is also acceptable, and will cause clients to display the player with one of the two default skins depending on UUID.
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Property<'a> {
     pub name: &'a str,
     pub value: &'a str,
@@ -91,6 +101,7 @@ pub struct Property<'a> {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct InitializeChat<'a> {
     pub session_id: UUID,
     /// Key expiry time, as a UNIX timestamp in milliseconds. 
@@ -103,6 +114,7 @@ pub struct InitializeChat<'a> {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PlayerAdditionInfo<'a> {
     pub uuid: UUID,
     pub name: &'a str,
@@ -119,6 +131,7 @@ pub struct PlayerAdditionInfo<'a> {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum FaceAim {
     Feet,
     Eyes,
@@ -126,6 +139,7 @@ pub enum FaceAim {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FaceTarget {
     pub target_entity_id: VarInt,
     pub target_aim: FaceAim,
@@ -133,6 +147,7 @@ pub struct FaceTarget {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct DeathLocation<'a> {
     pub dimension: Identifier<'a>,
     pub position: Position,
@@ -141,25 +156,47 @@ pub struct DeathLocation<'a> {
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug)]
 #[minecraft_enum(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum MainHand {
     Left,
     Right
 }
 
 impl<'a> MinecraftPacketPart<'a> for PlayersInfos<'a> {
+    fn serialized_size(&self) -> usize {
+        let actions_size = |action: &PlayerActions| match action {
+            PlayerActions::AddPlayer(action) => action.serialized_size(),
+            PlayerActions::InitializeChat(action) => action.serialized_size(),
+            PlayerActions::UpdateGamemodes(action) => action.serialized_size(),
+            PlayerActions::UpdateListed(action) => action.serialized_size(),
+            PlayerActions::Ping(action) => action.serialized_size(),
+            PlayerActions::UpdateDisplayName(action) => action.serialized_size(),
+        };
+        // The mask, the number of players, and the uuid and actions of each player
+        1 + VarInt::from(self.players_infos.len()).serialized_size()
+            + self.players_infos.iter().map(|player_info| 16 + player_info.actions.iter().map(actions_size).sum::<usize>()).sum::<usize>()
+    }
+
     /// Look at this [wiki page](https://wiki.vg/Protocol#Player_Info_Update) for more information about this packet.
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
-        let mask =  {
-            // If no player action is sent, the mask is 0.
-            if self.players_infos.is_empty() {
-                0u8
-            } else {
-                // The mask is the sum of all the player actions. The variants of the player actions are the same for all players.
-                // So we can just take the first player and sum all the variants.
-                let first_player = &self.players_infos[0];
-                first_player.actions.iter().fold(0, |acc, action| acc + action.get_discriminant())
-            }
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        // The mask is the sum of all the player actions. The variants of the player actions must be the same for all players,
+        // and they must be sorted since they are written in the order of the bits.
+        let player_mask = |player_info: &PlayerInfos| {
+            player_info.actions.iter().try_fold(0u8, |acc, action| match action.get_discriminant() > acc {
+                true => Ok(acc + action.get_discriminant()),
+                false => Err("The player actions must be sorted and unique."),
+            })
         };
+        // If no player action is sent, the mask is 0.
+        let mask = match self.players_infos.first() {
+            Some(first_player) => player_mask(first_player)?,
+            None => 0,
+        };
+        for player_info in &self.players_infos {
+            if player_mask(player_info)? != mask {
+                return Err("All the players must have the same actions.");
+            }
+        }
         let n_players = self.players_infos.len();
 
         // Write the mask
@@ -167,13 +204,13 @@ impl<'a> MinecraftPacketPart<'a> for PlayersInfos<'a> {
         // Write the number of players (array size)
         VarInt::from(n_players).serialize_minecraft_packet_part(output)?;
         // Write the length of the following array
-        for player_info in self.players_infos {
+        for player_info in &self.players_infos {
             // Write the uuid of the concerned player
             player_info.player_uuid.serialize_minecraft_packet_part(output)?;
             
             // Write the actions here and not in the PlayerInfos struct because the mask defines the action type
             // As the mask is not a prefix, we can't use the trait to deserialize the actions so we can't implement the trait for PlayerInfos
-            for action in player_info.actions {
+            for action in &player_info.actions {
                 match action {
                     PlayerActions::AddPlayer(action) => action.serialize_minecraft_packet_part(output)?,
                     PlayerActions::InitializeChat(action) => action.serialize_minecraft_packet_part(output)?,
@@ -190,13 +227,14 @@ impl<'a> MinecraftPacketPart<'a> for PlayersInfos<'a> {
 
     /// Look at this [wiki page](https://wiki.vg/Protocol#Player_Info_Update) for more information about this packet.
     fn deserialize_minecraft_packet_part(input: &'a [u8])
-        -> Result<(Self, &'a [u8]), &'static str> {
+        -> Result<(Self, &'a [u8]), ParseError> {
         // The first byte is the mask of actions
-        let (mut mask, input) = u8::deserialize_minecraft_packet_part(input)?;
+        let (mask, input) = u8::deserialize_minecraft_packet_part(input)?;
         // The second byte is the number of players
         let (n_players, mut input) = VarInt::deserialize_minecraft_packet_part(input)?;
         // We will deserialize n_players times the player uuid and actions 
-        let mut players_infos = Vec::with_capacity(n_players.0 as usize);
+        // The count is not trusted, and each player takes at least 16 bytes
+        let mut players_infos = Vec::with_capacity((n_players.0.max(0) as usize).min(input.len() / 16));
         for _ in 0..n_players.0 {
             // The first part of the player infos is the uuid
             let (player_uuid, new_input) = UUID::deserialize_minecraft_packet_part(input)?;
@@ -207,6 +245,7 @@ impl<'a> MinecraftPacketPart<'a> for PlayersInfos<'a> {
             // the least significant bit of the mask corresponds to the first action
             // the most significant bit of the mask corresponds to the last action
             // So we need to iterate over the bits of the mask to know which actions are present
+            let mut mask = mask;
             let mut current_bit = 0;
             while mask > 0 {
                 // If the bit is 1, the action is present
@@ -237,7 +276,7 @@ impl<'a> MinecraftPacketPart<'a> for PlayersInfos<'a> {
                             let (action, input) = UpdateDisplayNameAction::deserialize_minecraft_packet_part(input)?;
                             (PlayerActions::UpdateDisplayName(action), input)
                         },
-                        _ => return Err("Invalid mask"),
+                        _ => return Err("Invalid mask".into()),
                     };
                     input = new_input;
                     actions.push(action);
@@ -3,6 +3,7 @@ use crate::*;
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum UnlockRecipesAction<'a> {
     Init {
         crafting_recipe_book_open: bool,
@@ -48,6 +49,7 @@ pub enum UnlockRecipesAction<'a> {
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum RecipeBook {
     Crafting,
     Furnace,
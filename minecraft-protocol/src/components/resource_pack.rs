@@ -3,9 +3,15 @@ use crate::*;
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ResourcePackStatus {
     Loaded,
     Declined,
     FailedDownload,
     Accepted,
+    /// The following values were added in 1.20.3.
+    Downloaded,
+    InvalidUrl,
+    FailedToReload,
+    Discarded,
 }
@@ -3,6 +3,7 @@ use crate::{nbt::NbtTag, *};
 /// The [Slot] data structure is how Minecraft represents an item and its associated data in the [Minecraft Protocol](https://wiki.vg/Protocol).
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, Clone, Default, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Slot {
     /// `Some(item)` if there is an item in this slot; `None` if it is empty.
     pub item: Option<SlotItem>,
@@ -10,6 +11,7 @@ pub struct Slot {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, Clone, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SlotItem {
     /// The [item](crate::data::items::Item).
     /// Item IDs are distinct from [block IDs](crate::data::blocks::Block); see [crate::data] for more information.
@@ -22,6 +24,7 @@ pub struct SlotItem {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Hand {
     MainHand,
     OffHand,
@@ -30,6 +33,7 @@ pub enum Hand {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum MainHand {
     Left,
     Right,
@@ -37,6 +41,7 @@ pub enum MainHand {
 
 #[minecraft_enum(u8)]
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum EquipmentSlot {
     MainHand,
     OffHand,
@@ -66,16 +71,21 @@ impl std::cmp::Ord for EquipmentSlot {
 use std::collections::BTreeMap;
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EquipmentSlotArray {
     pub slots: BTreeMap<EquipmentSlot, Slot>,
 }
 
 
 impl<'a> MinecraftPacketPart<'a> for EquipmentSlotArray {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
         let len = self.slots.len();
-        for (idx, (slot_index, slot)) in self.slots.into_iter().enumerate() {
-            let mut slot_index = slot_index as u8;
+        if len == 0 {
+            return Err("At least one equipment slot is required.");
+        }
+        for (idx, (slot_index, slot)) in self.slots.iter().enumerate() {
+            let mut slot_index = *slot_index as u8;
             if idx + 1 < len {
                 slot_index += 0b1000_0000;
             }
@@ -85,9 +95,13 @@ impl<'a> MinecraftPacketPart<'a> for EquipmentSlotArray {
         Ok(())
     }
 
+    fn serialized_size(&self) -> usize {
+        self.slots.values().map(|slot| 1 + slot.serialized_size()).sum()
+    }
+
     fn deserialize_minecraft_packet_part(
         mut input: &'a [u8],
-    ) -> Result<(Self, &'a [u8]), &'static str> {
+    ) -> Result<(Self, &'a [u8]), ParseError> {
         let mut slots = BTreeMap::new();
         loop {
             let (number, new_input) = u8::deserialize_minecraft_packet_part(input)?;
@@ -98,7 +112,7 @@ impl<'a> MinecraftPacketPart<'a> for EquipmentSlotArray {
             let slot_index_variant: EquipmentSlot = if slot_index <= 5 {
                 unsafe { std::mem::transmute(slot_index) }
             } else {
-                return Err("The slot index cannot be higher than 5.");
+                return Err("The slot index cannot be higher than 5.".into());
             };
             slots.insert(slot_index_variant, slot);
 
@@ -113,6 +127,7 @@ impl<'a> MinecraftPacketPart<'a> for EquipmentSlotArray {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum WindowType {
     OneRow,
     TwoRows,
@@ -3,6 +3,7 @@ use crate::*;
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum SoundCategory {
     Master,
     Music,
@@ -18,6 +19,8 @@ pub enum SoundCategory {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct StopSoundPacket<'a> {
     /// If not present, then sounds from all sources are cleared
     pub sound_category: Option<SoundCategory>,
@@ -27,26 +30,31 @@ pub struct StopSoundPacket<'a> {
 }
 
 impl<'a> MinecraftPacketPart<'a> for StopSoundPacket<'a> {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
         let mut flags: u8 = 0;
         if self.sound_category.is_some() {
             flags += 0b0000_0001;
         }
-        if self.sound_category.is_some() {
+        if self.sound_effect_name.is_some() {
             flags += 0b0000_0010;
         }
         output.push(flags);
-        if let Some(sound_category) = self.sound_category {
+        if let Some(sound_category) = &self.sound_category {
             sound_category.serialize_minecraft_packet_part(output)?;
         }
-        if let Some(sound_effect_name) = self.sound_effect_name {
+        if let Some(sound_effect_name) = &self.sound_effect_name {
             sound_effect_name.serialize_minecraft_packet_part(output)?;
         }
         Ok(())
     }
+
+    fn serialized_size(&self) -> usize {
+        1 + self.sound_category.as_ref().map(|category| category.serialized_size()).unwrap_or(0)
+            + self.sound_effect_name.as_ref().map(|name| name.serialized_size()).unwrap_or(0)
+    }
     fn deserialize_minecraft_packet_part(
         input: &'a [u8],
-    ) -> Result<(Self, &'a [u8]), &'static str> {
+    ) -> Result<(Self, &'a [u8]), ParseError> {
         let (flags, input) = u8::deserialize_minecraft_packet_part(input)?;
         let (sound_category, input) = match flags & 0b0000_0001 == 1 {
             true => {
@@ -73,3 +81,19 @@ impl<'a> MinecraftPacketPart<'a> for StopSoundPacket<'a> {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stop_sound_flags() {
+        let packet = StopSoundPacket {
+            sound_category: None,
+            sound_effect_name: Some("minecraft:entity.cow.ambient"),
+        };
+        let serialized = packet.serialize_minecraft_packet().unwrap();
+        assert_eq!(serialized[0], 0b0000_0010);
+        assert_eq!(StopSoundPacket::deserialize_uncompressed_minecraft_packet(&serialized).unwrap(), packet);
+    }
+}
@@ -6,6 +6,7 @@ use crate::*;
 /// And a list of all tags is here: https://minecraft.gamepedia.com/Tag#List_of_tags
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Tag<'a> {
     pub tag_name: Identifier<'a>,
     pub data: Array<'a, VarInt, VarInt>,
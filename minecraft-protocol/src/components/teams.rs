@@ -1,40 +1,116 @@
-use crate::*;
+use crate::{nbt::NbtTag, packets::version::{ProtocolVersion, SinceVersion}, *};
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ScoreboardAction<'a> {
     Create {
         text: Chat<'a>,
         scoreboard_type: ScoreboardType,
+        /// The default format of the scores, added in 1.20.3.
+        number_format: SinceVersion<Option<NumberFormat<'a>>, 765>,
     },
     Remove,
     Update {
         text: Chat<'a>,
         scoreboard_type: ScoreboardType,
+        /// The default format of the scores, added in 1.20.3.
+        number_format: SinceVersion<Option<NumberFormat<'a>>, 765>,
     },
 }
 
+/// How a score is displayed, added in 1.20.3.
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
-#[discriminant(u8)]
+#[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum NumberFormat<'a> {
+    /// The score is hidden.
+    Blank,
+    /// The score is displayed with a style, such as `{"color": "red"}`.
+    Styled { styling: NbtTag },
+    /// The score is replaced by a text.
+    Fixed { content: Chat<'a> },
+}
+
+/// Since 1.20.3, the action is not sent anymore and scores are removed with [ResetScore](crate::packets::play_clientbound::ClientboundPacket::ResetScore).
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum ScoreboardScoreAction<'a> {
     /// Update or Create
     Update {
         /// The name of the objective the score belongs to
-        objective_name: Chat<'a>,
+        objective_name: &'a str,
         /// The score to be displayed next to the entry
         value: VarInt,
+        /// Replaces the name of the entity, added in 1.20.3.
+        display_name: SinceVersion<Option<Chat<'a>>, 765>,
+        /// Overrides the format of the objective, added in 1.20.3.
+        number_format: SinceVersion<Option<NumberFormat<'a>>, 765>,
     },
     Remove {
         /// The name of the objective the score belongs to
-        objective_name: Chat<'a>,
+        objective_name: &'a str,
     },
 }
 
+impl<'a> MinecraftPacketPart<'a> for ScoreboardScoreAction<'a> {
+    fn serialized_size(&self) -> usize {
+        let action_size = (ProtocolVersion::current() < ProtocolVersion::V1_20_3) as usize;
+        match self {
+            ScoreboardScoreAction::Update { objective_name, value, display_name, number_format } => {
+                action_size + objective_name.serialized_size() + value.serialized_size() + display_name.serialized_size() + number_format.serialized_size()
+            }
+            ScoreboardScoreAction::Remove { objective_name } => action_size + objective_name.serialized_size(),
+        }
+    }
+
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        let has_action = ProtocolVersion::current() < ProtocolVersion::V1_20_3;
+        match self {
+            ScoreboardScoreAction::Update { objective_name, value, display_name, number_format } => {
+                if has_action {
+                    0u8.serialize_minecraft_packet_part(output)?;
+                }
+                objective_name.serialize_minecraft_packet_part(output)?;
+                value.serialize_minecraft_packet_part(output)?;
+                display_name.serialize_minecraft_packet_part(output)?;
+                number_format.serialize_minecraft_packet_part(output)
+            }
+            ScoreboardScoreAction::Remove { objective_name } if has_action => {
+                1u8.serialize_minecraft_packet_part(output)?;
+                objective_name.serialize_minecraft_packet_part(output)
+            }
+            ScoreboardScoreAction::Remove { .. } => Err("Scores are removed with ResetScore since 1.20.3."),
+        }
+    }
+
+    fn deserialize_minecraft_packet_part(input: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError> {
+        let (action, input) = match ProtocolVersion::current() < ProtocolVersion::V1_20_3 {
+            true => u8::deserialize_minecraft_packet_part(input)?,
+            false => (0, input),
+        };
+        let (objective_name, input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)?;
+        match action {
+            0 => {
+                let (value, input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)?;
+                let (display_name, input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)?;
+                let (number_format, input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)?;
+                Ok((ScoreboardScoreAction::Update { objective_name, value, display_name, number_format }, input))
+            }
+            1 => Ok((ScoreboardScoreAction::Remove { objective_name }, input)),
+            _ => Err("Invalid score action".into()),
+        }
+    }
+}
+
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ScoreboardType {
     Integer,
     Hearts,
@@ -43,6 +119,7 @@ pub enum ScoreboardType {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(u8)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ScoreboardPosition {
     List,
     Sidebar,
@@ -68,6 +145,7 @@ pub enum ScoreboardPosition {
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TeamAction<'a> {
     Create {
         team_display_name: Chat<'a>,
@@ -116,6 +194,7 @@ pub enum TeamAction<'a> {
 #[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TeamColor {
     Black,
     DarkBlue,
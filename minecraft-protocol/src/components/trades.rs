@@ -7,6 +7,7 @@ use crate::*;
 /// `Adjusted price = default price + floor(default price x multiplier x demand) + special price`
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Trade {
     /// The first item the player has to supply for this villager trade.
     /// The count of the item stack is the default "price" of this trade.
@@ -37,6 +38,7 @@ pub struct Trade {
 
 #[minecraft_enum(VarInt)]
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum VillagerType {
     Desert,
     Jungle,
@@ -49,6 +51,7 @@ pub enum VillagerType {
 
 #[minecraft_enum(VarInt)]
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum VillagerProfession {
     None,
     Armorer,
@@ -70,6 +73,7 @@ pub enum VillagerProfession {
 /// Appears on the trade GUI; meaning comes from the translation key `merchant.level.` + level.
 #[minecraft_enum(VarInt)]
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum VillagerLevel {
     Novice = 1,
     Apprentice,
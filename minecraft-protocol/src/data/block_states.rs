@@ -2,6 +2,7 @@ use crate::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BlockWithState(u32);
 
 impl BlockWithState {
@@ -18,12 +19,17 @@ impl BlockWithState {
 
 impl<'a> MinecraftPacketPart<'a> for BlockWithState {
     #[inline]
-    fn serialize_minecraft_packet_part(self, _output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialize_minecraft_packet_part(&self, _output: &mut Vec<u8>) -> Result<(), &'static str> {
         VarInt::from(self.0).serialize_minecraft_packet_part(_output)
     }
 
     #[inline]
-    fn deserialize_minecraft_packet_part(input: &'a[u8]) -> Result<(Self, &'a[u8]), &'static str> {
+    fn serialized_size(&self) -> usize {
+        VarInt::from(self.0).serialized_size()
+    }
+
+    #[inline]
+    fn deserialize_minecraft_packet_part(input: &'a[u8]) -> Result<(Self, &'a[u8]), ParseError> {
         let (id, input) = VarInt::deserialize_minecraft_packet_part(input)?;
         Ok((BlockWithState(id.0 as u32), input))
     }
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use crate::*;
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Block(u32);
 
 impl Block {
@@ -18,14 +19,19 @@ impl Block {
 
 impl<'a> MinecraftPacketPart<'a> for Block {
     #[inline]
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
         VarInt::from(self.0).serialize_minecraft_packet_part(output)
     }
 
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        VarInt::from(self.0).serialized_size()
+    }
+
     #[inline]
     fn deserialize_minecraft_packet_part(
         input: &'a [u8],
-    ) -> Result<(Self, &'a [u8]), &'static str> {
+    ) -> Result<(Self, &'a [u8]), ParseError> {
         let (id, input) = VarInt::deserialize_minecraft_packet_part(input)?;
         Ok((Block(id.0 as u32), input))
     }
@@ -11,7 +11,7 @@
 //!
 //! This crate is low-level since you will have to manage the network and learn about [how the protocol works](https://wiki.vg).
 //! There are WIP helper functions for reading and writing packets over a `TcpStream`.
-//! There is currently no support for encryption and compression.
+//! Compression and encryption are supported; see the [network] module.
 //!
 //! You can serialize and deserialize any struct of this library like this:
 //!
@@ -29,6 +29,9 @@
 //! let parsed_packet = ClientboundPacket::deserialize_minecraft_packet(&raw_packet).unwrap();
 //! ```
 //!
+//! With the `serde` feature, packets, components and NBT values also implement `serde::Serialize`, which is handy to log them as JSON.
+//! With the `arbitrary` feature, they implement `arbitrary::Arbitrary`, and packet enums can generate any chosen variant with `arbitrary_variant`, for property tests and fuzzing.
+//!
 //! # Internal design
 //!
 //! This crate uses procedural macros to generate most of the parsing of composed structs.  
@@ -70,5 +73,6 @@ pub mod nbt;
 pub mod network;
 pub mod packets;
 
-pub use crate::packets::serializer::MinecraftPacketPart;
+pub use crate::packets::{limits::DecodeLimits, owned::Owned, serializer::{MinecraftPacketEnum, MinecraftPacketPart, ParseError}};
+pub use yoke::Yokeable;
 pub(crate) use crate::packets::*;
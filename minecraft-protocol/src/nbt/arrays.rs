@@ -1,7 +1,9 @@
 use super::*;
+use crate::packets::limits::NbtDepthGuard;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum NbtList {
     None,
     Byte(Vec<i8>),
@@ -117,17 +119,31 @@ impl NbtList {
                 output.push(10);
                 output.extend_from_slice(&(list.len() as i32).to_be_bytes());
                 for element in list {
-                    for (name, value) in element.iter() {
-                        value.serialize_type_id(output);
-                        output.extend_from_slice(&(name.len() as u16).to_be_bytes());
-                        output.extend_from_slice(name.as_bytes());
-                        value.serialize_value(output);
-                    }
-                    output.push(0);
+                    serialize_compound(element, output);
                 }
             }
         }
     }
+
+    /// The number of bytes written by [serialize_list](NbtList::serialize_list).
+    pub fn serialized_list_size(&self) -> usize {
+        let elements = match self {
+            NbtList::None => 0,
+            NbtList::Byte(list) => list.len(),
+            NbtList::Short(list) => list.len() * 2,
+            NbtList::Int(list) => list.len() * 4,
+            NbtList::Float(list) => list.len() * 4,
+            NbtList::Long(list) => list.len() * 8,
+            NbtList::Double(list) => list.len() * 8,
+            NbtList::ByteArray(list) => list.iter().map(|inner_list| 4 + inner_list.len()).sum(),
+            NbtList::IntArray(list) => list.iter().map(|inner_list| 4 + inner_list.len() * 4).sum(),
+            NbtList::LongArray(list) => list.iter().map(|inner_list| 4 + inner_list.len() * 8).sum(),
+            NbtList::String(list) => list.iter().map(|string| 2 + string.len()).sum(),
+            NbtList::List(list) => list.iter().map(|inner_list| inner_list.serialized_list_size()).sum(),
+            NbtList::Compound(list) => list.iter().map(serialized_compound_size).sum(),
+        };
+        1 + 4 + elements
+    }
 }
 
 /// A length-prefixed modified UTF-8 string. The prefix is an unsigned short (thus 2 bytes) signifying the length of the string in bytes
@@ -151,6 +167,7 @@ pub fn parse_string(mut input: &[u8]) -> Result<(String, &[u8]), &'static str> {
 /// A list of nameless tags, all of the same type. The list is prefixed with the Type ID of the items it contains (thus 1 byte), and the length of the list as a signed integer (a further 4 bytes).
 #[inline]
 pub fn parse_list(input: &[u8]) -> Result<(NbtList, &[u8]), &'static str> {
+    let _depth = NbtDepthGuard::enter()?;
     if input.len() < 5 {
         return Err("A tag list should contain five bytes.");
     }
@@ -266,7 +283,7 @@ pub fn parse_list(input: &[u8]) -> Result<(NbtList, &[u8]), &'static str> {
         }
         7 => {
             let mut input = &input[5..];
-            let mut list = Vec::with_capacity(len);
+            let mut list = Vec::with_capacity(len.min(input.len()));
             for _ in 0..len {
                 let (result, new_input) =
                     parse_byte_array(input).map_err(|_| "Invalid list item (bytes)")?;
@@ -277,7 +294,7 @@ pub fn parse_list(input: &[u8]) -> Result<(NbtList, &[u8]), &'static str> {
         }
         8 => {
             let mut input = &input[5..];
-            let mut list = Vec::with_capacity(len);
+            let mut list = Vec::with_capacity(len.min(input.len()));
             for _ in 0..len {
                 let (result, new_input) =
                     parse_string(input).map_err(|_| "Invalid list item (string)")?;
@@ -288,10 +305,9 @@ pub fn parse_list(input: &[u8]) -> Result<(NbtList, &[u8]), &'static str> {
         }
         9 => {
             let mut input = &input[5..];
-            let mut list = Vec::with_capacity(len);
+            let mut list = Vec::with_capacity(len.min(input.len()));
             for _ in 0..len {
-                let (result, new_input) =
-                    parse_list(input).map_err(|_| "Invalid list item (list)")?;
+                let (result, new_input) = parse_list(input)?;
                 input = new_input;
                 list.push(result);
             }
@@ -299,10 +315,9 @@ pub fn parse_list(input: &[u8]) -> Result<(NbtList, &[u8]), &'static str> {
         }
         10 => {
             let mut input = &input[5..];
-            let mut list = Vec::with_capacity(len);
+            let mut list = Vec::with_capacity(len.min(input.len()));
             for _ in 0..len {
-                let (result, new_input) =
-                    parse_compound(input).map_err(|_| "Invalid list item (compound)")?;
+                let (result, new_input) = parse_compound(input)?;
                 input = new_input;
                 list.push(result);
             }
@@ -310,7 +325,7 @@ pub fn parse_list(input: &[u8]) -> Result<(NbtList, &[u8]), &'static str> {
         }
         11 => {
             let mut input = &input[5..];
-            let mut list = Vec::with_capacity(len);
+            let mut list = Vec::with_capacity(len.min(input.len()));
             for _ in 0..len {
                 let (result, new_input) =
                     parse_int_array(input).map_err(|_| "Invalid list item (int)")?;
@@ -321,7 +336,7 @@ pub fn parse_list(input: &[u8]) -> Result<(NbtList, &[u8]), &'static str> {
         }
         12 => {
             let mut input = &input[5..];
-            let mut list = Vec::with_capacity(len);
+            let mut list = Vec::with_capacity(len.min(input.len()));
             for _ in 0..len {
                 let (result, new_input) =
                     parse_long_array(input).map_err(|_| "Invalid list item (long)")?;
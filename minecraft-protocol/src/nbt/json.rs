@@ -0,0 +1,152 @@
+//! Conversions between NBT and JSON, used by text components which are sent as NBT since 1.20.3.
+
+use super::{arrays::NbtList, NbtTag};
+use serde_json::{Map, Number, Value};
+use std::{collections::HashMap, convert::TryFrom};
+
+fn number<T: Into<Number>>(value: T) -> Value {
+    Value::Number(value.into())
+}
+
+fn float(value: f64) -> Value {
+    Number::from_f64(value).map(Value::Number).unwrap_or(Value::Null)
+}
+
+fn compound_to_json(compound: &HashMap<String, NbtTag>) -> Value {
+    Value::Object(compound.iter().map(|(key, value)| (key.clone(), value.to_json())).collect())
+}
+
+impl NbtList {
+    pub fn to_json(&self) -> Value {
+        Value::Array(match self {
+            NbtList::None => Vec::new(),
+            NbtList::Byte(values) => values.iter().map(|value| number(*value)).collect(),
+            NbtList::Short(values) => values.iter().map(|value| number(*value)).collect(),
+            NbtList::Int(values) => values.iter().map(|value| number(*value)).collect(),
+            NbtList::Long(values) => values.iter().map(|value| number(*value)).collect(),
+            NbtList::Float(values) => values.iter().map(|value| float(*value as f64)).collect(),
+            NbtList::Double(values) => values.iter().map(|value| float(*value)).collect(),
+            NbtList::ByteArray(arrays) => arrays.iter().map(|array| array.iter().map(|value| number(*value)).collect()).collect(),
+            NbtList::IntArray(arrays) => arrays.iter().map(|array| array.iter().map(|value| number(*value)).collect()).collect(),
+            NbtList::LongArray(arrays) => arrays.iter().map(|array| array.iter().map(|value| number(*value)).collect()).collect(),
+            NbtList::String(values) => values.iter().map(|value| Value::String(value.clone())).collect(),
+            NbtList::List(lists) => lists.iter().map(|list| list.to_json()).collect(),
+            NbtList::Compound(compounds) => compounds.iter().map(compound_to_json).collect(),
+        })
+    }
+}
+
+impl NbtTag {
+    /// Converts the tag to JSON.
+    /// Bytes equal to 0 or 1 become booleans, since NBT has no boolean type.
+    pub fn to_json(&self) -> Value {
+        match self {
+            NbtTag::Null => Value::Null,
+            NbtTag::Byte(value @ (0 | 1)) => Value::Bool(*value == 1),
+            NbtTag::Byte(value) => number(*value),
+            NbtTag::Short(value) => number(*value),
+            NbtTag::Int(value) => number(*value),
+            NbtTag::Long(value) => number(*value),
+            NbtTag::Float(value) => float(*value as f64),
+            NbtTag::Double(value) => float(*value),
+            NbtTag::ByteArray(array) => array.iter().map(|value| number(*value)).collect(),
+            NbtTag::IntArray(array) => array.iter().map(|value| number(*value)).collect(),
+            NbtTag::LongArray(array) => array.iter().map(|value| number(*value)).collect(),
+            NbtTag::String(value) => Value::String(value.clone()),
+            NbtTag::List(list) => list.to_json(),
+            NbtTag::Compound(compound) | NbtTag::RootCompound(_, compound) => compound_to_json(compound),
+        }
+    }
+
+    /// Converts JSON to NBT, the way the Notchian server encodes text components.
+    /// Booleans become bytes, and null values are dropped.
+    /// Lists must contain a single type in NBT, so the items of mixed arrays are wrapped in compounds, as `{"text": item}` for strings and `{"": item}` otherwise.
+    pub fn from_json(value: &Value) -> NbtTag {
+        match value {
+            Value::Null => NbtTag::Null,
+            Value::Bool(value) => NbtTag::Byte(*value as i8),
+            Value::Number(value) => match (value.as_i64(), value.as_f64()) {
+                (Some(value), _) if i32::try_from(value).is_ok() => NbtTag::Int(value as i32),
+                (Some(value), _) => NbtTag::Long(value),
+                (None, Some(value)) => NbtTag::Double(value),
+                (None, None) => NbtTag::Null,
+            },
+            Value::String(value) => NbtTag::String(value.clone()),
+            Value::Array(items) => NbtTag::List(list_from_json(items)),
+            Value::Object(object) => NbtTag::Compound(compound_from_json(object)),
+        }
+    }
+}
+
+fn compound_from_json(object: &Map<String, Value>) -> HashMap<String, NbtTag> {
+    object
+        .iter()
+        .filter(|(_, value)| !value.is_null())
+        .map(|(key, value)| (key.clone(), NbtTag::from_json(value)))
+        .collect()
+}
+
+fn list_from_json(items: &[Value]) -> NbtList {
+    let items: Vec<NbtTag> = items.iter().filter(|item| !item.is_null()).map(NbtTag::from_json).collect();
+    let first_type = match items.first() {
+        Some(item) => std::mem::discriminant(item),
+        None => return NbtList::None,
+    };
+
+    if items.iter().all(|item| std::mem::discriminant(item) == first_type) {
+        macro_rules! collect {
+            ($variant:ident) => {
+                NbtList::$variant(
+                    items
+                        .into_iter()
+                        .filter_map(|item| match item {
+                            NbtTag::$variant(value) => Some(value),
+                            _ => None,
+                        })
+                        .collect(),
+                )
+            };
+        }
+        match items[0] {
+            NbtTag::Byte(_) => return collect!(Byte),
+            NbtTag::Int(_) => return collect!(Int),
+            NbtTag::Long(_) => return collect!(Long),
+            NbtTag::Double(_) => return collect!(Double),
+            NbtTag::String(_) => return collect!(String),
+            NbtTag::List(_) => return collect!(List),
+            NbtTag::Compound(_) => return collect!(Compound),
+            _ => (),
+        }
+    }
+
+    NbtList::Compound(
+        items
+            .into_iter()
+            .map(|item| match item {
+                NbtTag::Compound(compound) => compound,
+                NbtTag::String(text) => HashMap::from([("text".to_string(), NbtTag::String(text))]),
+                item => HashMap::from([(String::new(), item)]),
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn text_component_round_trip() {
+        let component = json!({
+            "text": "",
+            "bold": true,
+            "extra": [{"text": "A ", "color": "gold"}, {"translate": "chat.type.text", "with": ["Notch", "hello"]}],
+        });
+        assert_eq!(NbtTag::from_json(&component).to_json(), component);
+
+        let mixed = json!({"text": "", "extra": ["plain ", {"text": "gold", "color": "gold"}]});
+        let expected = json!({"text": "", "extra": [{"text": "plain "}, {"text": "gold", "color": "gold"}]});
+        assert_eq!(NbtTag::from_json(&mixed).to_json(), expected);
+    }
+}
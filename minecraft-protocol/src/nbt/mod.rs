@@ -1,6 +1,8 @@
+use crate::DecodeLimits;
 use std::collections::HashMap;
 pub mod arrays;
 pub mod compound;
+pub mod json;
 pub mod numbers;
 pub mod serializer;
 use arrays::*;
@@ -8,6 +10,7 @@ use compound::*;
 use numbers::*;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum NbtTag {
     Null,
     Byte(i8),
@@ -363,24 +366,12 @@ impl NbtTag {
                 list.serialize_list(output);
             }
             NbtTag::Compound(compound) => {
-                for (name, value) in compound.iter() {
-                    value.serialize_type_id(output);
-                    output.extend_from_slice(&(name.len() as u16).to_be_bytes());
-                    output.extend_from_slice(name.as_bytes());
-                    value.serialize_value(output);
-                }
-                output.push(0);
+                serialize_compound(compound, output);
             }
             NbtTag::RootCompound(name, compound) => {
                 output.extend_from_slice(&(name.len() as u16).to_be_bytes());
                 output.extend_from_slice(name.as_bytes());
-                for (name, value) in compound.iter() {
-                    value.serialize_type_id(output);
-                    output.extend_from_slice(&(name.len() as u16).to_be_bytes());
-                    output.extend_from_slice(name.as_bytes());
-                    value.serialize_value(output);
-                }
-                output.push(0);
+                serialize_compound(compound, output);
             }
         }
     }
@@ -389,21 +380,71 @@ impl NbtTag {
         self.serialize_type_id(output);
         self.serialize_value(output);
     }
+
+    /// The number of bytes written by [serialize_value](NbtTag::serialize_value).
+    pub fn serialized_value_size(&self) -> usize {
+        match self {
+            NbtTag::Null => 0,
+            NbtTag::Byte(_) => 1,
+            NbtTag::Short(_) => 2,
+            NbtTag::Int(_) | NbtTag::Float(_) => 4,
+            NbtTag::Long(_) | NbtTag::Double(_) => 8,
+            NbtTag::ByteArray(array) => 4 + array.len(),
+            NbtTag::IntArray(array) => 4 + array.len() * 4,
+            NbtTag::LongArray(array) => 4 + array.len() * 8,
+            NbtTag::String(string) => 2 + string.len(),
+            NbtTag::List(list) => list.serialized_list_size(),
+            NbtTag::Compound(compound) => serialized_compound_size(compound),
+            NbtTag::RootCompound(name, compound) => 2 + name.len() + serialized_compound_size(compound),
+        }
+    }
+
+    /// The number of bytes written by [serialize](NbtTag::serialize).
+    pub fn serialized_size(&self) -> usize {
+        1 + self.serialized_value_size()
+    }
+}
+
+/// Writes the named tags of a compound and its end tag.
+///
+/// Tags are written in the order of their names so that equal compounds are always serialized to the same bytes.
+fn serialize_compound(compound: &HashMap<String, NbtTag>, output: &mut Vec<u8>) {
+    let mut tags: Vec<(&String, &NbtTag)> = compound.iter().collect();
+    tags.sort_unstable_by_key(|(name, _)| *name);
+    for (name, value) in tags {
+        value.serialize_type_id(output);
+        output.extend_from_slice(&(name.len() as u16).to_be_bytes());
+        output.extend_from_slice(name.as_bytes());
+        value.serialize_value(output);
+    }
+    output.push(0);
+}
+
+/// The size of the named tags of a compound and of its end tag.
+fn serialized_compound_size(compound: &HashMap<String, NbtTag>) -> usize {
+    compound.iter().map(|(name, value)| 1 + 2 + name.len() + value.serialized_value_size()).sum::<usize>() + 1
 }
 
 /// NBT on the network doesn't use root compounds
+///
+/// Nesting is bounded by the current [DecodeLimits], or by the default limits outside of a scope.
 pub fn parse_network_nbt(input: &[u8]) -> Result<(NbtTag, &[u8]), &'static str> {
-    let tag_id = *input.first().ok_or("Empty input, no NBT data.")?;
-    parse_nbt_tag(&input[1..], tag_id)
+    DecodeLimits::scope_or_default(|| {
+        let tag_id = *input.first().ok_or("Empty input, no NBT data.")?;
+        parse_nbt_tag(&input[1..], tag_id)
+    })
 }
 
+/// Nesting is bounded by the current [DecodeLimits], or by the default limits outside of a scope.
 pub fn parse_nbt(input: &[u8]) -> Result<(NbtTag, &[u8]), &'static str> {
-    let tag_id = *input.first().ok_or("Empty input, no NBT data.")?;
-    if tag_id == 10 {
-        let ((name, content), input) = parse_root_compound(input)?;
-        return Ok((NbtTag::RootCompound(name, content), input));
-    }
-    parse_nbt_tag(&input[1..], tag_id)
+    DecodeLimits::scope_or_default(|| {
+        let tag_id = *input.first().ok_or("Empty input, no NBT data.")?;
+        if tag_id == 10 {
+            let ((name, content), input) = parse_root_compound(input)?;
+            return Ok((NbtTag::RootCompound(name, content), input));
+        }
+        parse_nbt_tag(&input[1..], tag_id)
+    })
 }
 
 #[cfg(test)]
@@ -416,13 +457,18 @@ mod tests {
         let parsed = parse_nbt(&original).unwrap().0;
         let mut serialized = Vec::new();
         parsed.serialize(&mut serialized);
+        assert_eq!(parsed.serialized_size(), serialized.len());
         let parsed2 = parse_nbt(&serialized).unwrap().0;
         assert_eq!(parsed, parsed2);
+        let mut reserialized = Vec::new();
+        parsed2.serialize(&mut reserialized);
+        assert_eq!(reserialized, serialized);
 
         let original = include_bytes!("test_data/hello_world.nbt").to_vec();
         let parsed = parse_nbt(&original).unwrap().0;
         let mut serialized = Vec::new();
         parsed.serialize(&mut serialized);
+        assert_eq!(parsed.serialized_size(), serialized.len());
         let parsed2 = parse_nbt(&serialized).unwrap().0;
         assert_eq!(parsed, parsed2);
 
@@ -430,6 +476,7 @@ mod tests {
         let parsed = parse_nbt(&original).unwrap().0;
         let mut serialized = Vec::new();
         parsed.serialize(&mut serialized);
+        assert_eq!(parsed.serialized_size(), serialized.len());
         let parsed2 = parse_nbt(&serialized).unwrap().0;
         assert_eq!(parsed, parsed2);
 
@@ -437,7 +484,34 @@ mod tests {
         let parsed = parse_nbt(&original).unwrap().0;
         let mut serialized = Vec::new();
         parsed.serialize(&mut serialized);
+        assert_eq!(parsed.serialized_size(), serialized.len());
         let parsed2 = parse_nbt(&serialized).unwrap().0;
         assert_eq!(parsed, parsed2)
     }
+
+    fn nested_lists(depth: usize) -> Vec<u8> {
+        let mut data = vec![9];
+        for _ in 0..depth {
+            data.extend_from_slice(&[9, 0, 0, 0, 1]);
+        }
+        data.extend_from_slice(&[0, 0, 0, 0, 0]);
+        data
+    }
+
+    #[test]
+    fn test_nbt_depth() {
+        // Unoptimized builds use several kilobytes of stack per level, more than test threads have at the default depth
+        let thread = std::thread::Builder::new().stack_size(16 * 1024 * 1024).spawn(|| {
+            let data = nested_lists(100_000);
+            assert_eq!(parse_nbt(&data).unwrap_err(), "NBT data is nested deeper than the maximum NBT depth.");
+            assert_eq!(parse_network_nbt(&data).unwrap_err(), "NBT data is nested deeper than the maximum NBT depth.");
+        });
+        thread.unwrap().join().unwrap();
+
+        // The limits of the current scope apply
+        let data = nested_lists(10);
+        assert!(parse_nbt(&data).is_ok());
+        let limits = DecodeLimits { max_nbt_depth: 5, ..Default::default() };
+        assert_eq!(limits.scope(|| parse_nbt(&data)).unwrap_err(), "NBT data is nested deeper than the maximum NBT depth.");
+    }
 }
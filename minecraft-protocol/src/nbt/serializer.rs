@@ -2,14 +2,30 @@ use super::NbtTag;
 use crate::*;
 
 impl<'a> MinecraftPacketPart<'a> for NbtTag {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialized_size(&self) -> usize {
+        NbtTag::serialized_size(self)
+    }
+
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
         self.serialize(output);
         Ok(())
     }
 
     fn deserialize_minecraft_packet_part(
         input: &'a [u8],
-    ) -> Result<(Self, &'a [u8]), &'static str> {
-        super::parse_network_nbt(input)
+    ) -> Result<(Self, &'a [u8]), ParseError> {
+        // Parsing is bounded by the remaining NBT size, so that forged lengths cannot allocate more
+        let limit = DecodeLimits::nbt_bytes_left();
+        let bounded_input = &input[..input.len().min(limit)];
+        let (tag, rest) = match super::parse_network_nbt(bounded_input) {
+            Ok(result) => result,
+            Err(_) if bounded_input.len() < input.len() => {
+                return Err(ParseError::new("NBT data is larger than the maximum NBT size.").at(input.len()))
+            }
+            Err(e) => return Err(ParseError::new(e).at(input.len())),
+        };
+        let consumed = bounded_input.len() - rest.len();
+        DecodeLimits::consume_nbt_bytes(consumed);
+        Ok((tag, &input[consumed..]))
     }
 }
@@ -0,0 +1,361 @@
+//! Online mode authentication: the RSA key exchange and the session server.
+//!
+//! The server sends its public key in an [EncryptionRequest](login::ClientboundPacket::EncryptionRequest).
+//! The client generates a shared secret, tells the session server it is joining (see [SessionClient::join]), and sends the secret encrypted with the public key in an [EncryptionResponse](login::ServerboundPacket::EncryptionResponse).
+//! The server decrypts it and asks the session server whether the player joined (see [SessionClient::has_joined]).
+//! Both sides identify the exchange with [server_hash].
+//! See [Protocol Encryption](https://wiki.vg/Protocol_Encryption) for details.
+
+use super::encryption::SHARED_SECRET_LENGTH;
+use crate::packets::{
+    login::{self, GameProfile, ProfileProperty},
+    UUIDExt, UUID,
+};
+use rsa::{
+    pkcs8::{DecodePublicKey, EncodePublicKey},
+    rand_core::{OsRng, RngCore},
+    Pkcs1v15Encrypt, RsaPrivateKey, RsaPublicKey,
+};
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::convert::TryInto;
+
+/// The session server used by the Notchian server and client.
+pub const MOJANG_SESSION_SERVER: &str = "https://sessionserver.mojang.com/session/minecraft";
+
+/// The size of the RSA key used by the Notchian server.
+pub const KEY_SIZE: usize = 1024;
+
+#[derive(Debug)]
+pub enum AuthError {
+    Custom(&'static str),
+    Rsa(rsa::Error),
+    Http(Box<dyn std::error::Error + Send + Sync>),
+    /// The session server answered with an unexpected status code.
+    Status(u16),
+    Json(serde_json::Error),
+}
+
+impl std::fmt::Display for AuthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthError::Custom(e) => write!(f, "{}", e),
+            AuthError::Rsa(e) => write!(f, "{}", e),
+            AuthError::Http(e) => write!(f, "{}", e),
+            AuthError::Status(status) => write!(f, "Unexpected status code from the session server: {}", status),
+            AuthError::Json(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for AuthError {}
+
+impl From<&'static str> for AuthError {
+    fn from(e: &'static str) -> Self {
+        AuthError::Custom(e)
+    }
+}
+
+impl From<rsa::Error> for AuthError {
+    fn from(e: rsa::Error) -> Self {
+        AuthError::Rsa(e)
+    }
+}
+
+impl From<serde_json::Error> for AuthError {
+    fn from(e: serde_json::Error) -> Self {
+        AuthError::Json(e)
+    }
+}
+
+/// The keypair of a server, used to receive the shared secret.
+#[derive(Debug, Clone)]
+pub struct ServerKey {
+    private_key: RsaPrivateKey,
+    public_key_der: Vec<u8>,
+}
+
+impl ServerKey {
+    /// Generates a 1024-bit keypair, as the Notchian server does once at startup.
+    pub fn generate() -> Result<ServerKey, AuthError> {
+        ServerKey::from_private_key(RsaPrivateKey::new(&mut OsRng, KEY_SIZE)?)
+    }
+
+    pub fn from_private_key(private_key: RsaPrivateKey) -> Result<ServerKey, AuthError> {
+        let public_key_der = RsaPublicKey::from(&private_key)
+            .to_public_key_der()
+            .map_err(|_| "Failed to encode the public key")?
+            .into_vec();
+        Ok(ServerKey {
+            private_key,
+            public_key_der,
+        })
+    }
+
+    /// The public key in the X.509 SubjectPublicKeyInfo DER format, as sent in the [EncryptionRequest](login::ClientboundPacket::EncryptionRequest).
+    pub fn public_key_der(&self) -> &[u8] {
+        &self.public_key_der
+    }
+
+    /// Decrypts data encrypted by the client with PKCS#1 v1.5 padding.
+    pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, AuthError> {
+        Ok(self.private_key.decrypt(Pkcs1v15Encrypt, data)?)
+    }
+
+    /// Builds the packet asking the client to enable encryption.
+    /// The server id is empty since 1.7.
+    pub fn encryption_request<'a>(&'a self, verify_token: &[u8]) -> login::ClientboundPacket<'a> {
+        login::ClientboundPacket::EncryptionRequest {
+            server_id: "",
+            public_key: self.public_key_der.clone().into(),
+            verify_token: verify_token.to_vec().into(),
+        }
+    }
+
+    /// Decrypts the fields of an [EncryptionResponse](login::ServerboundPacket::EncryptionResponse), checks the verify token and returns the shared secret.
+    pub fn verify_response(
+        &self,
+        verify_token: &[u8],
+        encrypted_shared_secret: &[u8],
+        encrypted_verify_token: &[u8],
+    ) -> Result<[u8; SHARED_SECRET_LENGTH], AuthError> {
+        if self.decrypt(encrypted_verify_token)? != verify_token {
+            return Err("The verify token does not match".into());
+        }
+        let shared_secret = self.decrypt(encrypted_shared_secret)?;
+        Ok(shared_secret
+            .as_slice()
+            .try_into()
+            .map_err(|_| "The shared secret should be 16 bytes long")?)
+    }
+}
+
+/// Generates the random token the client must send back encrypted.
+pub fn generate_verify_token() -> [u8; 4] {
+    let mut token = [0; 4];
+    OsRng.fill_bytes(&mut token);
+    token
+}
+
+/// Generates the shared secret on the client side.
+pub fn generate_shared_secret() -> [u8; SHARED_SECRET_LENGTH] {
+    let mut shared_secret = [0; SHARED_SECRET_LENGTH];
+    OsRng.fill_bytes(&mut shared_secret);
+    shared_secret
+}
+
+/// Encrypts data with the public key of the server, on the client side.
+pub fn encrypt_with_public_key(public_key_der: &[u8], data: &[u8]) -> Result<Vec<u8>, AuthError> {
+    let public_key = RsaPublicKey::from_public_key_der(public_key_der).map_err(|_| "Invalid public key")?;
+    Ok(public_key.encrypt(&mut OsRng, Pkcs1v15Encrypt, data)?)
+}
+
+/// Minecraft's hex digest of a SHA-1 hash: the hash is read as a signed two's complement big integer and printed in hexadecimal, without leading zeros.
+pub fn minecraft_hex_digest(hash: [u8; 20]) -> String {
+    let negative = hash[0] & 0x80 != 0;
+    let mut hash = hash;
+    if negative {
+        // Two's complement negation
+        let mut carry = true;
+        for byte in hash.iter_mut().rev() {
+            let (value, overflow) = (!*byte).overflowing_add(carry as u8);
+            *byte = value;
+            carry = overflow;
+        }
+    }
+    let digits: String = hash.iter().map(|byte| format!("{:02x}", byte)).collect();
+    let digits = digits.trim_start_matches('0');
+    match (negative, digits.is_empty()) {
+        (_, true) => "0".to_string(),
+        (true, false) => format!("-{}", digits),
+        (false, false) => digits.to_string(),
+    }
+}
+
+/// The server id sent to the session server by both sides.
+pub fn server_hash(server_id: &str, shared_secret: &[u8], public_key_der: &[u8]) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(server_id.as_bytes());
+    hasher.update(shared_secret);
+    hasher.update(public_key_der);
+    minecraft_hex_digest(hasher.finalize().into())
+}
+
+/// The response of an [HttpClient].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Performs the requests of a [SessionClient], so that any HTTP library can be used.
+pub trait HttpClient {
+    fn get(&self, url: &str) -> Result<HttpResponse, Box<dyn std::error::Error + Send + Sync>>;
+    fn post_json(&self, url: &str, body: &str) -> Result<HttpResponse, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+#[derive(Deserialize)]
+struct SessionProfile {
+    id: String,
+    name: String,
+    #[serde(default)]
+    properties: Vec<ProfileProperty>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JoinRequest<'a> {
+    access_token: &'a str,
+    selected_profile: String,
+    server_id: &'a str,
+}
+
+/// Percent-encodes a query parameter.
+fn encode_query(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// A client of the session server.
+#[derive(Debug, Clone)]
+pub struct SessionClient<H> {
+    http: H,
+    base_url: String,
+}
+
+impl<H: HttpClient> SessionClient<H> {
+    /// Uses the session server of Mojang.
+    pub fn new(http: H) -> Self {
+        SessionClient::with_base_url(http, MOJANG_SESSION_SERVER)
+    }
+
+    /// Uses another session server, such as a local stand-in.
+    pub fn with_base_url(http: H, base_url: impl Into<String>) -> Self {
+        SessionClient {
+            http,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Checks on the server side that the player has joined with this server hash.
+    /// Returns `None` if the player is not authenticated.
+    /// The ip of the client can be checked too, if the server is configured to prevent proxy connections.
+    pub fn has_joined(&self, username: &str, server_hash: &str, ip: Option<&str>) -> Result<Option<GameProfile>, AuthError> {
+        let mut url = format!(
+            "{}/hasJoined?username={}&serverId={}",
+            self.base_url,
+            encode_query(username),
+            encode_query(server_hash)
+        );
+        if let Some(ip) = ip {
+            url.push_str("&ip=");
+            url.push_str(&encode_query(ip));
+        }
+
+        let response = self.http.get(&url).map_err(AuthError::Http)?;
+        match response.status {
+            200 => {
+                let profile: SessionProfile = serde_json::from_str(&response.body)?;
+                Ok(Some(GameProfile {
+                    uuid: UUID::parse_str(&profile.id).ok_or("Invalid UUID in the session server response")?,
+                    username: profile.name,
+                    properties: profile.properties,
+                }))
+            }
+            204 => Ok(None),
+            status => Err(AuthError::Status(status)),
+        }
+    }
+
+    /// Tells the session server on the client side that the player is joining the server identified by this hash.
+    pub fn join(&self, access_token: &str, profile_uuid: UUID, server_hash: &str) -> Result<(), AuthError> {
+        let body = serde_json::to_string(&JoinRequest {
+            access_token,
+            selected_profile: profile_uuid.to_simple_string(),
+            server_id: server_hash,
+        })?;
+        let response = self.http.post_json(&format!("{}/join", self.base_url), &body).map_err(AuthError::Http)?;
+        match response.status {
+            200 | 204 => Ok(()),
+            status => Err(AuthError::Status(status)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, collections::HashMap};
+
+    #[test]
+    fn hex_digest() {
+        let digest = |name: &str| minecraft_hex_digest(Sha1::digest(name.as_bytes()).into());
+        assert_eq!(digest("Notch"), "4ed1f46bbe04bc756bcb17c0c7ce3e4632f06a48");
+        assert_eq!(digest("jeb_"), "-7c9d5b0044c130109a5d7b5fb5c317c02b4e28c1");
+        assert_eq!(digest("simon"), "88e16a1019277b15d58faf0541e11910eb756f6");
+    }
+
+    /// A session server that remembers who joined.
+    #[derive(Default)]
+    struct LocalSessionServer {
+        joined: RefCell<HashMap<String, String>>,
+    }
+
+    impl HttpClient for &LocalSessionServer {
+        fn get(&self, url: &str) -> Result<HttpResponse, Box<dyn std::error::Error + Send + Sync>> {
+            let query = url.strip_prefix("http://localhost/hasJoined?username=").ok_or("Unknown url")?;
+            let (username, server_hash) = query.split_once("&serverId=").ok_or("Invalid query")?;
+            match self.joined.borrow().get(server_hash) {
+                Some(uuid) => Ok(HttpResponse {
+                    status: 200,
+                    body: format!(r#"{{"id":"{}","name":"{}","properties":[{{"name":"textures","value":"e30="}}]}}"#, uuid, username),
+                }),
+                None => Ok(HttpResponse { status: 204, body: String::new() }),
+            }
+        }
+
+        fn post_json(&self, url: &str, body: &str) -> Result<HttpResponse, Box<dyn std::error::Error + Send + Sync>> {
+            assert_eq!(url, "http://localhost/join");
+            let body: serde_json::Value = serde_json::from_str(body)?;
+            self.joined.borrow_mut().insert(
+                body["serverId"].as_str().ok_or("Missing server id")?.to_string(),
+                body["selectedProfile"].as_str().ok_or("Missing profile")?.to_string(),
+            );
+            Ok(HttpResponse { status: 204, body: String::new() })
+        }
+    }
+
+    #[test]
+    fn key_exchange() {
+        let key = ServerKey::generate().unwrap();
+        let verify_token = generate_verify_token();
+        let public_key_der = match key.encryption_request(&verify_token) {
+            login::ClientboundPacket::EncryptionRequest { public_key, .. } => public_key.items,
+            _ => unreachable!(),
+        };
+
+        // Client side
+        let session_server = LocalSessionServer::default();
+        let session = SessionClient::with_base_url(&session_server, "http://localhost/");
+        let shared_secret = generate_shared_secret();
+        let uuid = UUID::offline_player("Notch");
+        session.join("token", uuid, &server_hash("", &shared_secret, &public_key_der)).unwrap();
+        let encrypted_shared_secret = encrypt_with_public_key(&public_key_der, &shared_secret).unwrap();
+        let encrypted_verify_token = encrypt_with_public_key(&public_key_der, &verify_token).unwrap();
+
+        // Server side
+        assert!(key.verify_response(&[0; 4], &encrypted_shared_secret, &encrypted_verify_token).is_err());
+        let received_secret = key.verify_response(&verify_token, &encrypted_shared_secret, &encrypted_verify_token).unwrap();
+        assert_eq!(received_secret, shared_secret);
+        let hash = server_hash("", &received_secret, key.public_key_der());
+        let profile = session.has_joined("Notch", &hash, None).unwrap().unwrap();
+        assert_eq!(profile.uuid, uuid);
+        assert_eq!(profile.properties[0].name, "textures");
+        assert_eq!(session.has_joined("Notch", "0", None).unwrap(), None);
+    }
+}
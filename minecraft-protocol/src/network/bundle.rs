@@ -0,0 +1,220 @@
+//! Bundles of packets, which the client handles on the same tick.
+//!
+//! During play, the server can wrap packets between two [BundleDelimiter](ClientboundPacket::BundleDelimiter)s, for example to spawn an entity with its metadata and equipment at once.
+//! A [BundleReader] groups the received packets accordingly, and a [BundleBuilder] sends such a group.
+//!
+//! ```no_run
+//! use minecraft_protocol::{
+//!     network::{bundle::*, connection::Connection, NetworkError},
+//!     packets::play_clientbound::ClientboundPacket,
+//! };
+//! use std::net::TcpStream;
+//!
+//! // Server side, for example to spawn an entity along with its metadata
+//! fn spawn(connection: &mut Connection<TcpStream>, packets: Vec<ClientboundPacket>) -> Result<(), NetworkError> {
+//!     BundleBuilder::new().packets(packets).send(connection)
+//! }
+//!
+//! // Client side
+//! fn receive(connection: &mut Connection<TcpStream>) -> Result<(), NetworkError> {
+//!     for packet in BundleReader::new(connection.clientbound_packets()) {
+//!         match packet? {
+//!             Bundled::Packet(packet) => println!("{:?}", packet),
+//!             Bundled::Bundle(packets) => println!("{} packets on the same tick", packets.len()),
+//!         }
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use super::{
+    connection::{Clientbound, Connection},
+    NetworkError,
+};
+use crate::packets::{owned::Owned, play_clientbound::ClientboundPacket};
+use std::io::Write;
+
+/// The maximum number of packets in a bundle, above which the Notchian client disconnects.
+pub const MAX_BUNDLE_SIZE: usize = 4096;
+
+/// A packet received on its own, or the packets of a bundle without their delimiters.
+#[derive(Debug)]
+pub enum Bundled<P> {
+    Packet(P),
+    Bundle(Vec<P>),
+}
+
+/// Groups the packets received between two delimiters into a [Bundled::Bundle].
+///
+/// It wraps any iterator of received packets, such as [Connection::clientbound_packets].
+/// Bundles that exceed [MAX_BUNDLE_SIZE] or that are not closed before the packets end are errors.
+#[derive(Debug)]
+pub struct BundleReader<I> {
+    packets: I,
+}
+
+impl<I> BundleReader<I> {
+    pub fn new(packets: I) -> Self {
+        BundleReader { packets }
+    }
+
+    pub fn into_inner(self) -> I {
+        self.packets
+    }
+}
+
+fn is_delimiter(packet: &Owned<Clientbound<'static>>) -> bool {
+    matches!(packet.get(), Clientbound::Play(ClientboundPacket::BundleDelimiter))
+}
+
+impl<I, E> Iterator for BundleReader<I>
+where
+    I: Iterator<Item = Result<Owned<Clientbound<'static>>, E>>,
+    E: From<&'static str>,
+{
+    type Item = Result<Bundled<Owned<Clientbound<'static>>>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let packet = match self.packets.next()? {
+            Ok(packet) => packet,
+            Err(e) => return Some(Err(e)),
+        };
+        if !is_delimiter(&packet) {
+            return Some(Ok(Bundled::Packet(packet)));
+        }
+
+        let mut bundle = Vec::new();
+        loop {
+            let packet = match self.packets.next() {
+                Some(Ok(packet)) => packet,
+                Some(Err(e)) => return Some(Err(e)),
+                None => return Some(Err("The packets ended in the middle of a bundle.".into())),
+            };
+            if is_delimiter(&packet) {
+                return Some(Ok(Bundled::Bundle(bundle)));
+            }
+            if bundle.len() >= MAX_BUNDLE_SIZE {
+                return Some(Err("Too many packets in a bundle.".into()));
+            }
+            bundle.push(packet);
+        }
+    }
+}
+
+/// Builds a bundle of packets to be sent during play, see [the module documentation](self).
+#[derive(Debug, Default)]
+pub struct BundleBuilder<'a> {
+    packets: Vec<ClientboundPacket<'a>>,
+}
+
+impl<'a> BundleBuilder<'a> {
+    pub fn new() -> Self {
+        BundleBuilder::default()
+    }
+
+    pub fn packet(mut self, packet: ClientboundPacket<'a>) -> Self {
+        self.packets.push(packet);
+        self
+    }
+
+    pub fn packets(mut self, packets: impl IntoIterator<Item = ClientboundPacket<'a>>) -> Self {
+        self.packets.extend(packets);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.packets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packets.is_empty()
+    }
+
+    /// The packets of the bundle, between two delimiters.
+    /// Fails if there are more than [MAX_BUNDLE_SIZE] packets, or if one of them is a delimiter.
+    pub fn build(self) -> Result<Vec<ClientboundPacket<'a>>, &'static str> {
+        if self.packets.len() > MAX_BUNDLE_SIZE {
+            return Err("Too many packets in a bundle.");
+        }
+        if self.packets.iter().any(|packet| matches!(packet, ClientboundPacket::BundleDelimiter)) {
+            return Err("A bundle cannot contain a delimiter.");
+        }
+        let mut packets = Vec::with_capacity(self.packets.len() + 2);
+        packets.push(ClientboundPacket::BundleDelimiter);
+        packets.extend(self.packets);
+        packets.push(ClientboundPacket::BundleDelimiter);
+        Ok(packets)
+    }
+
+    /// Sends the bundle to a client in the play state.
+    pub fn send<S: Write>(self, connection: &mut Connection<S>) -> Result<(), NetworkError> {
+        for packet in self.build()? {
+            connection.send_clientbound(Clientbound::Play(packet))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{network::testing::loopback, packets::ConnectionState};
+    use std::io::ErrorKind;
+
+    fn keep_alive(keep_alive_id: u64) -> ClientboundPacket<'static> {
+        ClientboundPacket::KeepAlive { keep_alive_id }
+    }
+
+    fn owned(packet: ClientboundPacket) -> Result<Owned<Clientbound<'static>>, NetworkError> {
+        let frame = Clientbound::Play(packet).serialize()?;
+        Ok(Owned::try_new(frame, |frame| Clientbound::deserialize(ConnectionState::Play, frame))?)
+    }
+
+    /// The packets of a bundle, or the packet received on its own.
+    fn packets(bundled: Bundled<Owned<Clientbound<'static>>>) -> Vec<Owned<Clientbound<'static>>> {
+        match bundled {
+            Bundled::Packet(packet) => vec![packet],
+            Bundled::Bundle(packets) => packets,
+        }
+    }
+
+    #[test]
+    fn bundles() {
+        let mut client = loopback(ConnectionState::Play, |server| {
+            server.send_clientbound(Clientbound::Play(keep_alive(1))).unwrap();
+            BundleBuilder::new().packet(keep_alive(2)).packets([keep_alive(3), keep_alive(4)]).send(server).unwrap();
+            BundleBuilder::new().send(server).unwrap();
+            server.send_clientbound(Clientbound::Play(keep_alive(5))).unwrap();
+            server.send_clientbound(Clientbound::Play(ClientboundPacket::BundleDelimiter)).unwrap();
+            server.send_clientbound(Clientbound::Play(keep_alive(6))).unwrap();
+        });
+        let mut reader = BundleReader::new(client.clientbound_packets());
+        for expected in [vec![1], vec![2, 3, 4], vec![], vec![5]] {
+            let received = packets(reader.next().unwrap().unwrap());
+            let expected: Vec<_> = expected.into_iter().map(|id| Clientbound::Play(keep_alive(id))).collect();
+            assert_eq!(received.iter().map(Owned::get).collect::<Vec<_>>(), expected.iter().collect::<Vec<_>>());
+        }
+        // The stream ends in the middle of the last bundle
+        assert!(matches!(reader.next(), Some(Err(NetworkError::Io(e))) if e.kind() == ErrorKind::UnexpectedEof));
+        assert!(reader.next().is_none());
+
+        let unclosed = vec![ClientboundPacket::BundleDelimiter, keep_alive(1)].into_iter().map(owned);
+        assert!(matches!(
+            BundleReader::new(unclosed).next(),
+            Some(Err(NetworkError::Custom("The packets ended in the middle of a bundle.")))
+        ));
+    }
+
+    #[test]
+    fn bundle_size() {
+        let builder = BundleBuilder::new().packets((0..MAX_BUNDLE_SIZE as u64).map(keep_alive));
+        assert_eq!(builder.len(), MAX_BUNDLE_SIZE);
+        assert_eq!(builder.build().unwrap().len(), MAX_BUNDLE_SIZE + 2);
+        let builder = BundleBuilder::new().packets((0..=MAX_BUNDLE_SIZE as u64).map(keep_alive));
+        assert_eq!(builder.build().unwrap_err(), "Too many packets in a bundle.");
+        assert_eq!(BundleBuilder::new().packet(ClientboundPacket::BundleDelimiter).build().unwrap_err(), "A bundle cannot contain a delimiter.");
+
+        let too_many = std::iter::once(ClientboundPacket::BundleDelimiter).chain((0..=MAX_BUNDLE_SIZE as u64).map(keep_alive)).map(owned);
+        assert!(matches!(BundleReader::new(too_many).next(), Some(Err(NetworkError::Custom("Too many packets in a bundle.")))));
+    }
+}
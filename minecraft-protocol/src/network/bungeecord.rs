@@ -0,0 +1,138 @@
+//! BungeeCord legacy forwarding, which gives the identity of players to the servers behind a BungeeCord proxy.
+//!
+//! The proxy replaces the `server_address` of the [Hello](crate::packets::handshake::ServerboundPacket::Hello) packet by `host\0ip\0uuid\0properties`, where the properties are a JSON array.
+//! Nothing is signed, so the backend must only be reachable by the proxy.
+//!
+//! ```
+//! use minecraft_protocol::network::bungeecord::BungeeCordForwarding;
+//!
+//! # fn main() -> Result<(), &'static str> {
+//! // Backend, with the server address of the Hello packet
+//! let server_address = "localhost\u{0}203.0.113.7\u{0}b50ad385829d3141a2167e7d7539ba7f";
+//! let forwarding = BungeeCordForwarding::parse(server_address)?.ok_or("This server requires BungeeCord forwarding")?;
+//! let profile = forwarding.to_profile("Notch");
+//! assert_eq!(profile.username, "Notch");
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::packets::{
+    login::{GameProfile, ProfileProperty},
+    UUIDExt, UUID,
+};
+use std::net::IpAddr;
+
+/// The separator of the fields in the server address.
+const SEPARATOR: char = '\0';
+
+/// The information forwarded by the proxy in the handshake.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BungeeCordForwarding {
+    /// The hostname or IP that the player used to connect to the proxy.
+    pub host: String,
+    /// The IP address of the player, as seen by the proxy.
+    pub client_address: IpAddr,
+    pub uuid: UUID,
+    /// The properties of the player, such as its skin.
+    /// The proxy omits them in offline mode.
+    pub properties: Vec<ProfileProperty>,
+}
+
+impl BungeeCordForwarding {
+    /// Builds the forwarded information of a player on the proxy side.
+    pub fn new(host: impl Into<String>, client_address: IpAddr, profile: &GameProfile) -> Self {
+        BungeeCordForwarding {
+            host: host.into(),
+            client_address,
+            uuid: profile.uuid,
+            properties: profile.properties.clone(),
+        }
+    }
+
+    /// Returns true if a server address contains forwarded information.
+    /// Any client can put the separator in the address it sends, so this does not prove that the connection comes from the proxy.
+    /// The backend must only accept connections from the proxy, for example with a firewall.
+    pub fn is_forwarded(server_address: &str) -> bool {
+        server_address.contains(SEPARATOR)
+    }
+
+    /// Splits and validates the server address of a [Hello](crate::packets::handshake::ServerboundPacket::Hello) packet.
+    /// Returns `None` if the address was not forwarded, which means the player connected directly to the backend.
+    pub fn parse(server_address: &str) -> Result<Option<BungeeCordForwarding>, &'static str> {
+        if !BungeeCordForwarding::is_forwarded(server_address) {
+            return Ok(None);
+        }
+        let fields: Vec<&str> = server_address.split(SEPARATOR).collect();
+        let (host, client_address, uuid, properties) = match fields.as_slice() {
+            [host, client_address, uuid] => (host, client_address, uuid, None),
+            [host, client_address, uuid, properties] => (host, client_address, uuid, Some(properties)),
+            _ => return Err("Invalid number of fields in BungeeCord forwarding"),
+        };
+
+        Ok(Some(BungeeCordForwarding {
+            host: host.to_string(),
+            client_address: client_address.parse().map_err(|_| "Invalid client address in BungeeCord forwarding")?,
+            uuid: UUID::parse_str(uuid).ok_or("Invalid UUID in BungeeCord forwarding")?,
+            properties: match properties {
+                Some(properties) => serde_json::from_str(properties).map_err(|_| "Invalid properties in BungeeCord forwarding")?,
+                None => Vec::new(),
+            },
+        }))
+    }
+
+    /// Encodes the server address sent by the proxy.
+    /// The properties are omitted if there are none, as BungeeCord does.
+    pub fn encode(&self) -> String {
+        let mut server_address = format!(
+            "{}{}{}{}{}",
+            self.host,
+            SEPARATOR,
+            self.client_address,
+            SEPARATOR,
+            self.uuid.to_simple_string()
+        );
+        if !self.properties.is_empty() {
+            server_address.push(SEPARATOR);
+            server_address.push_str(&serde_json::to_string(&self.properties).expect("Properties are always serializable"));
+        }
+        server_address
+    }
+
+    /// The identity of the player, to be sent in [LoginSuccess](crate::packets::login::ClientboundPacket::LoginSuccess) instead of an offline profile.
+    /// The username is the one of the [LoginStart](crate::packets::login::ServerboundPacket::LoginStart) packet, as it is not forwarded.
+    pub fn to_profile(&self, username: impl Into<String>) -> GameProfile {
+        GameProfile {
+            uuid: self.uuid,
+            username: username.into(),
+            properties: self.properties.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bungeecord_round_trip() {
+        let mut profile = GameProfile::offline("Notch");
+        let forwarding = BungeeCordForwarding::new("mc.example.org", "192.168.1.12".parse().unwrap(), &profile);
+        let server_address = forwarding.encode();
+        assert_eq!(server_address, "mc.example.org\u{0}192.168.1.12\u{0}b50ad385829d3141a2167e7d7539ba7f");
+        assert_eq!(BungeeCordForwarding::parse(&server_address), Ok(Some(forwarding)));
+
+        profile.properties.push(ProfileProperty {
+            name: "textures".to_string(),
+            value: "e30=".to_string(),
+            signature: Some("c2lnbmF0dXJl".to_string()),
+        });
+        let forwarding = BungeeCordForwarding::new("mc.example.org", "::1".parse().unwrap(), &profile);
+        let parsed = BungeeCordForwarding::parse(&forwarding.encode()).unwrap().unwrap();
+        assert_eq!(parsed.to_profile("Notch"), profile);
+
+        assert_eq!(BungeeCordForwarding::parse("mc.example.org"), Ok(None));
+        assert!(BungeeCordForwarding::parse("mc.example.org\u{0}not an ip\u{0}b50ad385829d3141a2167e7d7539ba7f").is_err());
+        assert!(BungeeCordForwarding::parse("mc.example.org\u{0}127.0.0.1\u{0}Notch").is_err());
+        assert!(BungeeCordForwarding::parse("mc.example.org\u{0}127.0.0.1").is_err());
+    }
+}
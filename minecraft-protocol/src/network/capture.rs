@@ -0,0 +1,324 @@
+//! Captures of the packets going through a connection, to be replayed later.
+//!
+//! A capture starts with the [MAGIC] bytes and the time at which it started, in milliseconds since the Unix epoch as an [i64].
+//! It is followed by one record per packet:
+//!
+//! | Field | Type | Description |
+//! | --- | --- | --- |
+//! | Timestamp | [VarLong] | Microseconds since the capture started. |
+//! | Direction | [u8] | `0` for serverbound packets and `1` for clientbound packets. |
+//! | State | [ConnectionState] | The state in which the packet was sent. |
+//! | Protocol | [VarInt] | The protocol version number for which the packet is encoded. |
+//! | Frame | [VarInt] length and bytes | The decrypted and decompressed frame, starting with the packet id used on the wire. |
+//!
+//! A [Recorder] is attached to a [Connection] with [Connection::set_recorder], or to a [Proxy](super::proxy::Proxy).
+//! A [Replayer] reads a capture, decodes its packets and sends them again through a connection.
+//!
+//! ```no_run
+//! use minecraft_protocol::{
+//!     network::{
+//!         capture::{Recorder, Replayer},
+//!         connection::Connection,
+//!     },
+//!     packets::version::PacketDirection,
+//! };
+//! use std::{fs::File, net::TcpStream};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut connection = Connection::new(TcpStream::connect("127.0.0.1:25565")?);
+//! connection.set_recorder(Some(Recorder::new(File::create("session.mcap")?)?));
+//! // Play a session, then close the connection
+//! drop(connection);
+//!
+//! let capture = std::fs::read("session.mcap")?;
+//! let replayer = Replayer::new(&capture)?;
+//! assert!(replayer.decode_failures().is_empty());
+//! replayer.replay(&mut Connection::new(TcpStream::connect("127.0.0.1:25565")?), PacketDirection::Serverbound)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{
+    connection::{follow_clientbound, Clientbound, Connection, Serverbound},
+    NetworkError,
+};
+use crate::packets::{
+    owned::Owned,
+    serializer::{MinecraftPacketPart, ParseError},
+    version::{PacketDirection, ProtocolVersion},
+    ConnectionState, VarInt, VarLong,
+};
+use std::{
+    convert::TryFrom,
+    fmt,
+    io::Write,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// The bytes at the start of every capture, including the version of the format.
+pub const MAGIC: &[u8; 8] = b"MCPCAP01";
+
+/// Writes the packets of a connection into a capture.
+pub struct Recorder {
+    output: Box<dyn Write + Send>,
+    start: Instant,
+}
+
+impl fmt::Debug for Recorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Recorder").field("start", &self.start).finish_non_exhaustive()
+    }
+}
+
+impl Recorder {
+    /// Starts a capture by writing its header.
+    pub fn new(mut output: impl Write + Send + 'static) -> Result<Recorder, NetworkError> {
+        let started_at = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_millis() as i64).unwrap_or(0);
+        let mut header = MAGIC.to_vec();
+        started_at.serialize_minecraft_packet_part(&mut header)?;
+        output.write_all(&header)?;
+        Ok(Recorder {
+            output: Box::new(output),
+            start: Instant::now(),
+        })
+    }
+
+    /// Appends a frame, starting with the packet id used on the wire by `version`.
+    pub fn record(&mut self, direction: PacketDirection, state: ConnectionState, version: ProtocolVersion, frame: &[u8]) -> Result<(), NetworkError> {
+        let timestamp = self.start.elapsed().as_micros() as i64;
+        let mut record = Vec::with_capacity(frame.len() + 16);
+        VarLong(timestamp).serialize_minecraft_packet_part(&mut record)?;
+        let direction: u8 = match direction {
+            PacketDirection::Serverbound => 0,
+            PacketDirection::Clientbound => 1,
+        };
+        direction.serialize_minecraft_packet_part(&mut record)?;
+        state.serialize_minecraft_packet_part(&mut record)?;
+        VarInt(version.protocol()).serialize_minecraft_packet_part(&mut record)?;
+        VarInt::from(frame.len()).serialize_minecraft_packet_part(&mut record)?;
+        record.extend_from_slice(frame);
+        self.output.write_all(&record)?;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), NetworkError> {
+        Ok(self.output.flush()?)
+    }
+}
+
+/// A packet read from a capture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapturedPacket<'a> {
+    /// The time elapsed between the start of the capture and the packet.
+    pub timestamp: Duration,
+    pub direction: PacketDirection,
+    /// The state in which the packet was sent.
+    pub state: ConnectionState,
+    pub version: ProtocolVersion,
+    /// The frame, starting with the packet id used on the wire by [CapturedPacket::version].
+    pub frame: &'a [u8],
+}
+
+impl<'a> CapturedPacket<'a> {
+    fn deserialize(input: &'a [u8]) -> Result<(CapturedPacket<'a>, &'a [u8]), ParseError> {
+        let (timestamp, input) = VarLong::deserialize_minecraft_packet_part(input).map_err(|e| e.in_field("timestamp"))?;
+        let (direction, input) = match u8::deserialize_minecraft_packet_part(input).map_err(|e| e.in_field("direction"))? {
+            (0, input) => (PacketDirection::Serverbound, input),
+            (1, input) => (PacketDirection::Clientbound, input),
+            _ => return Err(ParseError::new("Invalid packet direction").at(input.len()).in_field("direction")),
+        };
+        let (state, input) = ConnectionState::deserialize_minecraft_packet_part(input).map_err(|e| e.in_field("state"))?;
+        let (protocol, rest) = VarInt::deserialize_minecraft_packet_part(input).map_err(|e| e.in_field("protocol"))?;
+        let version = ProtocolVersion::from_protocol(protocol.0)
+            .ok_or_else(|| ParseError::new("Unsupported protocol version").at(input.len()).in_field("protocol"))?;
+        let (length, input) = VarInt::deserialize_minecraft_packet_part(rest).map_err(|e| e.in_field("frame"))?;
+        let length = usize::try_from(length).map_err(|_| ParseError::new("Negative frame length").at(rest.len()).in_field("frame"))?;
+        if length > input.len() {
+            return Err(ParseError::new("The frame is truncated").at(input.len()).in_field("frame"));
+        }
+        let (frame, input) = input.split_at(length);
+        let packet = CapturedPacket {
+            timestamp: Duration::from_micros(timestamp.0.max(0) as u64),
+            direction,
+            state,
+            version,
+            frame,
+        };
+        Ok((packet, input))
+    }
+
+    /// The frame with the packet id numbered as in the packet enums.
+    pub fn canonical_frame(&self) -> Result<Vec<u8>, ParseError> {
+        let mut frame = self.frame.to_vec();
+        self.version.frame_to_canonical(self.state, self.direction, &mut frame)?;
+        Ok(frame)
+    }
+
+    /// Parses a packet sent by the client.
+    pub fn decode_serverbound(&self) -> Result<Owned<Serverbound<'static>>, ParseError> {
+        if self.direction != PacketDirection::Serverbound {
+            return Err(ParseError::new("The packet was sent by the server"));
+        }
+        let (state, version) = (self.state, self.version);
+        Owned::try_new(self.canonical_frame()?, |frame| version.scope(|| Serverbound::deserialize(state, frame)))
+    }
+
+    /// Parses a packet sent by the server.
+    pub fn decode_clientbound(&self) -> Result<Owned<Clientbound<'static>>, ParseError> {
+        if self.direction != PacketDirection::Clientbound {
+            return Err(ParseError::new("The packet was sent by the client"));
+        }
+        let (state, version) = (self.state, self.version);
+        Owned::try_new(self.canonical_frame()?, |frame| version.scope(|| Clientbound::deserialize(state, frame)))
+    }
+}
+
+/// Reads a capture to decode its packets or to send them again.
+#[derive(Debug, Clone)]
+pub struct Replayer<'a> {
+    started_at: SystemTime,
+    packets: Vec<CapturedPacket<'a>>,
+}
+
+impl<'a> Replayer<'a> {
+    /// Reads all the records of a capture.
+    pub fn new(capture: &'a [u8]) -> Result<Replayer<'a>, ParseError> {
+        let mut input = capture
+            .strip_prefix(MAGIC.as_slice())
+            .ok_or_else(|| ParseError::new("This is not a capture or its version is not supported").at(capture.len()))?;
+        let (started_at, rest) = i64::deserialize_minecraft_packet_part(input).map_err(|e| e.in_field("started_at"))?;
+        input = rest;
+        let mut packets = Vec::new();
+        while !input.is_empty() {
+            let (packet, rest) = CapturedPacket::deserialize(input).map_err(|e| e.with_offset(capture.len()))?;
+            packets.push(packet);
+            input = rest;
+        }
+        Ok(Replayer {
+            started_at: UNIX_EPOCH + Duration::from_millis(started_at.max(0) as u64),
+            packets,
+        })
+    }
+
+    /// The time at which the capture started.
+    pub fn started_at(&self) -> SystemTime {
+        self.started_at
+    }
+
+    pub fn packets(&self) -> &[CapturedPacket<'a>] {
+        &self.packets
+    }
+
+    /// Decodes every packet and returns the index of those that could not be decoded, along with the error.
+    /// A capture used as a regression corpus should have none.
+    pub fn decode_failures(&self) -> Vec<(usize, ParseError)> {
+        self.packets
+            .iter()
+            .enumerate()
+            .filter_map(|(index, packet)| {
+                let result = match packet.direction {
+                    PacketDirection::Serverbound => packet.decode_serverbound().map(drop),
+                    PacketDirection::Clientbound => packet.decode_clientbound().map(drop),
+                };
+                result.err().map(|e| (index, e))
+            })
+            .collect()
+    }
+
+    /// Sends the packets captured in one direction through a connection, such as the packets of the client to a local server.
+    /// The state and compression of the connection follow the capture, so the responses do not need to be read.
+    /// Returns the number of packets sent.
+    pub fn replay<S: Write>(&self, connection: &mut Connection<S>, direction: PacketDirection) -> Result<usize, NetworkError> {
+        let mut sent = 0;
+        for packet in &self.packets {
+            connection.set_state(packet.state);
+            connection.set_protocol_version(packet.version);
+            if packet.direction == direction {
+                connection.send_frame(packet.frame.to_vec())?;
+                sent += 1;
+            }
+            if packet.direction == PacketDirection::Clientbound {
+                let compression = packet
+                    .canonical_frame()
+                    .ok()
+                    .and_then(|frame| follow_clientbound(packet.state, packet.version, &frame));
+                if let Some(compression) = compression {
+                    connection.set_compression(compression);
+                }
+            }
+        }
+        Ok(sent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        network::testing::{login_sequence, loopback},
+        packets::login,
+    };
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let capture = SharedBuffer::default();
+        loopback(ConnectionState::HandShake, |client| {
+            client.set_recorder(Some(Recorder::new(capture.clone()).unwrap()));
+            for (index, packet) in login_sequence().into_iter().enumerate() {
+                client.send_serverbound(packet).unwrap();
+                if index == 1 {
+                    // The server enables compression
+                    let set_compression = login::ClientboundPacket::SetCompression { threshold: VarInt(16) };
+                    let mut recorder = client.take_recorder().unwrap();
+                    let frame = set_compression.serialize_minecraft_packet().unwrap();
+                    recorder.record(PacketDirection::Clientbound, ConnectionState::Login, client.protocol_version(), &frame).unwrap();
+                    client.set_recorder(Some(recorder));
+                    client.set_compression(Some(16));
+                }
+            }
+        });
+
+        let capture = capture.0.lock().unwrap().clone();
+        let replayer = Replayer::new(&capture).unwrap();
+        assert_eq!(replayer.packets().len(), 8);
+        assert!(replayer.decode_failures().is_empty());
+        assert!(replayer.packets().windows(2).all(|packets| packets[0].timestamp <= packets[1].timestamp));
+        let states: Vec<_> = replayer.packets().iter().map(|packet| packet.state).collect();
+        assert_eq!(
+            states[4..],
+            [ConnectionState::Configuration, ConnectionState::Play, ConnectionState::Play, ConnectionState::Configuration]
+        );
+        assert!(matches!(
+            replayer.packets()[2].decode_clientbound().unwrap().get(),
+            Clientbound::Login(login::ClientboundPacket::SetCompression { threshold: VarInt(16) })
+        ));
+
+        // The packets of the client are sent again to a server stand-in
+        let mut server = loopback(ConnectionState::HandShake, |replay| {
+            assert_eq!(replayer.replay(replay, PacketDirection::Serverbound).unwrap(), 7);
+        });
+        for (index, expected) in login_sequence().into_iter().enumerate() {
+            assert_eq!(server.receive_serverbound().unwrap(), expected);
+            if index == 1 {
+                server.set_compression(Some(16));
+            }
+        }
+
+        assert!(Replayer::new(b"MCPCAP00").is_err());
+        assert!(Replayer::new(&capture[..capture.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,546 @@
+//! Tokio codecs for the Minecraft framing, enabled by the `tokio` feature.
+//!
+//! [FrameCodec] only handles the length prefix, compression and encryption.
+//! [ServerCodec] and [ClientCodec] additionally follow the [ConnectionState] of the connection, so that the [Frame]s they yield can be parsed into typed packets.
+
+use super::{
+    compress_packet, connection::{check_protocol, follow_clientbound, follow_serverbound, select_protocol, Clientbound, Serverbound}, decompress_packet,
+    encryption::{StreamDecryptor, StreamEncryptor, SHARED_SECRET_LENGTH},
+    frame_length, NetworkError, MAX_FRAME_SIZE,
+};
+use crate::packets::{
+    limits::DecodeLimits,
+    raw::RawPacket,
+    serializer::{MinecraftPacketPart, ParseError},
+    version::{PacketDirection, ProtocolVersion},
+    ConnectionState, VarInt,
+};
+use bytes::{Bytes, BytesMut};
+use std::convert::TryInto;
+use tokio_util::codec::{Decoder, Encoder};
+
+/// Splits a byte stream into packets.
+/// The yielded bytes are decrypted and decompressed, and start with the packet id.
+/// Frames are checked against [DecodeLimits::default] unless other limits are set.
+#[derive(Debug, Default)]
+pub struct FrameCodec {
+    compression: Option<u32>,
+    limits: DecodeLimits,
+    encryptor: Option<StreamEncryptor>,
+    decryptor: Option<StreamDecryptor>,
+    /// The number of bytes at the start of the read buffer that are already decrypted.
+    decrypted: usize,
+}
+
+impl FrameCodec {
+    pub fn new() -> Self {
+        FrameCodec::default()
+    }
+
+    pub fn compression(&self) -> Option<u32> {
+        self.compression
+    }
+
+    pub fn set_compression(&mut self, compression: Option<u32>) {
+        self.compression = compression;
+    }
+
+    pub fn decode_limits(&self) -> DecodeLimits {
+        self.limits
+    }
+
+    /// Sets the limits enforced on decoded frames, and on the packets parsed from them by [ServerCodec] and [ClientCodec].
+    /// Use [DecodeLimits::unlimited] only if the other side is trusted.
+    pub fn set_decode_limits(&mut self, limits: DecodeLimits) {
+        self.limits = limits;
+    }
+
+    pub fn is_encrypted(&self) -> bool {
+        self.encryptor.is_some()
+    }
+
+    /// Encrypts everything that is encoded or decoded from now on.
+    /// Bytes that are already buffered but not decoded yet will be decrypted too.
+    pub fn enable_encryption(&mut self, shared_secret: &[u8; SHARED_SECRET_LENGTH]) {
+        self.encryptor = Some(StreamEncryptor::new(shared_secret));
+        self.decryptor = Some(StreamDecryptor::new(shared_secret));
+        self.decrypted = 0;
+    }
+}
+
+impl Decoder for FrameCodec {
+    type Item = Bytes;
+    type Error = NetworkError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Bytes>, NetworkError> {
+        if let Some(decryptor) = &mut self.decryptor {
+            decryptor.decrypt(&mut src[self.decrypted..]);
+        }
+        self.decrypted = src.len();
+
+        let (prefix_len, len) = match self.limits.scope(|| frame_length(src))? {
+            Some(length) => length,
+            None => return Ok(None),
+        };
+        if src.len() < prefix_len + len {
+            src.reserve(prefix_len + len - src.len());
+            return Ok(None);
+        }
+
+        let mut frame = src.split_to(prefix_len + len);
+        self.decrypted -= prefix_len + len;
+        let frame = frame.split_off(prefix_len);
+        match self.compression {
+            None => Ok(Some(frame.freeze())),
+            Some(threshold) => Ok(Some(self.limits.scope(|| decompress_packet(&frame, threshold))?.into())),
+        }
+    }
+}
+
+impl Encoder<&[u8]> for FrameCodec {
+    type Error = NetworkError;
+
+    fn encode(&mut self, packet: &[u8], dst: &mut BytesMut) -> Result<(), NetworkError> {
+        let compressed;
+        let packet = match self.compression {
+            None => packet,
+            Some(threshold) => {
+                compressed = compress_packet(packet, threshold)?;
+                compressed.as_slice()
+            }
+        };
+        if packet.len() > MAX_FRAME_SIZE {
+            return Err("Packet too long".into());
+        }
+
+        let mut prefix = Vec::new();
+        VarInt(packet.len().try_into().map_err(|_| "Packet too long")?).serialize_minecraft_packet_part(&mut prefix)?;
+        let start = dst.len();
+        dst.reserve(prefix.len() + packet.len());
+        dst.extend_from_slice(&prefix);
+        dst.extend_from_slice(packet);
+        if let Some(encryptor) = &mut self.encryptor {
+            encryptor.encrypt(&mut dst[start..]);
+        }
+        Ok(())
+    }
+}
+
+impl Encoder<Vec<u8>> for FrameCodec {
+    type Error = NetworkError;
+
+    fn encode(&mut self, packet: Vec<u8>, dst: &mut BytesMut) -> Result<(), NetworkError> {
+        self.encode(packet.as_slice(), dst)
+    }
+}
+
+/// A packet along with the [ConnectionState] and [ProtocolVersion] in which it was received.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub state: ConnectionState,
+    pub version: ProtocolVersion,
+    /// The limits of the codec, enforced when the packet is parsed.
+    pub limits: DecodeLimits,
+    /// The packet id, as numbered by the packet enums, followed by the packet data.
+    pub data: Bytes,
+}
+
+impl Frame {
+    pub fn serverbound(&self) -> Result<Serverbound<'_>, ParseError> {
+        self.version.scope(|| self.limits.scope(|| Serverbound::deserialize(self.state, &self.data)))
+    }
+
+    pub fn clientbound(&self) -> Result<Clientbound<'_>, ParseError> {
+        self.version.scope(|| self.limits.scope(|| Clientbound::deserialize(self.state, &self.data)))
+    }
+
+    /// Reads the packet id only, leaving the fields undecoded.
+    pub fn raw(&self) -> Result<RawPacket<'_>, ParseError> {
+        self.version.scope(|| RawPacket::peek(&self.data))
+    }
+}
+
+/// Replaces the packet id of a received frame by the one of the packet enums.
+fn canonical_frame(version: ProtocolVersion, state: ConnectionState, direction: PacketDirection, data: Bytes) -> Result<Bytes, NetworkError> {
+    let (id, _) = VarInt::deserialize_minecraft_packet_part(&data)?;
+    if version.canonical_packet_id(state, direction, id.0) == Some(id.0) {
+        return Ok(data);
+    }
+    let mut frame = data.to_vec();
+    version.frame_to_canonical(state, direction, &mut frame)?;
+    Ok(frame.into())
+}
+
+/// Serializes a packet for a protocol version.
+fn wire_frame(version: ProtocolVersion, state: ConnectionState, direction: PacketDirection, packet: &[u8]) -> Result<Vec<u8>, NetworkError> {
+    let mut frame = packet.to_vec();
+    version.frame_to_wire(state, direction, &mut frame)?;
+    Ok(frame)
+}
+
+/// The codec of a server: decodes serverbound packets and encodes clientbound packets.
+///
+/// The [ProtocolVersion] is selected by the [Hello](crate::packets::handshake::ServerboundPacket::Hello) packet.
+/// If the requested one is not supported, it is returned by `unsupported_protocol` and no packet can be decoded after the status or the handshake.
+/// Raw packets are numbered as by the packet enums, and their ids are translated for the version of the connection.
+#[derive(Debug)]
+pub struct ServerCodec {
+    frames: FrameCodec,
+    state: ConnectionState,
+    version: ProtocolVersion,
+    unsupported_protocol: Option<i32>,
+}
+
+impl Default for ServerCodec {
+    fn default() -> Self {
+        ServerCodec {
+            frames: FrameCodec::new(),
+            state: ConnectionState::HandShake,
+            version: ProtocolVersion::V1_20_2,
+            unsupported_protocol: None,
+        }
+    }
+}
+
+impl ServerCodec {
+    pub fn new() -> Self {
+        ServerCodec::default()
+    }
+
+    pub fn state(&self) -> ConnectionState {
+        self.state
+    }
+
+    /// Overrides the state, for connections that do not start with a handshake.
+    pub fn set_state(&mut self, state: ConnectionState) {
+        self.state = state;
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.version
+    }
+
+    /// Overrides the protocol version, for connections that do not start with a handshake.
+    pub fn set_protocol_version(&mut self, version: ProtocolVersion) {
+        self.version = version;
+        self.unsupported_protocol = None;
+    }
+
+    /// The protocol number requested by the [Hello](crate::packets::handshake::ServerboundPacket::Hello) packet, if it is not supported.
+    pub fn unsupported_protocol(&self) -> Option<i32> {
+        self.unsupported_protocol
+    }
+
+    pub fn frames(&self) -> &FrameCodec {
+        &self.frames
+    }
+
+    pub fn frames_mut(&mut self) -> &mut FrameCodec {
+        &mut self.frames
+    }
+}
+
+impl Decoder for ServerCodec {
+    type Item = Frame;
+    type Error = NetworkError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Frame>, NetworkError> {
+        check_protocol(self.state, self.unsupported_protocol)?;
+        let data = match self.frames.decode(src)? {
+            Some(data) => data,
+            None => return Ok(None),
+        };
+        let (state, version, limits) = (self.state, self.version, self.frames.limits);
+        let data = canonical_frame(version, state, PacketDirection::Serverbound, data)?;
+        let (next_state, protocol) = limits.scope(|| follow_serverbound(state, version, &data))?;
+        self.state = next_state;
+        if let Some(protocol) = protocol {
+            (self.version, self.unsupported_protocol) = select_protocol(protocol, self.version);
+        }
+        Ok(Some(Frame { state, version, limits, data }))
+    }
+}
+
+impl Encoder<Clientbound<'_>> for ServerCodec {
+    type Error = NetworkError;
+
+    fn encode(&mut self, packet: Clientbound, dst: &mut BytesMut) -> Result<(), NetworkError> {
+        if packet.state() != self.state {
+            return Err("The packet does not belong to the current connection state.".into());
+        }
+        let packet = self.version.scope(|| packet.serialize())?;
+        self.encode(packet.as_slice(), dst)
+    }
+}
+
+/// Raw packets are parsed during login only, to follow the compression enabled by [SetCompression](crate::packets::login::ClientboundPacket::SetCompression).
+impl Encoder<&[u8]> for ServerCodec {
+    type Error = NetworkError;
+
+    fn encode(&mut self, packet: &[u8], dst: &mut BytesMut) -> Result<(), NetworkError> {
+        let compression = follow_clientbound(self.state, self.version, packet);
+        let frame = wire_frame(self.version, self.state, PacketDirection::Clientbound, packet)?;
+        self.frames.encode(frame, dst)?;
+        if let Some(compression) = compression {
+            self.frames.set_compression(compression);
+        }
+        Ok(())
+    }
+}
+
+/// The codec of a client: decodes clientbound packets and encodes serverbound packets.
+///
+/// The [ProtocolVersion] is selected by the [Hello](crate::packets::handshake::ServerboundPacket::Hello) packet.
+/// If the requested one is not supported, it is returned by `unsupported_protocol` and no packet can be decoded after the status or the handshake.
+/// Raw packets are numbered as by the packet enums, and their ids are translated for the version of the connection.
+#[derive(Debug)]
+pub struct ClientCodec {
+    frames: FrameCodec,
+    state: ConnectionState,
+    version: ProtocolVersion,
+    unsupported_protocol: Option<i32>,
+}
+
+impl Default for ClientCodec {
+    fn default() -> Self {
+        ClientCodec {
+            frames: FrameCodec::new(),
+            state: ConnectionState::HandShake,
+            version: ProtocolVersion::V1_20_2,
+            unsupported_protocol: None,
+        }
+    }
+}
+
+impl ClientCodec {
+    pub fn new() -> Self {
+        ClientCodec::default()
+    }
+
+    pub fn state(&self) -> ConnectionState {
+        self.state
+    }
+
+    /// Overrides the state, for connections that do not start with a handshake.
+    pub fn set_state(&mut self, state: ConnectionState) {
+        self.state = state;
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.version
+    }
+
+    /// Overrides the protocol version, for connections that do not start with a handshake.
+    pub fn set_protocol_version(&mut self, version: ProtocolVersion) {
+        self.version = version;
+        self.unsupported_protocol = None;
+    }
+
+    /// The protocol number requested by the [Hello](crate::packets::handshake::ServerboundPacket::Hello) packet, if it is not supported.
+    pub fn unsupported_protocol(&self) -> Option<i32> {
+        self.unsupported_protocol
+    }
+
+    pub fn frames(&self) -> &FrameCodec {
+        &self.frames
+    }
+
+    pub fn frames_mut(&mut self) -> &mut FrameCodec {
+        &mut self.frames
+    }
+}
+
+impl Decoder for ClientCodec {
+    type Item = Frame;
+    type Error = NetworkError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Frame>, NetworkError> {
+        check_protocol(self.state, self.unsupported_protocol)?;
+        let data = match self.frames.decode(src)? {
+            Some(data) => data,
+            None => return Ok(None),
+        };
+        let (state, version, limits) = (self.state, self.version, self.frames.limits);
+        let data = canonical_frame(version, state, PacketDirection::Clientbound, data)?;
+        if let Some(compression) = limits.scope(|| follow_clientbound(state, version, &data)) {
+            self.frames.set_compression(compression);
+        }
+        Ok(Some(Frame { state, version, limits, data }))
+    }
+}
+
+impl Encoder<Serverbound<'_>> for ClientCodec {
+    type Error = NetworkError;
+
+    fn encode(&mut self, packet: Serverbound, dst: &mut BytesMut) -> Result<(), NetworkError> {
+        if packet.state() != self.state {
+            return Err("The packet does not belong to the current connection state.".into());
+        }
+        packet.check_next_state()?;
+        let packet = self.version.scope(|| packet.serialize())?;
+        self.encode(packet.as_slice(), dst)
+    }
+}
+
+/// Raw packets that can change the state are parsed to follow it, as are the ones selecting the protocol version.
+impl Encoder<&[u8]> for ClientCodec {
+    type Error = NetworkError;
+
+    fn encode(&mut self, packet: &[u8], dst: &mut BytesMut) -> Result<(), NetworkError> {
+        let (next_state, protocol) = follow_serverbound(self.state, self.version, packet)?;
+        let frame = wire_frame(self.version, self.state, PacketDirection::Serverbound, packet)?;
+        self.frames.encode(frame, dst)?;
+        self.state = next_state;
+        if let Some(protocol) = protocol {
+            (self.version, self.unsupported_protocol) = select_protocol(protocol, self.version);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packets::{config, handshake, login, Array};
+
+    #[test]
+    fn frames_split_across_chunks() {
+        let shared_secret = [1; SHARED_SECRET_LENGTH];
+        let mut encoder = FrameCodec::new();
+        encoder.set_compression(Some(16));
+        encoder.enable_encryption(&shared_secret);
+        let packets: Vec<Vec<u8>> = vec![vec![0, 1, 2], vec![5; 200], vec![1; 16]];
+        let mut stream = BytesMut::new();
+        for packet in &packets {
+            encoder.encode(packet.as_slice(), &mut stream).unwrap();
+        }
+
+        let mut decoder = FrameCodec::new();
+        decoder.set_compression(Some(16));
+        decoder.enable_encryption(&shared_secret);
+        let mut buffer = BytesMut::new();
+        let mut decoded = Vec::new();
+        for chunk in stream.chunks(7) {
+            buffer.extend_from_slice(chunk);
+            while let Some(frame) = decoder.decode(&mut buffer).unwrap() {
+                decoded.push(frame.to_vec());
+            }
+        }
+        assert_eq!(decoded, packets);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn typed_login_sequence() {
+        let mut client = ClientCodec::new();
+        let mut stream = BytesMut::new();
+        let packets = vec![
+            Serverbound::Handshake(handshake::ServerboundPacket::Hello {
+                protocol_version: VarInt(764),
+                server_address: "localhost",
+                server_port: 25565,
+                next_state: ConnectionState::Login,
+            }),
+            Serverbound::Login(login::ServerboundPacket::LoginStart {
+                username: "bot",
+                player_uuid: 0,
+            }),
+            Serverbound::Login(login::ServerboundPacket::LoginAcknowledged),
+            Serverbound::Config(config::ServerboundPacket::KeepAlive { id: 1 }),
+        ];
+        for packet in packets {
+            client.encode(packet, &mut stream).unwrap();
+        }
+        assert_eq!(client.state(), ConnectionState::Configuration);
+
+        let mut server = ServerCodec::new();
+        let mut states = Vec::new();
+        while let Some(frame) = server.decode(&mut stream).unwrap() {
+            frame.serverbound().unwrap();
+            states.push(frame.state);
+        }
+        assert_eq!(
+            states,
+            [ConnectionState::HandShake, ConnectionState::Login, ConnectionState::Login, ConnectionState::Configuration]
+        );
+
+        // The compression is enabled on both sides by the SetCompression packet
+        let mut server = ServerCodec::new();
+        server.set_state(ConnectionState::Login);
+        let mut stream = BytesMut::new();
+        server
+            .encode(Clientbound::Login(login::ClientboundPacket::SetCompression { threshold: VarInt(8) }), &mut stream)
+            .unwrap();
+        server
+            .encode(
+                Clientbound::Login(login::ClientboundPacket::LoginSuccess {
+                    uuid: 1,
+                    username: "bot",
+                    properties: Array::default(),
+                }),
+                &mut stream,
+            )
+            .unwrap();
+        assert_eq!(server.frames().compression(), Some(8));
+
+        let mut client = ClientCodec::new();
+        client.set_state(ConnectionState::Login);
+        let first = client.decode(&mut stream).unwrap().unwrap();
+        assert!(matches!(first.clientbound(), Ok(Clientbound::Login(login::ClientboundPacket::SetCompression { .. }))));
+        let second = client.decode(&mut stream).unwrap().unwrap();
+        assert!(matches!(second.clientbound(), Ok(Clientbound::Login(login::ClientboundPacket::LoginSuccess { .. }))));
+    }
+
+    #[test]
+    fn raw_packets_are_followed() {
+        // The state and the protocol version are followed through raw serverbound packets
+        let mut client = ClientCodec::new();
+        let mut stream = BytesMut::new();
+        let packets = vec![
+            Serverbound::Handshake(handshake::ServerboundPacket::Hello {
+                protocol_version: VarInt(765),
+                server_address: "localhost",
+                server_port: 25565,
+                next_state: ConnectionState::Login,
+            }),
+            Serverbound::Login(login::ServerboundPacket::LoginAcknowledged),
+        ];
+        for packet in packets {
+            let frame = ProtocolVersion::V1_20_2.scope(|| packet.serialize()).unwrap();
+            client.encode(frame.as_slice(), &mut stream).unwrap();
+        }
+        assert_eq!(client.state(), ConnectionState::Configuration);
+        assert_eq!(client.protocol_version(), ProtocolVersion::V1_20_3);
+
+        // The compression is enabled by a raw SetCompression packet
+        let mut server = ServerCodec::new();
+        server.set_state(ConnectionState::Login);
+        let frame = login::ClientboundPacket::SetCompression { threshold: VarInt(0) }.serialize_minecraft_packet().unwrap();
+        server.encode(frame.as_slice(), &mut BytesMut::new()).unwrap();
+        assert_eq!(server.frames().compression(), Some(0));
+    }
+
+    #[test]
+    fn invalid_handshakes_are_rejected() {
+        let hello = handshake::ServerboundPacket::Hello {
+            protocol_version: VarInt(764),
+            server_address: "localhost",
+            server_port: 25565,
+            next_state: ConnectionState::Play,
+        };
+        let frame = hello.serialize_minecraft_packet().unwrap();
+        let mut client = ClientCodec::new();
+        let mut stream = BytesMut::new();
+        assert!(client.encode(Serverbound::Handshake(hello), &mut stream).is_err());
+        assert_eq!(client.state(), ConnectionState::HandShake);
+
+        // Neither a Hello requesting the play state nor an unknown packet can leave the handshake
+        for frame in [frame, vec![0x7f]] {
+            let mut server = ServerCodec::new();
+            let mut stream = BytesMut::new();
+            FrameCodec::new().encode(frame.as_slice(), &mut stream).unwrap();
+            assert!(server.decode(&mut stream).is_err());
+            assert_eq!(server.state(), ConnectionState::HandShake);
+        }
+    }
+}
@@ -0,0 +1,888 @@
+//! A connection that keeps track of the [ConnectionState] and decodes the packets of the current state.
+//!
+//! ```no_run
+//! use minecraft_protocol::network::connection::{Connection, Serverbound};
+//! use std::net::TcpListener;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let (stream, _) = TcpListener::bind("127.0.0.1:25565")?.accept()?;
+//! let mut connection = Connection::new(stream);
+//! loop {
+//!     match connection.receive_serverbound()? {
+//!         Serverbound::Handshake(packet) => println!("{:?}", packet),
+//!         Serverbound::Login(packet) => println!("{:?}", packet),
+//!         _ => (),
+//!     }
+//! }
+//! # }
+//! ```
+
+use super::{
+    capture::Recorder,
+    encryption::{StreamDecryptor, StreamEncryptor, SHARED_SECRET_LENGTH},
+    read_packet, send_packet, NetworkError,
+};
+use crate::packets::{
+    config, limits::DecodeLimits, handshake, login, play_clientbound, play_serverbound,
+    owned::Owned,
+    raw::RawPacket,
+    serializer::{MinecraftPacketPart, ParseError},
+    status,
+    version::{PacketDirection, ProtocolVersion},
+    ConnectionState,
+};
+use std::io::{Read, Write};
+use yoke::Yokeable;
+
+/// A packet sent by the client, in any [ConnectionState].
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug)]
+pub enum Serverbound<'a> {
+    Handshake(handshake::ServerboundPacket<'a>),
+    Status(status::ServerboundPacket),
+    Login(login::ServerboundPacket<'a>),
+    Config(config::ServerboundPacket<'a>),
+    Play(play_serverbound::ServerboundPacket<'a>),
+}
+
+impl<'a> Serverbound<'a> {
+    /// Parses a packet using the packet enum of the given state.
+    /// A [Hello](handshake::ServerboundPacket::Hello) packet that does not request the status or login state is rejected, since the other states are only reached after login.
+    pub fn deserialize(state: ConnectionState, input: &'a [u8]) -> Result<Self, ParseError> {
+        let packet = match state {
+            ConnectionState::HandShake => {
+                Serverbound::Handshake(MinecraftPacketPart::deserialize_uncompressed_minecraft_packet(input)?)
+            }
+            ConnectionState::Status => {
+                Serverbound::Status(MinecraftPacketPart::deserialize_uncompressed_minecraft_packet(input)?)
+            }
+            ConnectionState::Login => {
+                Serverbound::Login(MinecraftPacketPart::deserialize_uncompressed_minecraft_packet(input)?)
+            }
+            ConnectionState::Configuration => {
+                Serverbound::Config(MinecraftPacketPart::deserialize_uncompressed_minecraft_packet(input)?)
+            }
+            ConnectionState::Play => {
+                Serverbound::Play(MinecraftPacketPart::deserialize_uncompressed_minecraft_packet(input)?)
+            }
+        };
+        packet.check_next_state()?;
+        Ok(packet)
+    }
+
+    pub fn serialize(&self) -> Result<Vec<u8>, &'static str> {
+        match self {
+            Serverbound::Handshake(packet) => packet.serialize_minecraft_packet(),
+            Serverbound::Status(packet) => packet.serialize_minecraft_packet(),
+            Serverbound::Login(packet) => packet.serialize_minecraft_packet(),
+            Serverbound::Config(packet) => packet.serialize_minecraft_packet(),
+            Serverbound::Play(packet) => packet.serialize_minecraft_packet(),
+        }
+    }
+
+    /// The state in which this packet can be sent.
+    pub fn state(&self) -> ConnectionState {
+        match self {
+            Serverbound::Handshake(_) => ConnectionState::HandShake,
+            Serverbound::Status(_) => ConnectionState::Status,
+            Serverbound::Login(_) => ConnectionState::Login,
+            Serverbound::Config(_) => ConnectionState::Configuration,
+            Serverbound::Play(_) => ConnectionState::Play,
+        }
+    }
+
+    /// The id of this packet in its packet enum.
+    pub fn packet_id(&self) -> i32 {
+        match self {
+            Serverbound::Handshake(packet) => packet.packet_id(),
+            Serverbound::Status(packet) => packet.packet_id(),
+            Serverbound::Login(packet) => packet.packet_id(),
+            Serverbound::Config(packet) => packet.packet_id(),
+            Serverbound::Play(packet) => packet.packet_id(),
+        }
+    }
+
+    /// The name of this packet, such as `"ChunkData"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Serverbound::Handshake(packet) => packet.name(),
+            Serverbound::Status(packet) => packet.name(),
+            Serverbound::Login(packet) => packet.name(),
+            Serverbound::Config(packet) => packet.name(),
+            Serverbound::Play(packet) => packet.name(),
+        }
+    }
+
+    /// The state of the connection once this packet has been sent.
+    /// All state transitions are triggered by the client.
+    pub fn next_state(&self) -> ConnectionState {
+        match self {
+            Serverbound::Handshake(handshake::ServerboundPacket::Hello { next_state, .. }) => *next_state,
+            Serverbound::Login(login::ServerboundPacket::LoginAcknowledged) => ConnectionState::Configuration,
+            Serverbound::Config(config::ServerboundPacket::FinishConfiguration) => ConnectionState::Play,
+            Serverbound::Play(play_serverbound::ServerboundPacket::AcknowledgeConfiguration) => {
+                ConnectionState::Configuration
+            }
+            _ => self.state(),
+        }
+    }
+
+    /// Fails if this packet is a [Hello](handshake::ServerboundPacket::Hello) packet that requests another state than [ConnectionState::Status] or [ConnectionState::Login].
+    pub fn check_next_state(&self) -> Result<(), &'static str> {
+        match self {
+            Serverbound::Handshake(handshake::ServerboundPacket::Hello { next_state, .. }) => match next_state {
+                ConnectionState::Status | ConnectionState::Login => Ok(()),
+                _ => Err("The handshake can only request the status or login state."),
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// The protocol version requested by this packet, if it is a [Hello](handshake::ServerboundPacket::Hello) packet with a supported version.
+    pub fn protocol_version(&self) -> Option<ProtocolVersion> {
+        self.requested_protocol().and_then(ProtocolVersion::from_protocol)
+    }
+
+    /// The protocol number requested by this packet, if it is a [Hello](handshake::ServerboundPacket::Hello) packet, even if it is not supported.
+    pub fn requested_protocol(&self) -> Option<i32> {
+        match self {
+            Serverbound::Handshake(handshake::ServerboundPacket::Hello { protocol_version, .. }) => Some(protocol_version.0),
+            _ => None,
+        }
+    }
+}
+
+/// A packet sent by the server, in any [ConnectionState].
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum Clientbound<'a> {
+    Status(status::ClientboundPacket<'a>),
+    Login(login::ClientboundPacket<'a>),
+    Config(config::ClientboundPacket<'a>),
+    Play(play_clientbound::ClientboundPacket<'a>),
+}
+
+impl<'a> Clientbound<'a> {
+    /// Parses a packet using the packet enum of the given state.
+    pub fn deserialize(state: ConnectionState, input: &'a [u8]) -> Result<Self, ParseError> {
+        Ok(match state {
+            ConnectionState::HandShake => return Err("There are no clientbound packets in the handshake state.".into()),
+            ConnectionState::Status => {
+                Clientbound::Status(MinecraftPacketPart::deserialize_uncompressed_minecraft_packet(input)?)
+            }
+            ConnectionState::Login => {
+                Clientbound::Login(MinecraftPacketPart::deserialize_uncompressed_minecraft_packet(input)?)
+            }
+            ConnectionState::Configuration => {
+                Clientbound::Config(MinecraftPacketPart::deserialize_uncompressed_minecraft_packet(input)?)
+            }
+            ConnectionState::Play => {
+                Clientbound::Play(MinecraftPacketPart::deserialize_uncompressed_minecraft_packet(input)?)
+            }
+        })
+    }
+
+    pub fn serialize(&self) -> Result<Vec<u8>, &'static str> {
+        match self {
+            Clientbound::Status(packet) => packet.serialize_minecraft_packet(),
+            Clientbound::Login(packet) => packet.serialize_minecraft_packet(),
+            Clientbound::Config(packet) => packet.serialize_minecraft_packet(),
+            Clientbound::Play(packet) => packet.serialize_minecraft_packet(),
+        }
+    }
+
+    /// The state in which this packet can be sent.
+    pub fn state(&self) -> ConnectionState {
+        match self {
+            Clientbound::Status(_) => ConnectionState::Status,
+            Clientbound::Login(_) => ConnectionState::Login,
+            Clientbound::Config(_) => ConnectionState::Configuration,
+            Clientbound::Play(_) => ConnectionState::Play,
+        }
+    }
+
+    /// The id of this packet in its packet enum.
+    pub fn packet_id(&self) -> i32 {
+        match self {
+            Clientbound::Status(packet) => packet.packet_id(),
+            Clientbound::Login(packet) => packet.packet_id(),
+            Clientbound::Config(packet) => packet.packet_id(),
+            Clientbound::Play(packet) => packet.packet_id(),
+        }
+    }
+
+    /// The name of this packet, such as `"ChunkData"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Clientbound::Status(packet) => packet.name(),
+            Clientbound::Login(packet) => packet.name(),
+            Clientbound::Config(packet) => packet.name(),
+            Clientbound::Play(packet) => packet.name(),
+        }
+    }
+
+    /// The compression setting enabled by this packet, if it is a [SetCompression](login::ClientboundPacket::SetCompression) packet.
+    pub fn compression(&self) -> Option<Option<u32>> {
+        match self {
+            Clientbound::Login(login::ClientboundPacket::SetCompression { threshold }) if threshold.0 >= 0 => {
+                Some(Some(threshold.0 as u32))
+            }
+            Clientbound::Login(login::ClientboundPacket::SetCompression { .. }) => Some(None),
+            _ => None,
+        }
+    }
+}
+
+/// The state and protocol version of a connection after the client sent this frame.
+/// Only the packets that can change them are parsed, and frames that cannot be parsed change nothing.
+/// During the handshake, a frame that cannot be parsed is an error since the next state would be unknown.
+/// The requested protocol number is returned for [Hello](handshake::ServerboundPacket::Hello) packets, see [select_protocol].
+pub(super) fn follow_serverbound(state: ConnectionState, version: ProtocolVersion, frame: &[u8]) -> Result<(ConnectionState, Option<i32>), ParseError> {
+    if state == ConnectionState::Play {
+        // Only one packet switches the state during play, so the others don't have to be parsed
+        let acknowledge = play_serverbound::ServerboundPacketKind::AcknowledgeConfiguration.packet_id();
+        return match RawPacket::peek(frame) {
+            Ok(packet) if packet.id == acknowledge => Ok((ConnectionState::Configuration, None)),
+            _ => Ok((state, None)),
+        };
+    }
+    match version.scope(|| Serverbound::deserialize(state, frame)) {
+        Ok(packet) => Ok((packet.next_state(), packet.requested_protocol())),
+        Err(e) if state == ConnectionState::HandShake => Err(e),
+        Err(_) => Ok((state, None)),
+    }
+}
+
+/// The version of a connection after the client requested a protocol number, and the protocol number if it is not supported.
+/// An unsupported protocol leaves the version unchanged.
+pub(super) fn select_protocol(protocol: i32, version: ProtocolVersion) -> (ProtocolVersion, Option<i32>) {
+    match ProtocolVersion::from_protocol(protocol) {
+        Some(version) => (version, None),
+        None => (version, Some(protocol)),
+    }
+}
+
+/// Fails if the packets of this state cannot be decoded because the client requested an unsupported protocol.
+/// The packets of the handshake and of the status are the same in all versions.
+pub(super) fn check_protocol(state: ConnectionState, unsupported_protocol: Option<i32>) -> Result<(), NetworkError> {
+    match (state, unsupported_protocol) {
+        (ConnectionState::HandShake | ConnectionState::Status, _) | (_, None) => Ok(()),
+        (_, Some(_)) => Err("The protocol version requested by the client is not supported.".into()),
+    }
+}
+
+/// The compression setting enabled by this frame sent by the server, parsing it only during login.
+pub(super) fn follow_clientbound(state: ConnectionState, version: ProtocolVersion, frame: &[u8]) -> Option<Option<u32>> {
+    if state != ConnectionState::Login {
+        return None;
+    }
+    version
+        .scope(|| Clientbound::deserialize(state, frame))
+        .ok()
+        .and_then(|packet| packet.compression())
+}
+
+// The lifetime can only be swapped because both enums are covariant in it, which the compiler checks in `transform`.
+macro_rules! impl_yokeable {
+    ($name:ident) => {
+        unsafe impl<'a> Yokeable<'a> for $name<'static> {
+            type Output = $name<'a>;
+
+            fn transform(&'a self) -> &'a Self::Output {
+                self
+            }
+
+            fn transform_owned(self) -> Self::Output {
+                self
+            }
+
+            unsafe fn make(from: Self::Output) -> Self {
+                std::mem::transmute::<$name<'a>, $name<'static>>(from)
+            }
+
+            fn transform_mut<F>(&'a mut self, f: F)
+            where
+                F: 'static + for<'b> FnOnce(&'b mut Self::Output),
+            {
+                unsafe { f(std::mem::transmute::<&mut Self, &mut Self::Output>(self)) }
+            }
+        }
+    };
+}
+
+impl_yokeable!(Serverbound);
+impl_yokeable!(Clientbound);
+
+/// Parses a frame in the scope of the protocol version and of the decode limits of a connection.
+pub(super) fn parse_frame<'a, T>(
+    version: ProtocolVersion,
+    limits: DecodeLimits,
+    frame: &'a [u8],
+    parse: impl FnOnce(&'a [u8]) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    version.scope(move || {
+        limits.scope(move || {
+            DecodeLimits::check_packet_size(frame.len())?;
+            parse(frame)
+        })
+    })
+}
+
+/// Reads from the stream, decrypting if needed.
+struct DecryptingReader<'a, S> {
+    stream: &'a mut S,
+    decryptor: Option<&'a mut StreamDecryptor>,
+}
+
+impl<S: Read> Read for DecryptingReader<'_, S> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.stream.read(buf)?;
+        if let Some(decryptor) = &mut self.decryptor {
+            decryptor.decrypt(&mut buf[..read]);
+        }
+        Ok(read)
+    }
+}
+
+/// Wraps a stream and follows the [ConnectionState] transitions of the packets going through it.
+///
+/// The same type is used on both sides of the connection.
+/// A server uses [Connection::receive_serverbound] and [Connection::send_clientbound], while a client uses [Connection::send_serverbound] and [Connection::receive_clientbound].
+/// Compression is enabled automatically when a [SetCompression](login::ClientboundPacket::SetCompression) packet goes through the connection.
+/// Encryption must be enabled with [Connection::enable_encryption] once the shared secret is known.
+/// The [ProtocolVersion] is selected by the [Hello](handshake::ServerboundPacket::Hello) packet.
+/// If the requested one is not supported, it is returned by [Connection::unsupported_protocol] and no packet can be received after the status or the handshake.
+#[derive(Debug)]
+pub struct Connection<S> {
+    stream: S,
+    state: ConnectionState,
+    version: ProtocolVersion,
+    unsupported_protocol: Option<i32>,
+    compression: Option<u32>,
+    encryptor: Option<StreamEncryptor>,
+    decryptor: Option<StreamDecryptor>,
+    limits: DecodeLimits,
+    recorder: Option<Recorder>,
+    buffer: Vec<u8>,
+}
+
+impl<S> Connection<S> {
+    /// Creates a connection in the [ConnectionState::HandShake] state.
+    pub fn new(stream: S) -> Self {
+        Connection {
+            stream,
+            state: ConnectionState::HandShake,
+            version: ProtocolVersion::V1_20_2,
+            unsupported_protocol: None,
+            compression: None,
+            encryptor: None,
+            decryptor: None,
+            limits: DecodeLimits::default(),
+            recorder: None,
+            buffer: Vec::new(),
+        }
+    }
+
+    pub fn state(&self) -> ConnectionState {
+        self.state
+    }
+
+    /// Overrides the state, for connections that do not start with a handshake.
+    pub fn set_state(&mut self, state: ConnectionState) {
+        self.state = state;
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.version
+    }
+
+    /// Overrides the protocol version, for connections that do not start with a handshake.
+    pub fn set_protocol_version(&mut self, version: ProtocolVersion) {
+        self.version = version;
+        self.unsupported_protocol = None;
+    }
+
+    /// The protocol number requested by the [Hello](handshake::ServerboundPacket::Hello) packet, if it is not supported.
+    /// A server should then send a [Disconnect](login::ClientboundPacket::Disconnect) packet and close the connection.
+    pub fn unsupported_protocol(&self) -> Option<i32> {
+        self.unsupported_protocol
+    }
+
+    pub fn compression(&self) -> Option<u32> {
+        self.compression
+    }
+
+    pub fn set_compression(&mut self, compression: Option<u32>) {
+        self.compression = compression;
+    }
+
+    pub fn is_encrypted(&self) -> bool {
+        self.encryptor.is_some()
+    }
+
+    /// Encrypts everything that is sent or received from now on.
+    /// This is to be called right after the [EncryptionResponse](login::ServerboundPacket::EncryptionResponse) is sent or received.
+    pub fn enable_encryption(&mut self, shared_secret: &[u8; SHARED_SECRET_LENGTH]) {
+        self.encryptor = Some(StreamEncryptor::new(shared_secret));
+        self.decryptor = Some(StreamDecryptor::new(shared_secret));
+    }
+
+    pub fn decode_limits(&self) -> DecodeLimits {
+        self.limits
+    }
+
+    /// Sets the limits enforced on received packets, which are [DecodeLimits::default] initially.
+    /// Use [DecodeLimits::unlimited] only if the other side is trusted.
+    pub fn set_decode_limits(&mut self, limits: DecodeLimits) {
+        self.limits = limits;
+    }
+
+    /// Records the packets sent and received from now on, see the [capture](super::capture) module.
+    pub fn set_recorder(&mut self, recorder: Option<Recorder>) {
+        self.recorder = recorder;
+    }
+
+    pub fn take_recorder(&mut self) -> Option<Recorder> {
+        self.recorder.take()
+    }
+
+    pub fn get_ref(&self) -> &S {
+        &self.stream
+    }
+
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.stream
+    }
+
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+impl<S: Read> Connection<S> {
+    /// Reads the next packet without parsing it.
+    /// The returned bytes are decrypted and decompressed, and start with the packet id of the [ProtocolVersion] of the connection.
+    pub fn read_frame(&mut self) -> Result<&[u8], NetworkError> {
+        let reader = DecryptingReader {
+            stream: &mut self.stream,
+            decryptor: self.decryptor.as_mut(),
+        };
+        let compression = self.compression;
+        self.buffer = self.limits.scope(move || read_packet(reader, compression))?;
+        Ok(&self.buffer)
+    }
+
+    /// Reads the next frame, records it and numbers its packet id as in the packet enums.
+    fn receive_frame(&mut self, direction: PacketDirection) -> Result<(), NetworkError> {
+        check_protocol(self.state, self.unsupported_protocol)?;
+        self.read_frame()?;
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record(direction, self.state, self.version, &self.buffer)?;
+        }
+        self.version.frame_to_canonical(self.state, direction, &mut self.buffer)?;
+        Ok(())
+    }
+
+    /// Reads the next packet sent by the client and updates the state accordingly.
+    pub fn receive_serverbound(&mut self) -> Result<Serverbound<'_>, NetworkError> {
+        self.receive_frame(PacketDirection::Serverbound)?;
+        let state = self.state;
+        let packet = parse_frame(self.version, self.limits, &self.buffer, |frame| Serverbound::deserialize(state, frame))?;
+        self.state = packet.next_state();
+        if let Some(protocol) = packet.requested_protocol() {
+            (self.version, self.unsupported_protocol) = select_protocol(protocol, self.version);
+        }
+        Ok(packet)
+    }
+
+    /// Reads the next packet sent by the server and updates the compression accordingly.
+    pub fn receive_clientbound(&mut self) -> Result<Clientbound<'_>, NetworkError> {
+        self.receive_frame(PacketDirection::Clientbound)?;
+        let state = self.state;
+        let packet = parse_frame(self.version, self.limits, &self.buffer, |frame| Clientbound::deserialize(state, frame))?;
+        if let Some(compression) = packet.compression() {
+            self.compression = compression;
+        }
+        Ok(packet)
+    }
+
+    /// Reads the next packet sent by the client into a packet that owns its bytes, and updates the state accordingly.
+    /// Unlike [Connection::receive_serverbound], the packet can be sent to another thread.
+    pub fn receive_serverbound_owned(&mut self) -> Result<Owned<Serverbound<'static>>, NetworkError> {
+        self.receive_frame(PacketDirection::Serverbound)?;
+        let (state, version, limits) = (self.state, self.version, self.limits);
+        let packet: Owned<Serverbound<'static>> = Owned::try_new(std::mem::take(&mut self.buffer), |frame| {
+            parse_frame(version, limits, frame, |frame| Serverbound::deserialize(state, frame))
+        })?;
+        self.state = packet.get().next_state();
+        if let Some(protocol) = packet.get().requested_protocol() {
+            (self.version, self.unsupported_protocol) = select_protocol(protocol, self.version);
+        }
+        Ok(packet)
+    }
+
+    /// Reads the next packet sent by the server into a packet that owns its bytes, and updates the compression accordingly.
+    /// Unlike [Connection::receive_clientbound], the packet can be sent to another thread.
+    pub fn receive_clientbound_owned(&mut self) -> Result<Owned<Clientbound<'static>>, NetworkError> {
+        self.receive_frame(PacketDirection::Clientbound)?;
+        let (state, version, limits) = (self.state, self.version, self.limits);
+        let packet: Owned<Clientbound<'static>> = Owned::try_new(std::mem::take(&mut self.buffer), |frame| {
+            parse_frame(version, limits, frame, |frame| Clientbound::deserialize(state, frame))
+        })?;
+        if let Some(compression) = packet.get().compression() {
+            self.compression = compression;
+        }
+        Ok(packet)
+    }
+
+    /// Iterates over the packets sent by the server, received with [Connection::receive_clientbound_owned].
+    /// The iteration ends after the first error, since the stream cannot be trusted anymore.
+    pub fn clientbound_packets(&mut self) -> impl Iterator<Item = Result<Owned<Clientbound<'static>>, NetworkError>> + '_ {
+        let mut failed = false;
+        std::iter::from_fn(move || {
+            if failed {
+                return None;
+            }
+            let packet = self.receive_clientbound_owned();
+            failed = packet.is_err();
+            Some(packet)
+        })
+    }
+
+    /// Reads the next packet sent by the client without parsing its fields, and updates the state accordingly.
+    /// Packets that this crate cannot parse are returned too, so that they can be forwarded.
+    pub fn receive_raw_serverbound(&mut self) -> Result<RawPacket<'_>, NetworkError> {
+        self.receive_frame(PacketDirection::Serverbound)?;
+        let (state, version, buffer) = (self.state, self.version, &self.buffer);
+        let (state, protocol) = self.limits.scope(move || follow_serverbound(state, version, buffer))?;
+        self.state = state;
+        if let Some(protocol) = protocol {
+            (self.version, self.unsupported_protocol) = select_protocol(protocol, self.version);
+        }
+        let buffer = &self.buffer;
+        Ok(self.version.scope(move || RawPacket::peek(buffer))?)
+    }
+
+    /// Reads the next packet sent by the server without parsing its fields, and updates the compression accordingly.
+    /// Packets that this crate cannot parse are returned too, so that they can be forwarded.
+    pub fn receive_raw_clientbound(&mut self) -> Result<RawPacket<'_>, NetworkError> {
+        self.receive_frame(PacketDirection::Clientbound)?;
+        let (state, version, buffer) = (self.state, self.version, &self.buffer);
+        if let Some(compression) = self.limits.scope(move || follow_clientbound(state, version, buffer)) {
+            self.compression = compression;
+        }
+        let buffer = &self.buffer;
+        Ok(self.version.scope(move || RawPacket::peek(buffer))?)
+    }
+}
+
+impl<S: Write> Connection<S> {
+    /// Sends a serialized packet, starting with its packet id in the [ProtocolVersion] of the connection.
+    pub fn send_frame(&mut self, packet: Vec<u8>) -> Result<(), NetworkError> {
+        let mut frame = Vec::new();
+        send_packet(&mut frame, packet, self.compression)?;
+        if let Some(encryptor) = &mut self.encryptor {
+            encryptor.encrypt(&mut frame);
+        }
+        self.stream.write_all(&frame)?;
+        Ok(())
+    }
+
+    /// Records a frame that starts with the packet id used on the wire, then sends it.
+    fn send_wire_frame(&mut self, direction: PacketDirection, frame: Vec<u8>) -> Result<(), NetworkError> {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record(direction, self.state, self.version, &frame)?;
+        }
+        self.send_frame(frame)
+    }
+
+    /// Sends a packet to the server and updates the state accordingly.
+    pub fn send_serverbound(&mut self, packet: Serverbound) -> Result<(), NetworkError> {
+        if packet.state() != self.state {
+            return Err("The packet does not belong to the current connection state.".into());
+        }
+        packet.check_next_state()?;
+        let next_state = packet.next_state();
+        let protocol = packet.requested_protocol();
+        let mut frame = self.version.scope(|| packet.serialize())?;
+        self.version.frame_to_wire(self.state, PacketDirection::Serverbound, &mut frame)?;
+        self.send_wire_frame(PacketDirection::Serverbound, frame)?;
+        self.state = next_state;
+        if let Some(protocol) = protocol {
+            (self.version, self.unsupported_protocol) = select_protocol(protocol, self.version);
+        }
+        Ok(())
+    }
+
+    /// Sends a packet to the server without parsing its fields, and updates the state accordingly.
+    /// The packet must be encoded for the [ProtocolVersion] of the connection.
+    pub fn send_raw_serverbound(&mut self, packet: RawPacket) -> Result<(), NetworkError> {
+        if packet.version != self.version {
+            return Err("The packet is encoded for another protocol version.".into());
+        }
+        let mut frame = packet.serialize();
+        let (state, protocol) = follow_serverbound(self.state, self.version, &frame)?;
+        self.version.frame_to_wire(self.state, PacketDirection::Serverbound, &mut frame)?;
+        self.send_wire_frame(PacketDirection::Serverbound, frame)?;
+        self.state = state;
+        if let Some(protocol) = protocol {
+            (self.version, self.unsupported_protocol) = select_protocol(protocol, self.version);
+        }
+        Ok(())
+    }
+
+    /// Sends a packet to the client without parsing its fields, and updates the compression accordingly.
+    /// The packet must be encoded for the [ProtocolVersion] of the connection.
+    pub fn send_raw_clientbound(&mut self, packet: RawPacket) -> Result<(), NetworkError> {
+        if packet.version != self.version {
+            return Err("The packet is encoded for another protocol version.".into());
+        }
+        let mut frame = packet.serialize();
+        let compression = follow_clientbound(self.state, self.version, &frame);
+        self.version.frame_to_wire(self.state, PacketDirection::Clientbound, &mut frame)?;
+        self.send_wire_frame(PacketDirection::Clientbound, frame)?;
+        if let Some(compression) = compression {
+            self.compression = compression;
+        }
+        Ok(())
+    }
+
+    /// Sends a packet to the client and updates the compression accordingly.
+    pub fn send_clientbound(&mut self, packet: Clientbound) -> Result<(), NetworkError> {
+        if packet.state() != self.state {
+            return Err("The packet does not belong to the current connection state.".into());
+        }
+        let compression = packet.compression();
+        let mut frame = self.version.scope(|| packet.serialize())?;
+        self.version.frame_to_wire(self.state, PacketDirection::Clientbound, &mut frame)?;
+        self.send_wire_frame(PacketDirection::Clientbound, frame)?;
+        if let Some(compression) = compression {
+            self.compression = compression;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        network::testing::{login_sequence, loopback},
+        packets::{Array, VarInt},
+    };
+
+    #[test]
+    fn serverbound_state_transitions() {
+        let mut server = loopback(ConnectionState::HandShake, |client| {
+            for packet in login_sequence() {
+                client.send_serverbound(packet).unwrap();
+            }
+            assert_eq!(client.state(), ConnectionState::Configuration);
+        });
+        let expected_states = [
+            ConnectionState::Login,
+            ConnectionState::Login,
+            ConnectionState::Configuration,
+            ConnectionState::Play,
+            ConnectionState::Play,
+            ConnectionState::Configuration,
+            ConnectionState::Configuration,
+        ];
+        for (expected, expected_state) in login_sequence().into_iter().zip(expected_states) {
+            assert_eq!(server.receive_serverbound().unwrap(), expected);
+            assert_eq!(server.state(), expected_state);
+        }
+    }
+
+    #[test]
+    fn raw_forwarding_follows_state() {
+        let mut proxy = loopback(ConnectionState::HandShake, |client| {
+            for packet in login_sequence() {
+                client.send_serverbound(packet).unwrap();
+            }
+        });
+
+        // A proxy forwards the packets without parsing them
+        let mut server = loopback(ConnectionState::HandShake, |backend| {
+            for _ in login_sequence() {
+                let packet = proxy.receive_raw_serverbound().unwrap();
+                backend.send_raw_serverbound(packet).unwrap();
+                assert_eq!(backend.state(), proxy.state());
+            }
+        });
+        assert_eq!(proxy.state(), ConnectionState::Configuration);
+        for expected in login_sequence() {
+            assert_eq!(server.receive_serverbound().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn owned_packets_outlive_the_buffer() {
+        let mut server = loopback(ConnectionState::HandShake, |client| {
+            for packet in login_sequence() {
+                client.send_serverbound(packet).unwrap();
+            }
+        });
+        let packets: Vec<_> = login_sequence().iter().map(|_| server.receive_serverbound_owned().unwrap()).collect();
+        assert_eq!(server.state(), ConnectionState::Configuration);
+        drop(server);
+        for (packet, expected) in packets.iter().zip(login_sequence()) {
+            assert_eq!(packet.get(), &expected);
+        }
+    }
+
+    #[test]
+    fn packet_metadata() {
+        use play_clientbound::{ClientboundPacket, ClientboundPacketKind};
+
+        let packet = Clientbound::Play(ClientboundPacket::StepTick { tick_steps: VarInt(1) });
+        assert_eq!(packet.name(), "StepTick");
+        assert_eq!(packet.packet_id(), 0x74);
+        let frame = packet.serialize().unwrap();
+        assert_eq!(frame[0], 0x74);
+
+        assert_eq!(ClientboundPacketKind::ALL[0], ClientboundPacketKind::BundleDelimiter);
+        for (id, kind) in ClientboundPacketKind::ALL.iter().enumerate() {
+            assert_eq!(kind.packet_id(), id as i32);
+        }
+        let keep_alive = play_serverbound::ServerboundPacket::KeepAlive { keep_alive_id: 5 };
+        assert_eq!(keep_alive.kind(), play_serverbound::ServerboundPacketKind::KeepAlive);
+        assert_eq!(keep_alive.kind().name(), "KeepAlive");
+    }
+
+    #[test]
+    fn hello_cannot_skip_login() {
+        let hello = handshake::ServerboundPacket::Hello {
+            protocol_version: VarInt(764),
+            server_address: "localhost",
+            server_port: 25565,
+            next_state: ConnectionState::Play,
+        };
+        let frame = hello.serialize_minecraft_packet().unwrap();
+        assert_eq!(frame.last(), Some(&3));
+
+        let mut server = loopback(ConnectionState::HandShake, |client| {
+            assert!(client.send_serverbound(Serverbound::Handshake(hello)).is_err());
+            assert_eq!(client.state(), ConnectionState::HandShake);
+            client.send_frame(frame).unwrap();
+        });
+        assert!(server.receive_serverbound().is_err());
+        assert_eq!(server.state(), ConnectionState::HandShake);
+    }
+
+    #[test]
+    fn unsupported_protocol() {
+        let hello = |next_state| {
+            Serverbound::Handshake(handshake::ServerboundPacket::Hello {
+                protocol_version: VarInt(766),
+                server_address: "localhost",
+                server_port: 25565,
+                next_state,
+            })
+        };
+        // The status is the same in all versions
+        let mut server = loopback(ConnectionState::HandShake, |client| {
+            client.send_serverbound(hello(ConnectionState::Status)).unwrap();
+            client.send_serverbound(Serverbound::Status(status::ServerboundPacket::Request)).unwrap();
+        });
+        server.receive_serverbound().unwrap();
+        assert_eq!(server.unsupported_protocol(), Some(766));
+        assert_eq!(server.protocol_version(), ProtocolVersion::V1_20_2);
+        server.receive_serverbound().unwrap();
+
+        // The login packets cannot be decoded
+        let mut server = loopback(ConnectionState::HandShake, |client| {
+            client.send_serverbound(hello(ConnectionState::Login)).unwrap();
+            client.send_serverbound(Serverbound::Login(login::ServerboundPacket::LoginAcknowledged)).unwrap();
+            assert_eq!(client.unsupported_protocol(), Some(766));
+        });
+        server.receive_serverbound().unwrap();
+        assert_eq!(server.unsupported_protocol(), Some(766));
+        assert!(server.receive_serverbound().is_err());
+        server.set_protocol_version(ProtocolVersion::V1_20_2);
+        assert!(server.receive_serverbound().is_ok());
+    }
+
+    #[test]
+    fn default_limits() {
+        let username = "a".repeat(40000);
+        let mut server = loopback(ConnectionState::Login, |client| {
+            for _ in 0..2 {
+                let packet = Serverbound::Login(login::ServerboundPacket::LoginStart { username: &username, player_uuid: 0 });
+                client.send_serverbound(packet).unwrap();
+            }
+        });
+
+        // Clients are not trusted unless the limits are lifted
+        assert_eq!(server.decode_limits(), DecodeLimits::default());
+        assert!(server.receive_serverbound().is_err());
+        server.set_decode_limits(DecodeLimits::unlimited());
+        assert!(server.receive_serverbound().is_ok());
+    }
+
+    #[test]
+    fn wrong_state_is_rejected() {
+        let mut client = Connection::new(Vec::new());
+        let packet = Serverbound::Login(login::ServerboundPacket::LoginAcknowledged);
+        assert!(client.send_serverbound(packet).is_err());
+        assert_eq!(client.state(), ConnectionState::HandShake);
+    }
+
+    #[test]
+    fn compression_and_encryption_are_stored() {
+        let shared_secret = [3; SHARED_SECRET_LENGTH];
+        let mut client = loopback(ConnectionState::Login, |server| {
+            server.enable_encryption(&shared_secret);
+            server
+                .send_clientbound(Clientbound::Login(login::ClientboundPacket::SetCompression {
+                    threshold: VarInt(64),
+                }))
+                .unwrap();
+            assert_eq!(server.compression(), Some(64));
+            server
+                .send_clientbound(Clientbound::Login(login::ClientboundPacket::LoginSuccess {
+                    uuid: 1,
+                    username: "a_rather_long_username_to_exceed_the_threshold_of_the_connection",
+                    properties: Array::default(),
+                }))
+                .unwrap();
+        });
+        client.enable_encryption(&shared_secret);
+        assert!(matches!(
+            client.receive_clientbound().unwrap(),
+            Clientbound::Login(login::ClientboundPacket::SetCompression { .. })
+        ));
+        assert_eq!(client.compression(), Some(64));
+        assert!(matches!(
+            client.receive_clientbound().unwrap(),
+            Clientbound::Login(login::ClientboundPacket::LoginSuccess { uuid: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn zero_threshold_compresses_every_packet() {
+        let mut client = loopback(ConnectionState::Login, |server| {
+            server
+                .send_clientbound(Clientbound::Login(login::ClientboundPacket::SetCompression { threshold: VarInt(0) }))
+                .unwrap();
+            assert_eq!(server.compression(), Some(0));
+            server
+                .send_clientbound(Clientbound::Login(login::ClientboundPacket::LoginSuccess {
+                    uuid: 1,
+                    username: "bot",
+                    properties: Array::default(),
+                }))
+                .unwrap();
+        });
+        client.receive_clientbound().unwrap();
+        assert_eq!(client.compression(), Some(0));
+        assert!(matches!(
+            client.receive_clientbound().unwrap(),
+            Clientbound::Login(login::ClientboundPacket::LoginSuccess { uuid: 1, .. })
+        ));
+
+        let disabled = Clientbound::Login(login::ClientboundPacket::SetCompression { threshold: VarInt(-1) });
+        assert_eq!(disabled.compression(), Some(None));
+    }
+}
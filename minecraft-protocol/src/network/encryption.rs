@@ -0,0 +1,191 @@
+//! AES-128-CFB8 stream encryption, as used by the Minecraft protocol once the [EncryptionResponse](crate::packets::login::ServerboundPacket::EncryptionResponse) is sent.
+//!
+//! The shared secret is used both as the key and as the initial vector.
+//! The cipher keeps its state from one packet to the next, so a single [StreamEncryptor] and [StreamDecryptor] must be kept for the whole connection.
+//! See [Protocol Encryption](https://wiki.vg/Protocol_Encryption) for details.
+
+use aes::{
+    cipher::{inout::InOutBuf, typenum::U1, BlockDecryptMut, BlockEncryptMut, KeyIvInit},
+    Aes128,
+};
+use std::io::{Read, Write};
+
+/// The length of the shared secret, in bytes.
+pub const SHARED_SECRET_LENGTH: usize = 16;
+
+/// Encrypts outgoing bytes.
+pub struct StreamEncryptor {
+    cipher: cfb8::Encryptor<Aes128>,
+}
+
+impl StreamEncryptor {
+    pub fn new(shared_secret: &[u8; SHARED_SECRET_LENGTH]) -> Self {
+        StreamEncryptor {
+            cipher: cfb8::Encryptor::new(shared_secret.into(), shared_secret.into()),
+        }
+    }
+
+    /// Encrypts the data in place, advancing the cipher state.
+    pub fn encrypt(&mut self, data: &mut [u8]) {
+        let (blocks, _) = InOutBuf::from(data).into_chunks::<U1>();
+        self.cipher.encrypt_blocks_inout_mut(blocks);
+    }
+}
+
+impl std::fmt::Debug for StreamEncryptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamEncryptor").finish_non_exhaustive()
+    }
+}
+
+/// Decrypts incoming bytes.
+pub struct StreamDecryptor {
+    cipher: cfb8::Decryptor<Aes128>,
+}
+
+impl StreamDecryptor {
+    pub fn new(shared_secret: &[u8; SHARED_SECRET_LENGTH]) -> Self {
+        StreamDecryptor {
+            cipher: cfb8::Decryptor::new(shared_secret.into(), shared_secret.into()),
+        }
+    }
+
+    /// Decrypts the data in place, advancing the cipher state.
+    pub fn decrypt(&mut self, data: &mut [u8]) {
+        let (blocks, _) = InOutBuf::from(data).into_chunks::<U1>();
+        self.cipher.decrypt_blocks_inout_mut(blocks);
+    }
+}
+
+impl std::fmt::Debug for StreamDecryptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamDecryptor").finish_non_exhaustive()
+    }
+}
+
+/// Wraps a reader and decrypts everything read from it.
+#[derive(Debug)]
+pub struct EncryptedReader<R> {
+    inner: R,
+    decryptor: StreamDecryptor,
+}
+
+impl<R: Read> EncryptedReader<R> {
+    pub fn new(inner: R, shared_secret: &[u8; SHARED_SECRET_LENGTH]) -> Self {
+        EncryptedReader {
+            inner,
+            decryptor: StreamDecryptor::new(shared_secret),
+        }
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for EncryptedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.decryptor.decrypt(&mut buf[..read]);
+        Ok(read)
+    }
+}
+
+/// Wraps a writer and encrypts everything written to it.
+#[derive(Debug)]
+pub struct EncryptedWriter<W> {
+    inner: W,
+    encryptor: StreamEncryptor,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> EncryptedWriter<W> {
+    pub fn new(inner: W, shared_secret: &[u8; SHARED_SECRET_LENGTH]) -> Self {
+        EncryptedWriter {
+            inner,
+            encryptor: StreamEncryptor::new(shared_secret),
+            buffer: Vec::new(),
+        }
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for EncryptedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        // The cipher state advances with every encrypted byte, so everything that was encrypted must be written.
+        self.buffer.clear();
+        self.buffer.extend_from_slice(buf);
+        self.encryptor.encrypt(&mut self.buffer);
+        self.inner.write_all(&self.buffer)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aes::cipher::AsyncStreamCipher;
+    use crate::network::{read_packet, send_packet};
+
+    #[test]
+    fn state_is_kept_between_calls() {
+        let shared_secret = [0x42; SHARED_SECRET_LENGTH];
+        let plaintext = b"hello world! this is my plaintext.";
+        let mut expected = plaintext.to_vec();
+        cfb8::Encryptor::<Aes128>::new(&shared_secret.into(), &shared_secret.into()).encrypt(&mut expected);
+
+        let mut data = plaintext.to_vec();
+        let mut encryptor = StreamEncryptor::new(&shared_secret);
+        let (first, second) = data.split_at_mut(5);
+        encryptor.encrypt(first);
+        encryptor.encrypt(second);
+        assert_eq!(data, expected);
+
+        let mut decryptor = StreamDecryptor::new(&shared_secret);
+        let (first, second) = data.split_at_mut(20);
+        decryptor.decrypt(first);
+        decryptor.decrypt(second);
+        assert_eq!(&data, plaintext);
+    }
+
+    #[test]
+    fn stateful_round_trip() {
+        let shared_secret = [7; SHARED_SECRET_LENGTH];
+        let packets: Vec<Vec<u8>> = vec![vec![0, 1, 2, 3], vec![0x27; 300], vec![9]];
+
+        let mut writer = EncryptedWriter::new(Vec::new(), &shared_secret);
+        for packet in &packets {
+            send_packet(&mut writer, packet.clone(), Some(256)).unwrap();
+        }
+        let stream = writer.into_inner();
+        assert!(!stream.starts_with(&[5, 0, 0, 1, 2, 3]));
+
+        let mut reader = EncryptedReader::new(stream.as_slice(), &shared_secret);
+        for packet in &packets {
+            assert_eq!(&read_packet(&mut reader, Some(256)).unwrap(), packet);
+        }
+    }
+}
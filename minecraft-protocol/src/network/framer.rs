@@ -0,0 +1,221 @@
+//! Incremental packet framing, for non-blocking sockets and in-memory pipes.
+//!
+//! A [FrameReader] is fed with chunks of bytes of any size, and yields the complete frames it contains.
+//! Its internal buffer is reused for the whole connection, so no allocation is made per packet.
+
+use super::{
+    encryption::{StreamDecryptor, SHARED_SECRET_LENGTH},
+    frame_length,
+    proxy_protocol::ProxyHeader,
+    NetworkError,
+};
+use crate::packets::limits::DecodeLimits;
+use std::io::Read;
+
+/// The number of bytes requested from the reader by [FrameReader::read_from].
+const READ_CHUNK_SIZE: usize = 8192;
+
+/// Splits a stream of bytes into frames.
+///
+/// The yielded frames do not contain the packet length prefix.
+/// They are still compressed if compression is enabled, see [decompress_packet](super::decompress_packet).
+/// Frames longer than [DecodeLimits::default] allows are rejected unless other limits are set.
+#[derive(Debug, Default)]
+pub struct FrameReader {
+    buffer: Vec<u8>,
+    limits: DecodeLimits,
+    /// The number of bytes at the start of the buffer that belong to frames already yielded.
+    consumed: usize,
+    decryptor: Option<StreamDecryptor>,
+}
+
+impl FrameReader {
+    pub fn new() -> Self {
+        FrameReader::default()
+    }
+
+    pub fn decode_limits(&self) -> DecodeLimits {
+        self.limits
+    }
+
+    /// Use [DecodeLimits::unlimited] only if the other side is trusted.
+    pub fn set_decode_limits(&mut self, limits: DecodeLimits) {
+        self.limits = limits;
+    }
+
+    pub fn is_encrypted(&self) -> bool {
+        self.decryptor.is_some()
+    }
+
+    /// Decrypts all the bytes that have not been yielded yet, and all the bytes that will be pushed later.
+    /// Should be called right after the frame of the [EncryptionResponse](crate::packets::login::ServerboundPacket::EncryptionResponse) is yielded.
+    pub fn enable_encryption(&mut self, shared_secret: &[u8; SHARED_SECRET_LENGTH]) {
+        let mut decryptor = StreamDecryptor::new(shared_secret);
+        decryptor.decrypt(&mut self.buffer[self.consumed..]);
+        self.decryptor = Some(decryptor);
+    }
+
+    /// The number of bytes that are buffered but not yielded yet.
+    pub fn pending(&self) -> usize {
+        self.buffer.len() - self.consumed
+    }
+
+    /// Drops the bytes of the frames that were already yielded, keeping the allocation.
+    fn compact(&mut self) {
+        if self.consumed > 0 {
+            self.buffer.copy_within(self.consumed.., 0);
+            self.buffer.truncate(self.buffer.len() - self.consumed);
+            self.consumed = 0;
+        }
+    }
+
+    /// Appends bytes received from the network.
+    pub fn push(&mut self, data: &[u8]) {
+        self.compact();
+        let start = self.buffer.len();
+        self.buffer.extend_from_slice(data);
+        if let Some(decryptor) = &mut self.decryptor {
+            decryptor.decrypt(&mut self.buffer[start..]);
+        }
+    }
+
+    /// Performs a single read, appending the bytes to the buffer.
+    /// Returns the number of bytes read, which is 0 at the end of the stream.
+    /// Errors of the reader are returned as they are, so that [WouldBlock](std::io::ErrorKind::WouldBlock) can be handled by the caller.
+    pub fn read_from(&mut self, mut reader: impl Read) -> std::io::Result<usize> {
+        self.compact();
+        let start = self.buffer.len();
+        self.buffer.resize(start + READ_CHUNK_SIZE, 0);
+        let read = match reader.read(&mut self.buffer[start..]) {
+            Ok(read) => read,
+            Err(e) => {
+                self.buffer.truncate(start);
+                return Err(e);
+            }
+        };
+        self.buffer.truncate(start + read);
+        if let Some(decryptor) = &mut self.decryptor {
+            decryptor.decrypt(&mut self.buffer[start..]);
+        }
+        Ok(read)
+    }
+
+    /// Strips the [PROXY protocol](super::proxy_protocol) header at the start of the stream.
+    /// Returns `None` if more bytes are needed, and an error if the stream does not start with a header.
+    /// This is to be called until it returns the header, before any frame is read.
+    pub fn take_proxy_header(&mut self) -> Result<Option<ProxyHeader>, NetworkError> {
+        match ProxyHeader::parse(&self.buffer[self.consumed..])? {
+            Some((header, len)) => {
+                self.consumed += len;
+                Ok(Some(header))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the next complete frame, or `None` if more bytes are needed.
+    pub fn next_frame(&mut self) -> Result<Option<&[u8]>, NetworkError> {
+        let pending = &self.buffer[self.consumed..];
+        let (prefix_len, len) = match self.limits.scope(|| frame_length(pending))? {
+            Some(length) => length,
+            None => return Ok(None),
+        };
+        let start = self.consumed + prefix_len;
+        if self.buffer.len() < start + len {
+            return Ok(None);
+        }
+        self.consumed = start + len;
+        Ok(Some(&self.buffer[start..start + len]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::{compress_packet, decompress_packet, encryption::EncryptedWriter, send_packet};
+
+    #[test]
+    fn arbitrary_chunks() {
+        let packets: Vec<Vec<u8>> = vec![vec![0, 1, 2, 3], vec![0x27; 300], vec![9], vec![0x42; 20000]];
+        let mut stream = Vec::new();
+        for packet in &packets {
+            send_packet(&mut stream, packet.clone(), None).unwrap();
+        }
+
+        for chunk_size in [1, 2, 3, 7, 301, stream.len()] {
+            let mut framer = FrameReader::new();
+            let mut received = Vec::new();
+            for chunk in stream.chunks(chunk_size) {
+                framer.push(chunk);
+                while let Some(frame) = framer.next_frame().unwrap() {
+                    received.push(frame.to_vec());
+                }
+            }
+            assert_eq!(received, packets);
+            assert_eq!(framer.pending(), 0);
+        }
+    }
+
+    #[test]
+    fn frames_are_limited() {
+        let mut stream = Vec::new();
+        send_packet(&mut stream, vec![0x42; 300], None).unwrap();
+
+        let mut framer = FrameReader::new();
+        framer.set_decode_limits(DecodeLimits {
+            max_packet_size: 256,
+            ..Default::default()
+        });
+        framer.push(&stream);
+        assert!(framer.next_frame().is_err());
+
+        // A compressed frame cannot claim more than the limits either
+        let frame = compress_packet(&[0x42; 300], 256).unwrap();
+        let limits = DecodeLimits {
+            max_packet_size: 256,
+            ..Default::default()
+        };
+        assert!(limits.scope(|| decompress_packet(&frame, 256)).is_err());
+        assert!(decompress_packet(&frame, 256).is_ok());
+    }
+
+    #[test]
+    fn read_from_reader() {
+        let mut stream = Vec::new();
+        for i in 0..100 {
+            send_packet(&mut stream, vec![i; i as usize * 10], Some(64)).unwrap();
+        }
+
+        let mut framer = FrameReader::new();
+        let mut reader = stream.as_slice();
+        let mut count = 0;
+        while framer.read_from(&mut reader).unwrap() > 0 {
+            while let Some(frame) = framer.next_frame().unwrap() {
+                let packet = crate::network::decompress_packet(frame, 64).unwrap();
+                assert_eq!(packet, vec![count; count as usize * 10]);
+                count += 1;
+            }
+        }
+        assert_eq!(count, 100);
+    }
+
+    #[test]
+    fn encryption_switch() {
+        let shared_secret = [3; SHARED_SECRET_LENGTH];
+        let mut stream = Vec::new();
+        send_packet(&mut stream, vec![1, 2, 3], None).unwrap();
+        let mut writer = EncryptedWriter::new(&mut stream, &shared_secret);
+        send_packet(&mut writer, vec![4, 5, 6], None).unwrap();
+        send_packet(&mut writer, vec![7; 50], None).unwrap();
+
+        // The first encrypted bytes arrive along with the last plain frame
+        let mut framer = FrameReader::new();
+        framer.push(&stream[..10]);
+        assert_eq!(framer.next_frame().unwrap(), Some(&[1, 2, 3][..]));
+        framer.enable_encryption(&shared_secret);
+        framer.push(&stream[10..]);
+        assert_eq!(framer.next_frame().unwrap(), Some(&[4, 5, 6][..]));
+        assert_eq!(framer.next_frame().unwrap(), Some(&[7; 50][..]));
+        assert_eq!(framer.next_frame().unwrap(), None);
+    }
+}
@@ -0,0 +1,260 @@
+//! The server list ping of clients older than 1.7, still sent by some launchers and crawlers.
+//!
+//! A legacy ping is recognized by its first byte, see [is_legacy_ping].
+//! The server answers with a kick packet containing the status, and closes the connection.
+//! See [Server List Ping](https://wiki.vg/Server_List_Ping#1.6) for details.
+
+use super::NetworkError;
+use crate::packets::status::StatusResponse;
+use std::io::{Read, Write};
+
+/// The id of the kick packet of the legacy protocol.
+const KICK_PACKET_ID: u8 = 0xFF;
+
+/// The protocol version sent by [legacy_ping], the one of 1.6.4.
+const LEGACY_PROTOCOL_VERSION: u8 = 78;
+
+/// Returns true if the first byte of a connection starts a legacy ping instead of a handshake.
+/// This is what the Notchian server checks.
+pub fn is_legacy_ping(first_byte: u8) -> bool {
+    first_byte == 0xFE
+}
+
+/// A legacy ping request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LegacyPing {
+    /// Sent by clients older than 1.4, as the single byte `0xFE`.
+    Beta,
+    /// Sent by 1.4 and 1.5 clients, as `0xFE 0x01`.
+    V1_4,
+    /// Sent by 1.6 clients, followed by a plugin message with the address they connect to.
+    V1_6 {
+        protocol_version: u8,
+        server_address: String,
+        server_port: u16,
+    },
+}
+
+fn read_u16(input: &[u8]) -> Option<(u16, &[u8])> {
+    match input {
+        [a, b, rest @ ..] => Some((u16::from_be_bytes([*a, *b]), rest)),
+        _ => None,
+    }
+}
+
+/// Reads a string prefixed by its length in UTF-16 code units.
+fn read_utf16(input: &[u8]) -> Result<Option<(String, &[u8])>, &'static str> {
+    let (len, input) = match read_u16(input) {
+        Some(result) => result,
+        None => return Ok(None),
+    };
+    let len = len as usize * 2;
+    if input.len() < len {
+        return Ok(None);
+    }
+    let (bytes, input) = input.split_at(len);
+    let units = bytes.chunks_exact(2).map(|unit| u16::from_be_bytes([unit[0], unit[1]]));
+    let string = char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .map_err(|_| "Invalid UTF-16 string in legacy ping")?;
+    Ok(Some((string, input)))
+}
+
+fn write_utf16(string: &str, output: &mut Vec<u8>) {
+    let units: Vec<u16> = string.encode_utf16().collect();
+    output.extend_from_slice(&(units.len() as u16).to_be_bytes());
+    for unit in units {
+        output.extend_from_slice(&unit.to_be_bytes());
+    }
+}
+
+impl LegacyPing {
+    /// Parses the bytes received at the start of a connection.
+    /// Returns `None` if more bytes are needed, which only happens for the 1.6 format.
+    ///
+    /// Older clients send a shorter request and wait for the response, so this should be called on the bytes that are available after a read rather than waiting for more.
+    pub fn parse(input: &[u8]) -> Result<Option<LegacyPing>, &'static str> {
+        let input = match input {
+            [] => return Ok(None),
+            [0xFE] => return Ok(Some(LegacyPing::Beta)),
+            [0xFE, 0x01] => return Ok(Some(LegacyPing::V1_4)),
+            [0xFE, 0x01, 0xFA, rest @ ..] => rest,
+            [0xFE, 0x01, ..] => return Ok(Some(LegacyPing::V1_4)),
+            _ => return Err("Not a legacy ping"),
+        };
+
+        let (channel, input) = match read_utf16(input)? {
+            Some(result) => result,
+            None => return Ok(None),
+        };
+        if channel != "MC|PingHost" {
+            return Err("Unexpected plugin channel in legacy ping");
+        }
+        let (len, input) = match read_u16(input) {
+            Some(result) => result,
+            None => return Ok(None),
+        };
+        if input.len() < len as usize {
+            return Ok(None);
+        }
+        let (protocol_version, input) = input.split_first().ok_or("Missing protocol version in legacy ping")?;
+        let (server_address, input) = read_utf16(input)?.ok_or("Invalid server address in legacy ping")?;
+        let server_port = match input {
+            [a, b, c, d, ..] => i32::from_be_bytes([*a, *b, *c, *d]),
+            _ => return Err("Missing server port in legacy ping"),
+        };
+
+        Ok(Some(LegacyPing::V1_6 {
+            protocol_version: *protocol_version,
+            server_address,
+            server_port: server_port as u16,
+        }))
+    }
+}
+
+/// The status returned by a server to a legacy ping.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacyStatus {
+    /// Unknown if the server answered in the format of clients older than 1.4.
+    pub protocol_version: Option<i32>,
+    pub version_name: Option<String>,
+    pub motd: String,
+    pub online_players: i32,
+    pub max_players: i32,
+}
+
+impl LegacyStatus {
+    /// Extracts the fields of a status, formatting the description as plain text.
+    pub fn from_status(status: &StatusResponse) -> LegacyStatus {
+        let (online_players, max_players) = match &status.players {
+            Some(players) => (players.online, players.max),
+            None => (0, 0),
+        };
+        LegacyStatus {
+            protocol_version: Some(status.version.protocol),
+            version_name: Some(status.version.name.clone()),
+            motd: status.description.as_ref().map(|description| description.to_plain_text()).unwrap_or_default(),
+            online_players,
+            max_players,
+        }
+    }
+
+    /// Encodes the kick packet answering a legacy ping.
+    /// Clients older than 1.4 expect a different format, without the version.
+    pub fn to_kick_packet(&self, ping: &LegacyPing) -> Vec<u8> {
+        let message = match ping {
+            LegacyPing::Beta => {
+                // The section sign is the separator, so it cannot appear in the fields
+                let motd: String = self.motd.chars().filter(|c| *c != '§').collect();
+                format!("{}§{}§{}", motd, self.online_players, self.max_players)
+            }
+            LegacyPing::V1_4 | LegacyPing::V1_6 { .. } => format!(
+                "§1\0{}\0{}\0{}\0{}\0{}",
+                self.protocol_version.unwrap_or(-1),
+                self.version_name.as_deref().unwrap_or_default(),
+                self.motd,
+                self.online_players,
+                self.max_players
+            ),
+        };
+        let mut packet = vec![KICK_PACKET_ID];
+        write_utf16(&message, &mut packet);
+        packet
+    }
+
+    /// Parses the kick packet answering a legacy ping.
+    pub fn parse_kick_packet(input: &[u8]) -> Result<LegacyStatus, &'static str> {
+        let input = match input.split_first() {
+            Some((&KICK_PACKET_ID, input)) => input,
+            _ => return Err("The response to a legacy ping should be a kick packet"),
+        };
+        let (message, _) = read_utf16(input)?.ok_or("Incomplete legacy ping response")?;
+
+        if let Some(message) = message.strip_prefix("§1\0") {
+            let fields: Vec<&str> = message.split('\0').collect();
+            if let [protocol_version, version_name, motd, online_players, max_players] = fields.as_slice() {
+                return Ok(LegacyStatus {
+                    protocol_version: Some(protocol_version.parse().map_err(|_| "Invalid protocol version in legacy ping response")?),
+                    version_name: Some(version_name.to_string()),
+                    motd: motd.to_string(),
+                    online_players: online_players.parse().map_err(|_| "Invalid player count in legacy ping response")?,
+                    max_players: max_players.parse().map_err(|_| "Invalid player count in legacy ping response")?,
+                });
+            }
+            return Err("Invalid number of fields in legacy ping response");
+        }
+
+        let mut fields = message.rsplitn(3, '§');
+        let max_players = fields.next().and_then(|field| field.parse().ok());
+        let online_players = fields.next().and_then(|field| field.parse().ok());
+        match (fields.next(), online_players, max_players) {
+            (Some(motd), Some(online_players), Some(max_players)) => Ok(LegacyStatus {
+                protocol_version: None,
+                version_name: None,
+                motd: motd.to_string(),
+                online_players,
+                max_players,
+            }),
+            _ => Err("Invalid legacy ping response"),
+        }
+    }
+}
+
+/// Encodes the kick packet answering a legacy ping, from the same status as modern pings.
+pub fn legacy_kick_response(ping: &LegacyPing, status: &StatusResponse) -> Vec<u8> {
+    LegacyStatus::from_status(status).to_kick_packet(ping)
+}
+
+/// Encodes the request sent by 1.6 clients.
+pub fn legacy_ping_request(server_address: &str, server_port: u16) -> Vec<u8> {
+    let mut payload = vec![LEGACY_PROTOCOL_VERSION];
+    write_utf16(server_address, &mut payload);
+    payload.extend_from_slice(&(server_port as i32).to_be_bytes());
+
+    let mut request = vec![0xFE, 0x01, 0xFA];
+    write_utf16("MC|PingHost", &mut request);
+    request.extend_from_slice(&(payload.len() as u16).to_be_bytes());
+    request.extend_from_slice(&payload);
+    request
+}
+
+/// Pings a server the way 1.6 clients do, which is understood by servers of any version.
+/// The stream is read until the server closes it.
+pub fn legacy_ping(mut stream: impl Read + Write, server_address: &str, server_port: u16) -> Result<LegacyStatus, NetworkError> {
+    stream.write_all(&legacy_ping_request(server_address, server_port))?;
+    stream.flush()?;
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response)?;
+    Ok(LegacyStatus::parse_kick_packet(&response)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_ping_round_trip() {
+        let request = legacy_ping_request("localhost", 25565);
+        assert!(is_legacy_ping(request[0]));
+        assert_eq!(LegacyPing::parse(&request[..10]), Ok(None));
+        let ping = LegacyPing::parse(&request).unwrap().unwrap();
+        assert_eq!(ping, LegacyPing::V1_6 {
+            protocol_version: LEGACY_PROTOCOL_VERSION,
+            server_address: "localhost".to_string(),
+            server_port: 25565,
+        });
+        assert_eq!(LegacyPing::parse(&[0xFE, 0x01]), Ok(Some(LegacyPing::V1_4)));
+
+        let status = StatusResponse::builder("1.20.4", 765).players(20, 3).description("A §aMinecraft Server").build();
+        let response = legacy_kick_response(&ping, &status);
+        assert_eq!(&response[..5], &[0xFF, 0x00, 0x27, 0x00, 0xA7]);
+        let legacy_status = LegacyStatus::parse_kick_packet(&response).unwrap();
+        assert_eq!(legacy_status, LegacyStatus::from_status(&status));
+
+        let response = legacy_kick_response(&LegacyPing::Beta, &status);
+        let legacy_status = LegacyStatus::parse_kick_packet(&response).unwrap();
+        assert_eq!(legacy_status.motd, "A aMinecraft Server");
+        assert_eq!((legacy_status.online_players, legacy_status.max_players), (3, 20));
+        assert_eq!(legacy_status.protocol_version, None);
+    }
+}
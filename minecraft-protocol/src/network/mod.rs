@@ -0,0 +1,360 @@
+#[cfg(feature = "tokio")]
+pub mod codec;
+#[cfg(feature = "auth")]
+pub mod auth;
+pub mod bundle;
+pub mod bungeecord;
+pub mod capture;
+pub mod connection;
+pub mod encryption;
+pub mod framer;
+pub mod legacy;
+pub mod proxy;
+pub mod proxy_protocol;
+#[cfg(test)]
+pub(crate) mod testing;
+pub mod velocity;
+
+use crate::packets::{
+    limits::DecodeLimits,
+    serializer::{MinecraftPacketPart, ParseError},
+    VarInt,
+};
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
+use std::{
+    convert::TryInto,
+    io::{Read, Write},
+};
+
+#[derive(Debug)]
+pub enum NetworkError {
+    Custom(&'static str),
+    Parse(ParseError),
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetworkError::Custom(e) => write!(f, "{}", e),
+            NetworkError::Parse(e) => write!(f, "{}", e),
+            NetworkError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+impl From<std::io::Error> for NetworkError {
+    fn from(e: std::io::Error) -> Self {
+        NetworkError::Io(e)
+    }
+}
+
+impl From<ParseError> for NetworkError {
+    fn from(e: ParseError) -> Self {
+        NetworkError::Parse(e)
+    }
+}
+
+impl From<&'static str> for NetworkError {
+    fn from(e: &'static str) -> Self {
+        NetworkError::Custom(e)
+    }
+}
+
+/// Packets whose uncompressed length exceeds this value are rejected, as the Notchian server does.
+pub const MAX_UNCOMPRESSED_PACKET_SIZE: usize = 8_388_608;
+
+/// The length prefix of a frame is a VarInt of at most 3 bytes, as read by the Notchian server.
+pub const MAX_FRAME_SIZE: usize = 2_097_151;
+
+/// Reads the length prefix at the start of a buffer that may contain an incomplete frame.
+/// Returns the size of the prefix and the length of the frame that follows it, or `None` if more bytes are needed to know.
+/// Frames longer than the [DecodeLimits] in scope, or the default ones, are rejected.
+pub(crate) fn frame_length(buffer: &[u8]) -> Result<Option<(usize, usize)>, NetworkError> {
+    let mut length: usize = 0;
+    for (idx, byte) in buffer.iter().enumerate().take(3) {
+        length |= ((byte & 0b0111_1111) as usize) << (7 * idx);
+        if byte & 0b1000_0000 == 0 {
+            DecodeLimits::scope_or_default(|| DecodeLimits::check_packet_size(length))?;
+            return Ok(Some((idx + 1, length)));
+        }
+    }
+    match buffer.len() < 3 {
+        true => Ok(None),
+        false => Err("Lenght too long".into()),
+    }
+}
+
+/// Reads a single packet from a blocking reader.
+/// See [FrameReader](framer::FrameReader) for non-blocking readers.
+/// Packets larger than the [DecodeLimits] in scope, or the default ones, are rejected before being read.
+pub fn read_packet(
+    mut reader: impl Read,
+    compression: Option<u32>,
+) -> Result<Vec<u8>, NetworkError> {
+    let mut lenght: Vec<u8> = Vec::with_capacity(2);
+
+    loop {
+        if lenght.len() >= 5 {
+            return Err("Lenght too long".into());
+        }
+        let mut byte = [0];
+        reader.read_exact(&mut byte)?;
+        lenght.push(byte[0]);
+        if byte[0] < 0b1000_0000 {
+            break;
+        }
+    }
+
+    let lenght = VarInt::deserialize_uncompressed_minecraft_packet(lenght.as_mut_slice())?;
+    if lenght.0 < 0 {
+        return Err("Negative packet lenght!".into());
+    }
+    let len = lenght.0 as usize;
+    if len > MAX_FRAME_SIZE {
+        return Err("Packet too long".into());
+    }
+    DecodeLimits::scope_or_default(|| DecodeLimits::check_packet_size(len))?;
+    let mut data: Vec<u8> = vec![0; len];
+    reader.read_exact(&mut data)?;
+
+    match compression {
+        None => Ok(data),
+        Some(threshold) => decompress_packet(&data, threshold),
+    }
+}
+
+/// Extracts the packet id and data from a frame in the compressed packet format.
+/// The input must not contain the packet length prefix.
+/// Packets larger than the [DecodeLimits] in scope, or the default ones, are rejected before being inflated.
+pub fn decompress_packet(frame: &[u8], threshold: u32) -> Result<Vec<u8>, NetworkError> {
+    let (data_length, compressed) = VarInt::deserialize_minecraft_packet_part(frame)?;
+    if data_length.0 < 0 {
+        return Err("Negative data lenght!".into());
+    }
+    let data_length = data_length.0 as usize;
+
+    // A data length of 0 means that the packet is not compressed
+    if data_length == 0 {
+        return Ok(compressed.to_vec());
+    }
+    if data_length < threshold as usize {
+        return Err("Compressed packet is below the compression threshold".into());
+    }
+    if DecodeLimits::scope_or_default(|| DecodeLimits::check_packet_size(data_length)).is_err() {
+        return Err("Compressed packet is too large".into());
+    }
+
+    let mut data = Vec::with_capacity(data_length);
+    ZlibDecoder::new(compressed)
+        .take(data_length as u64 + 1)
+        .read_to_end(&mut data)?;
+    if data.len() != data_length {
+        return Err("Decompressed packet does not match its data lenght".into());
+    }
+
+    Ok(data)
+}
+
+/// Builds a frame in the compressed packet format, without the packet length prefix.
+/// Packets smaller than the threshold are stored uncompressed, as required by the Notchian server.
+pub fn compress_packet(packet: &[u8], threshold: u32) -> Result<Vec<u8>, NetworkError> {
+    let mut frame = Vec::new();
+    if packet.len() < threshold as usize {
+        VarInt(0).serialize_minecraft_packet_part(&mut frame)?;
+        frame.extend_from_slice(packet);
+        return Ok(frame);
+    }
+
+    let data_length = VarInt(packet.len().try_into().map_err(|_| "Packet too long")?);
+    data_length.serialize_minecraft_packet_part(&mut frame)?;
+    let mut encoder = ZlibEncoder::new(frame, Compression::default());
+    encoder.write_all(packet)?;
+
+    Ok(encoder.finish()?)
+}
+
+/// Serializes a packet into a frame ready to be written, length prefix included.
+/// The size of the packet is computed first, so that packets below the compression threshold are written without an intermediate buffer.
+/// The packet is only borrowed, so the same packet can be framed for several connections.
+pub fn serialize_frame<'a>(packet: &impl MinecraftPacketPart<'a>, compression: Option<u32>) -> Result<Vec<u8>, NetworkError> {
+    let size = packet.serialized_size();
+    if let Some(threshold) = compression.filter(|threshold| size >= *threshold as usize) {
+        let packet = compress_packet(&packet.serialize_minecraft_packet()?, threshold)?;
+        let len = VarInt(packet.len().try_into().map_err(|_| "Packet too long")?);
+        let mut frame = Vec::with_capacity(len.serialized_size() + packet.len());
+        len.serialize_minecraft_packet_part(&mut frame)?;
+        frame.extend_from_slice(&packet);
+        return Ok(frame);
+    }
+
+    // Uncompressed packets are prefixed by a data length of 0 once compression is enabled
+    let data_length = compression.map(|_| VarInt(0));
+    let len = size + data_length.as_ref().map(|data_length| data_length.serialized_size()).unwrap_or(0);
+    let len = VarInt(len.try_into().map_err(|_| "Packet too long")?);
+    let frame_len = len.serialized_size() + len.0 as usize;
+    let mut frame = Vec::with_capacity(frame_len);
+    len.serialize_minecraft_packet_part(&mut frame)?;
+    if let Some(data_length) = data_length {
+        data_length.serialize_minecraft_packet_part(&mut frame)?;
+    }
+    packet.serialize_minecraft_packet_part(&mut frame)?;
+    if frame.len() != frame_len {
+        return Err("The packet size was not computed correctly.".into());
+    }
+    Ok(frame)
+}
+
+pub fn send_packet(
+    mut writer: impl Write,
+    packet: Vec<u8>,
+    compression: Option<u32>,
+) -> Result<(), NetworkError> {
+    let packet = match compression {
+        None => packet,
+        Some(threshold) => compress_packet(&packet, threshold)?,
+    };
+    let mut packet_prefix = Vec::new();
+    let len = VarInt(packet.len().try_into().map_err(|_| "Packet too long")?);
+    len.serialize_minecraft_packet_part(&mut packet_prefix)?;
+    writer.write_all(&packet_prefix)?;
+    writer.write_all(&packet)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compression_round_trip() {
+        let small_packet = crate::packets::play_serverbound::ServerboundPacket::KeepAlive { keep_alive_id: 42 }
+            .serialize_minecraft_packet()
+            .unwrap();
+        let big_packet = include_bytes!("../../tests/chunk2.dump").to_vec();
+
+        for packet in [small_packet, big_packet] {
+            let mut stream = Vec::new();
+            send_packet(&mut stream, packet.clone(), Some(256)).unwrap();
+            let received = read_packet(stream.as_slice(), Some(256)).unwrap();
+            assert_eq!(received, packet);
+        }
+    }
+
+    #[test]
+    fn frames_match_sent_packets() {
+        let small_packet = crate::packets::play_serverbound::ServerboundPacket::KeepAlive { keep_alive_id: 42 };
+        let big_packet = crate::packets::play_clientbound::ClientboundPacket::PluginMessage {
+            identifier: "minecraft:brand",
+            data: crate::packets::RawBytes { data: &[0x27; 300] },
+        };
+        assert_eq!(small_packet.serialized_size(), small_packet.serialize_minecraft_packet().unwrap().len());
+        assert_eq!(big_packet.serialized_size(), big_packet.serialize_minecraft_packet().unwrap().len());
+
+        for compression in [None, Some(256)] {
+            let mut stream = Vec::new();
+            send_packet(&mut stream, small_packet.serialize_minecraft_packet().unwrap(), compression).unwrap();
+            assert_eq!(serialize_frame(&small_packet, compression).unwrap(), stream);
+
+            let frame = serialize_frame(&big_packet, compression).unwrap();
+            let received = read_packet(frame.as_slice(), compression).unwrap();
+            assert_eq!(received, big_packet.serialize_minecraft_packet().unwrap());
+        }
+    }
+
+    #[test]
+    fn compression_threshold() {
+        let packet = vec![0x27; 300];
+
+        let frame = compress_packet(&packet[..10], 256).unwrap();
+        assert_eq!(frame[0], 0);
+        assert_eq!(&frame[1..], &packet[..10]);
+
+        let frame = compress_packet(&packet, 256).unwrap();
+        assert_eq!(&frame[..2], &[0xAC, 0x02]);
+        assert!(frame.len() < packet.len());
+        assert!(decompress_packet(&frame, 512).is_err());
+        assert_eq!(decompress_packet(&frame, 256).unwrap(), packet);
+    }
+
+    #[test]
+    #[ignore]
+    fn old_test() {
+        use std::net::TcpStream;
+
+        let mut stream = TcpStream::connect("127.0.0.1:25565").unwrap();
+
+        send_packet(
+            &mut stream,
+            crate::packets::handshake::ServerboundPacket::Hello {
+                protocol_version: 756.into(),
+                server_address: "127.0.0.1",
+                server_port: 25565,
+                next_state: crate::packets::ConnectionState::Login,
+            }
+            .serialize_minecraft_packet()
+            .unwrap(),
+            None,
+        )
+        .unwrap();
+
+        send_packet(
+            &mut stream,
+            crate::packets::login::ServerboundPacket::LoginStart { username: "bot2", player_uuid: 0 }
+                .serialize_minecraft_packet()
+                .unwrap(),
+            None,
+        )
+        .unwrap();
+
+        let response = read_packet(&stream, None).unwrap();
+        let response_packet =
+            crate::packets::login::ClientboundPacket::deserialize_uncompressed_minecraft_packet(
+                &response,
+            )
+            .unwrap();
+        println!("{:?}", response_packet);
+
+        use crate::packets::{
+            play_clientbound::ClientboundPacket, play_serverbound::ServerboundPacket,
+        };
+
+        loop {
+            let packet_bytes = read_packet(&stream, None).unwrap();
+            let packet =
+                ClientboundPacket::deserialize_uncompressed_minecraft_packet(&packet_bytes);
+            let packet = match packet {
+                Ok(packet) => packet,
+                Err(e) => panic!("{} for {:?}", e, packet_bytes),
+            };
+            match packet {
+                ClientboundPacket::KeepAlive { keep_alive_id } => {
+                    send_packet(
+                        &mut stream,
+                        ServerboundPacket::KeepAlive { keep_alive_id }
+                            .serialize_minecraft_packet()
+                            .unwrap(),
+                        None,
+                    )
+                    .unwrap();
+                    println!("pong!");
+                }
+                ClientboundPacket::UpdateAdvancements { .. } => {
+                    println!("Advancements parsed successfully!")
+                }
+                ClientboundPacket::ChatMessage {
+                    message,
+                    sender,
+                    ..
+                } => {
+                    println!("{}: {}", sender, message);
+                }
+
+                _ => (),
+            }
+        }
+    }
+}
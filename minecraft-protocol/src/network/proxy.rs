@@ -0,0 +1,546 @@
+//! A man-in-the-middle proxy that lets hooks inspect, modify, drop and inject packets.
+//!
+//! The proxy terminates both connections: it logs in to the server on behalf of the player, in offline mode, and logs the player in with its own compression threshold.
+//! Once both sides are in the [ConnectionState::Configuration] state, the packets of each direction are parsed and given to the hooks of that direction before being forwarded.
+//! Status requests are relayed through the hooks too.
+//!
+//! ```no_run
+//! use minecraft_protocol::{
+//!     network::{
+//!         connection::{Clientbound, Serverbound},
+//!         proxy::{Proxy, Verdict},
+//!     },
+//!     packets::{play_clientbound::ClientboundPacket, play_serverbound::ServerboundPacket, Chat},
+//! };
+//! use std::net::{TcpListener, TcpStream};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let listener = TcpListener::bind("127.0.0.1:25566")?;
+//! for client in listener.incoming() {
+//!     let (client, server) = (client?, TcpStream::connect("127.0.0.1:25565")?);
+//!     std::thread::spawn(move || {
+//!         Proxy::new()
+//!             .compression(256)
+//!             .on_serverbound(|packet, context| match packet {
+//!                 Serverbound::Play(ServerboundPacket::ChatMessage { message, .. }) if message.contains("spam") => {
+//!                     let reason = Chat::Json(r#"{"text":"No spam"}"#);
+//!                     let _ = context.send_to_client(Clientbound::Play(ClientboundPacket::Disconnect { reason }));
+//!                     Verdict::Drop
+//!                 }
+//!                 _ => Verdict::Forward,
+//!             })
+//!             .run(client, server)
+//!     });
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{
+    capture::Recorder,
+    connection::{parse_frame, Clientbound, Connection, Serverbound},
+    read_packet, send_packet, NetworkError,
+};
+use crate::packets::{
+    limits::DecodeLimits,
+    login::{self, GameProfile, ProfileProperty, ServerLogin},
+    version::{PacketDirection, ProtocolVersion},
+    ConnectionState,
+};
+use std::{
+    io::{BufReader, ErrorKind},
+    net::{Shutdown, TcpStream},
+    sync::Mutex,
+};
+
+/// What a hook decides to do with the packet it intercepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// Passes the packet to the next hook and then to its destination.
+    /// The bytes received are forwarded unchanged, unless another hook returns [Verdict::Replace].
+    Forward,
+    /// Passes the packet, modified by the hook, to the next hook and then to its destination.
+    /// The packet is serialized again instead of forwarding the bytes received.
+    Replace,
+    /// Discards the packet. The next hooks do not see it.
+    Drop,
+}
+
+type ServerboundHook = Box<dyn FnMut(&mut Serverbound<'_>, &mut Context<'_>) -> Verdict + Send>;
+type ClientboundHook = Box<dyn FnMut(&mut Clientbound<'_>, &mut Context<'_>) -> Verdict + Send>;
+
+/// Gives hooks information about the connection and lets them inject packets.
+#[derive(Debug)]
+pub struct Context<'a> {
+    state: ConnectionState,
+    version: ProtocolVersion,
+    profile: Option<&'a GameProfile>,
+    to_client: Vec<Vec<u8>>,
+    to_server: Vec<Vec<u8>>,
+}
+
+impl<'a> Context<'a> {
+    fn new(state: ConnectionState, version: ProtocolVersion, profile: Option<&'a GameProfile>) -> Self {
+        Context {
+            state,
+            version,
+            profile,
+            to_client: Vec::new(),
+            to_server: Vec::new(),
+        }
+    }
+
+    /// The state of the intercepted packet.
+    pub fn state(&self) -> ConnectionState {
+        self.state
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.version
+    }
+
+    /// The profile of the player, unless the connection is a status request.
+    pub fn profile(&self) -> Option<&GameProfile> {
+        self.profile
+    }
+
+    /// Sends a packet to the client before the intercepted packet is forwarded.
+    pub fn send_to_client(&mut self, packet: Clientbound) -> Result<(), NetworkError> {
+        if packet.state() != self.state {
+            return Err("The packet does not belong to the current connection state.".into());
+        }
+        let mut frame = self.version.scope(|| packet.serialize())?;
+        self.version.frame_to_wire(self.state, PacketDirection::Clientbound, &mut frame)?;
+        self.to_client.push(frame);
+        Ok(())
+    }
+
+    /// Sends a packet to the server before the intercepted packet is forwarded.
+    /// The packet must not change the state of the connection.
+    pub fn send_to_server(&mut self, packet: Serverbound) -> Result<(), NetworkError> {
+        if packet.state() != self.state {
+            return Err("The packet does not belong to the current connection state.".into());
+        }
+        if packet.next_state() != self.state {
+            return Err("Injected packets cannot change the state of the connection.".into());
+        }
+        let mut frame = self.version.scope(|| packet.serialize())?;
+        self.version.frame_to_wire(self.state, PacketDirection::Serverbound, &mut frame)?;
+        self.to_server.push(frame);
+        Ok(())
+    }
+}
+
+/// A proxy between one client and one server, see the [module documentation](self).
+///
+/// Hooks run in the order they were added, on one thread per direction.
+/// Packets are modified through the mutable reference given to hooks, which must then return [Verdict::Replace] for the modified packet to be sent.
+/// Packets can also be replaced by dropping them and injecting another packet.
+/// Packets that cannot be parsed, or whose id is unknown, are forwarded unchanged without going through the hooks.
+/// The server must be in offline mode.
+pub struct Proxy {
+    compression: Option<u32>,
+    recorder: Option<Recorder>,
+    serverbound_hooks: Vec<ServerboundHook>,
+    clientbound_hooks: Vec<ClientboundHook>,
+}
+
+impl Default for Proxy {
+    fn default() -> Self {
+        Proxy::new()
+    }
+}
+
+impl Proxy {
+    pub fn new() -> Self {
+        Proxy {
+            compression: None,
+            recorder: None,
+            serverbound_hooks: Vec::new(),
+            clientbound_hooks: Vec::new(),
+        }
+    }
+
+    /// Enables compression between the proxy and the client, for packets of at least `threshold` bytes.
+    /// The compression between the proxy and the server is chosen by the server.
+    pub fn compression(mut self, threshold: u32) -> Self {
+        self.compression = Some(threshold);
+        self
+    }
+
+    /// Records the packets sent by the client and by the server, as they were received by the proxy.
+    ///
+    /// During login, the packets sent by the proxy to the client are recorded instead of those of the server.
+    /// The capture thus contains the compression threshold of the proxy rather than the one of the server,
+    /// so [Replayer::replay](super::capture::Replayer::replay) would use the wrong threshold when sending the packets of the client to a real server.
+    pub fn recorder(mut self, recorder: Recorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// Adds a hook called with every packet sent by the client.
+    pub fn on_serverbound(mut self, hook: impl FnMut(&mut Serverbound<'_>, &mut Context<'_>) -> Verdict + Send + 'static) -> Self {
+        self.serverbound_hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Adds a hook called with every packet sent by the server.
+    pub fn on_clientbound(mut self, hook: impl FnMut(&mut Clientbound<'_>, &mut Context<'_>) -> Verdict + Send + 'static) -> Self {
+        self.clientbound_hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Proxies a connection until either side closes it.
+    /// Closing the connection is not an error.
+    pub fn run(mut self, client: TcpStream, server: TcpStream) -> Result<(), NetworkError> {
+        let mut client = Connection::new(client);
+        client.set_recorder(self.recorder.take());
+        let mut server = Connection::new(server);
+
+        let hello = client.receive_serverbound()?;
+        let next_state = hello.next_state();
+        let supported = hello.protocol_version().is_some();
+        server.send_serverbound(hello)?;
+        let profile = match next_state {
+            ConnectionState::Login if !supported => {
+                let reason = r#"{"text":"Unsupported protocol version"}"#;
+                client.send_clientbound(Clientbound::Login(login::ClientboundPacket::Disconnect { reason }))?;
+                return Err("The protocol version of the client is not supported.".into());
+            }
+            ConnectionState::Login => match self.login(&mut client, &mut server)? {
+                Some(profile) => Some(profile),
+                None => return Ok(()),
+            },
+            _ => None,
+        };
+
+        let (version, state) = (client.protocol_version(), client.state());
+        let (client_compression, server_compression) = (client.compression(), server.compression());
+        let recorder = client.take_recorder();
+        let (client, server) = (client.into_inner(), server.into_inner());
+        let client_reader = BufReader::new(client.try_clone()?);
+        let server_reader = BufReader::new(server.try_clone()?);
+        let relay = Relay {
+            version,
+            profile: profile.as_ref(),
+            state: Mutex::new(state),
+            recorder: recorder.map(Mutex::new),
+            client_compression,
+            server_compression,
+            client_writer: Mutex::new(client.try_clone()?),
+            server_writer: Mutex::new(server.try_clone()?),
+            client,
+            server,
+        };
+
+        let clientbound_hooks = &mut self.clientbound_hooks;
+        let serverbound_hooks = &mut self.serverbound_hooks;
+        std::thread::scope(|scope| {
+            let clientbound = scope.spawn(|| {
+                let result = relay.relay_clientbound(server_reader, clientbound_hooks);
+                relay.shutdown();
+                result
+            });
+            let serverbound = relay.relay_serverbound(client_reader, serverbound_hooks);
+            relay.shutdown();
+            let clientbound = clientbound.join().expect("The clientbound relay panicked");
+            ignore_disconnection(serverbound).and(ignore_disconnection(clientbound))
+        })
+    }
+
+    /// Logs in to the server with the username of the client, then logs the client in.
+    /// Returns `None` if the server disconnected the client.
+    fn login(&self, client: &mut Connection<TcpStream>, server: &mut Connection<TcpStream>) -> Result<Option<GameProfile>, NetworkError> {
+        let (username, player_uuid) = match client.receive_serverbound()? {
+            Serverbound::Login(login::ServerboundPacket::LoginStart { username, player_uuid }) => (username.to_string(), player_uuid),
+            _ => return Err("Unexpected packet during login".into()),
+        };
+        server.send_serverbound(Serverbound::Login(login::ServerboundPacket::LoginStart {
+            username: &username,
+            player_uuid,
+        }))?;
+
+        let profile = loop {
+            let plugin_request = match server.receive_clientbound()? {
+                // The connection enables compression by itself
+                Clientbound::Login(login::ClientboundPacket::SetCompression { .. }) => continue,
+                Clientbound::Login(login::ClientboundPacket::LoginSuccess { uuid, username, properties }) => {
+                    break GameProfile {
+                        uuid,
+                        username: username.to_string(),
+                        properties: properties
+                            .items
+                            .iter()
+                            .map(|property| ProfileProperty {
+                                name: property.name.to_string(),
+                                value: property.value.to_string(),
+                                signature: property.signature.map(str::to_string),
+                            })
+                            .collect(),
+                    };
+                }
+                Clientbound::Login(login::ClientboundPacket::Disconnect { reason }) => {
+                    let reason = reason.to_string();
+                    client.send_clientbound(Clientbound::Login(login::ClientboundPacket::Disconnect { reason: &reason }))?;
+                    return Ok(None);
+                }
+                Clientbound::Login(login::ClientboundPacket::EncryptionRequest { .. }) => {
+                    return Err("The server must be in offline mode.".into());
+                }
+                Clientbound::Login(login::ClientboundPacket::LoginPluginRequest { message_id, .. }) => message_id,
+                _ => return Err("Unexpected packet during login".into()),
+            };
+            // The proxy does not understand any plugin
+            server.send_serverbound(Serverbound::Login(login::ServerboundPacket::LoginPluginResponse {
+                message_id: plugin_request,
+                data: None,
+            }))?;
+        };
+        server.send_serverbound(Serverbound::Login(login::ServerboundPacket::LoginAcknowledged))?;
+
+        let mut login = ServerLogin::new().properties(profile.properties.clone());
+        if let Some(threshold) = self.compression {
+            login = login.compression(threshold);
+        }
+        for response in login.receive(login::ServerboundPacket::LoginStart { username: &username, player_uuid })? {
+            client.send_clientbound(Clientbound::Login(response))?;
+        }
+        // Waits for the acknowledgement of the client
+        login.run(client)?;
+        Ok(Some(profile))
+    }
+}
+
+/// The state shared by both directions once the login is done.
+struct Relay<'a> {
+    version: ProtocolVersion,
+    profile: Option<&'a GameProfile>,
+    /// Transitions are triggered by the client, so the state is updated by the serverbound direction before the server can answer.
+    state: Mutex<ConnectionState>,
+    recorder: Option<Mutex<Recorder>>,
+    client_compression: Option<u32>,
+    server_compression: Option<u32>,
+    /// Both directions write to both sides, since hooks can inject packets in any direction.
+    client_writer: Mutex<TcpStream>,
+    server_writer: Mutex<TcpStream>,
+    client: TcpStream,
+    server: TcpStream,
+}
+
+impl Relay<'_> {
+    fn state(&self) -> ConnectionState {
+        *self.state.lock().unwrap()
+    }
+
+    fn record(&self, direction: PacketDirection, state: ConnectionState, frame: &[u8]) -> Result<(), NetworkError> {
+        match &self.recorder {
+            Some(recorder) => recorder.lock().unwrap().record(direction, state, self.version, frame),
+            None => Ok(()),
+        }
+    }
+
+    fn send_to_client(&self, frame: Vec<u8>) -> Result<(), NetworkError> {
+        send_packet(&mut *self.client_writer.lock().unwrap(), frame, self.client_compression)
+    }
+
+    fn send_to_server(&self, frame: Vec<u8>) -> Result<(), NetworkError> {
+        send_packet(&mut *self.server_writer.lock().unwrap(), frame, self.server_compression)
+    }
+
+    fn send_injected(&self, context: Context) -> Result<(), NetworkError> {
+        for frame in context.to_client {
+            self.send_to_client(frame)?;
+        }
+        for frame in context.to_server {
+            self.send_to_server(frame)?;
+        }
+        Ok(())
+    }
+
+    /// Unblocks the other direction once one of them stops.
+    fn shutdown(&self) {
+        let _ = self.client.shutdown(Shutdown::Both);
+        let _ = self.server.shutdown(Shutdown::Both);
+    }
+
+    fn relay_serverbound(&self, mut reader: BufReader<TcpStream>, hooks: &mut [ServerboundHook]) -> Result<(), NetworkError> {
+        let version = self.version;
+        loop {
+            let mut frame = read_packet(&mut reader, self.client_compression)?;
+            let state = self.state();
+            self.record(PacketDirection::Serverbound, state, &frame)?;
+            if version.frame_to_canonical(state, PacketDirection::Serverbound, &mut frame).is_err() {
+                self.send_to_server(frame)?;
+                continue;
+            }
+
+            let mut context = Context::new(state, version, self.profile);
+            let parsed = parse_frame(version, DecodeLimits::default(), &frame, |frame| {
+                Serverbound::deserialize(state, frame)
+            });
+            let forwarded = match parsed {
+                Ok(mut packet) => match run_hooks(hooks.iter_mut(), &mut packet, &mut context) {
+                    Verdict::Forward => Some((frame.clone(), packet.next_state())),
+                    Verdict::Replace => Some((version.scope(|| packet.serialize())?, packet.next_state())),
+                    Verdict::Drop => None,
+                },
+                Err(_) => Some((frame.clone(), state)),
+            };
+            self.send_injected(context)?;
+
+            if let Some((mut frame, next_state)) = forwarded {
+                version.frame_to_wire(state, PacketDirection::Serverbound, &mut frame)?;
+                *self.state.lock().unwrap() = next_state;
+                self.send_to_server(frame)?;
+            }
+        }
+    }
+
+    fn relay_clientbound(&self, mut reader: BufReader<TcpStream>, hooks: &mut [ClientboundHook]) -> Result<(), NetworkError> {
+        let version = self.version;
+        loop {
+            let mut frame = read_packet(&mut reader, self.server_compression)?;
+            let state = self.state();
+            self.record(PacketDirection::Clientbound, state, &frame)?;
+            if version.frame_to_canonical(state, PacketDirection::Clientbound, &mut frame).is_err() {
+                self.send_to_client(frame)?;
+                continue;
+            }
+
+            let mut context = Context::new(state, version, self.profile);
+            let parsed = parse_frame(version, DecodeLimits::unlimited(), &frame, |frame| Clientbound::deserialize(state, frame));
+            let forwarded = match parsed {
+                Ok(mut packet) => match run_hooks(hooks.iter_mut(), &mut packet, &mut context) {
+                    Verdict::Forward => Some(frame.clone()),
+                    Verdict::Replace => Some(version.scope(|| packet.serialize())?),
+                    Verdict::Drop => None,
+                },
+                Err(_) => Some(frame.clone()),
+            };
+            self.send_injected(context)?;
+
+            if let Some(mut frame) = forwarded {
+                version.frame_to_wire(state, PacketDirection::Clientbound, &mut frame)?;
+                self.send_to_client(frame)?;
+            }
+        }
+    }
+}
+
+/// Runs the hooks until one of them drops the packet, and returns whether it must be dropped, replaced, or forwarded unchanged.
+fn run_hooks<P, H: FnMut(&mut P, &mut Context<'_>) -> Verdict>(hooks: impl IntoIterator<Item = H>, packet: &mut P, context: &mut Context<'_>) -> Verdict {
+    let mut verdict = Verdict::Forward;
+    for mut hook in hooks {
+        match hook(packet, context) {
+            Verdict::Forward => (),
+            Verdict::Replace => verdict = Verdict::Replace,
+            Verdict::Drop => return Verdict::Drop,
+        }
+    }
+    verdict
+}
+
+/// The relays only stop with an error, which is expected when a side closes the connection.
+fn ignore_disconnection(result: Result<(), NetworkError>) -> Result<(), NetworkError> {
+    match result {
+        Err(NetworkError::Io(e))
+            if matches!(
+                e.kind(),
+                ErrorKind::UnexpectedEof | ErrorKind::BrokenPipe | ErrorKind::ConnectionReset | ErrorKind::ConnectionAborted | ErrorKind::NotConnected
+            ) =>
+        {
+            Ok(())
+        }
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packets::{config, handshake, VarInt};
+    use std::net::TcpListener;
+
+    #[test]
+    fn hooks_drop_modify_and_inject() {
+        let backend = TcpListener::bind("127.0.0.1:0").unwrap();
+        let backend_address = backend.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let mut connection = Connection::new(backend.accept().unwrap().0);
+            connection.receive_serverbound().unwrap();
+            let profile = ServerLogin::new().compression(64).run(&mut connection).unwrap();
+            assert_eq!(profile.username, "Notch");
+            assert!(matches!(
+                connection.receive_serverbound().unwrap(),
+                Serverbound::Config(config::ServerboundPacket::KeepAlive { id: 2 })
+            ));
+            for keep_alive_id in [7, 9] {
+                connection
+                    .send_clientbound(Clientbound::Config(config::ClientboundPacket::KeepAlive { keep_alive_id }))
+                    .unwrap();
+            }
+        });
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let proxy = std::thread::spawn(move || {
+            let client = listener.accept().unwrap().0;
+            Proxy::new()
+                .compression(128)
+                .on_serverbound(|packet, context| match packet {
+                    Serverbound::Config(config::ServerboundPacket::KeepAlive { id: 1 }) => {
+                        assert_eq!(context.profile().unwrap().username, "Notch");
+                        let reply = config::ClientboundPacket::KeepAlive { keep_alive_id: 42 };
+                        context.send_to_client(Clientbound::Config(reply)).unwrap();
+                        let finish = Serverbound::Config(config::ServerboundPacket::FinishConfiguration);
+                        assert!(context.send_to_server(finish).is_err());
+                        Verdict::Drop
+                    }
+                    _ => Verdict::Forward,
+                })
+                .on_clientbound(|packet, _| match packet {
+                    // Only the first change is sent, since the second one is not returned as a replacement
+                    Clientbound::Config(config::ClientboundPacket::KeepAlive { keep_alive_id }) => {
+                        *keep_alive_id += 1;
+                        match *keep_alive_id {
+                            8 => Verdict::Replace,
+                            _ => Verdict::Forward,
+                        }
+                    }
+                    _ => Verdict::Forward,
+                })
+                .run(client, TcpStream::connect(backend_address).unwrap())
+        });
+
+        let mut client = Connection::new(TcpStream::connect(address).unwrap());
+        let hello = handshake::ServerboundPacket::Hello {
+            protocol_version: VarInt(764),
+            server_address: "localhost",
+            server_port: 25565,
+            next_state: ConnectionState::Login,
+        };
+        client.send_serverbound(Serverbound::Handshake(hello)).unwrap();
+        let login_start = login::ServerboundPacket::LoginStart { username: "Notch", player_uuid: 0 };
+        client.send_serverbound(Serverbound::Login(login_start)).unwrap();
+        client.receive_clientbound().unwrap();
+        assert_eq!(client.compression(), Some(128));
+        assert!(matches!(
+            client.receive_clientbound().unwrap(),
+            Clientbound::Login(login::ClientboundPacket::LoginSuccess { username: "Notch", .. })
+        ));
+        client.send_serverbound(Serverbound::Login(login::ServerboundPacket::LoginAcknowledged)).unwrap();
+
+        for id in [1, 2] {
+            client.send_serverbound(Serverbound::Config(config::ServerboundPacket::KeepAlive { id })).unwrap();
+        }
+        for expected in [42, 8, 9] {
+            assert!(matches!(
+                client.receive_clientbound().unwrap(),
+                Clientbound::Config(config::ClientboundPacket::KeepAlive { keep_alive_id }) if keep_alive_id == expected
+            ));
+        }
+
+        server.join().unwrap();
+        proxy.join().unwrap().unwrap();
+    }
+}
@@ -0,0 +1,383 @@
+//! The PROXY protocol of HAProxy, which gives the address of clients to the servers behind a TCP load balancer.
+//!
+//! The load balancer sends a header before the first [Hello](crate::packets::handshake::ServerboundPacket::Hello) packet, either as text (version 1) or binary (version 2).
+//! A server behind a load balancer should require the header, as anyone could send it otherwise.
+//! See [the specification](https://www.haproxy.org/download/2.9/doc/proxy-protocol.txt) for details.
+//!
+//! ```no_run
+//! use minecraft_protocol::network::{connection::Connection, proxy_protocol::ProxyProtocolReader};
+//! use std::net::TcpListener;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let (stream, _) = TcpListener::bind("127.0.0.1:25565")?.accept()?;
+//! let mut stream = ProxyProtocolReader::new(stream);
+//! let client_address = stream.read_header()?.and_then(|header| header.source);
+//! let mut connection = Connection::new(stream);
+//! # Ok(())
+//! # }
+//! ```
+
+use super::NetworkError;
+use std::{
+    io::{Read, Write},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+};
+
+/// The start of a version 1 header.
+const V1_PREFIX: &[u8] = b"PROXY ";
+
+/// The maximum length of a version 1 header, including the line break.
+const V1_MAX_LENGTH: usize = 107;
+
+/// The start of a version 2 header.
+const V2_SIGNATURE: [u8; 12] = [0x0D, 0x0A, 0x0D, 0x0A, 0x00, 0x0D, 0x0A, 0x51, 0x55, 0x49, 0x54, 0x0A];
+
+/// The length of a version 2 header before its addresses.
+const V2_HEADER_LENGTH: usize = 16;
+
+/// The addresses of a proxied connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProxyHeader {
+    /// The address of the client.
+    /// Unknown if the load balancer opened the connection itself, such as for health checks.
+    pub source: Option<SocketAddr>,
+    /// The address the client connected to.
+    pub destination: Option<SocketAddr>,
+}
+
+/// Returns true if the input starts with a PROXY protocol header, or `None` if more bytes are needed to know.
+///
+/// A handshake never starts like a header, since the packet id following its length is 0.
+pub fn starts_with_proxy_header(input: &[u8]) -> Option<bool> {
+    for prefix in [V1_PREFIX, &V2_SIGNATURE] {
+        let len = input.len().min(prefix.len());
+        if input[..len] == prefix[..len] {
+            return match len == prefix.len() {
+                true => Some(true),
+                false => None,
+            };
+        }
+    }
+    Some(false)
+}
+
+fn parse_v1(input: &[u8]) -> Result<Option<(ProxyHeader, usize)>, &'static str> {
+    let end = match input.windows(2).take(V1_MAX_LENGTH - 1).position(|window| window == b"\r\n") {
+        Some(end) => end,
+        None if input.len() >= V1_MAX_LENGTH => return Err("PROXY protocol header is too long"),
+        None => return Ok(None),
+    };
+    let line = std::str::from_utf8(&input[V1_PREFIX.len()..end]).map_err(|_| "Invalid PROXY protocol header")?;
+    let fields: Vec<&str> = line.split(' ').collect();
+    let header = match fields.as_slice() {
+        ["UNKNOWN", ..] => ProxyHeader {
+            source: None,
+            destination: None,
+        },
+        [protocol @ ("TCP4" | "TCP6"), source, destination, source_port, destination_port] => {
+            let parse_address = |address: &str, port: &str| -> Result<SocketAddr, &'static str> {
+                let address: IpAddr = address.parse().map_err(|_| "Invalid address in PROXY protocol header")?;
+                if address.is_ipv4() != (*protocol == "TCP4") {
+                    return Err("Address family mismatch in PROXY protocol header");
+                }
+                let port = port.parse().map_err(|_| "Invalid port in PROXY protocol header")?;
+                Ok(SocketAddr::new(address, port))
+            };
+            ProxyHeader {
+                source: Some(parse_address(source, source_port)?),
+                destination: Some(parse_address(destination, destination_port)?),
+            }
+        }
+        _ => return Err("Invalid PROXY protocol header"),
+    };
+    Ok(Some((header, end + 2)))
+}
+
+fn parse_v2(input: &[u8]) -> Result<Option<(ProxyHeader, usize)>, &'static str> {
+    if input.len() < V2_HEADER_LENGTH {
+        return Ok(None);
+    }
+    let version_command = input[12];
+    let family = input[13];
+    let len = V2_HEADER_LENGTH + u16::from_be_bytes([input[14], input[15]]) as usize;
+    if version_command >> 4 != 2 {
+        return Err("Unsupported PROXY protocol version");
+    }
+    if input.len() < len {
+        return Ok(None);
+    }
+    let addresses = &input[V2_HEADER_LENGTH..len];
+
+    let header = match (version_command & 0x0F, family >> 4) {
+        // LOCAL connections are opened by the load balancer, and the addresses must be ignored
+        (0, _) => ProxyHeader {
+            source: None,
+            destination: None,
+        },
+        (1, 1) if addresses.len() >= 12 => {
+            let ip = |offset: usize| -> Ipv4Addr {
+                let mut octets = [0; 4];
+                octets.copy_from_slice(&addresses[offset..offset + 4]);
+                octets.into()
+            };
+            ProxyHeader {
+                source: Some(SocketAddr::new(ip(0).into(), u16::from_be_bytes([addresses[8], addresses[9]]))),
+                destination: Some(SocketAddr::new(ip(4).into(), u16::from_be_bytes([addresses[10], addresses[11]]))),
+            }
+        }
+        (1, 2) if addresses.len() >= 36 => {
+            let ip = |offset: usize| -> Ipv6Addr {
+                let mut octets = [0; 16];
+                octets.copy_from_slice(&addresses[offset..offset + 16]);
+                octets.into()
+            };
+            ProxyHeader {
+                source: Some(SocketAddr::new(ip(0).into(), u16::from_be_bytes([addresses[32], addresses[33]]))),
+                destination: Some(SocketAddr::new(ip(16).into(), u16::from_be_bytes([addresses[34], addresses[35]]))),
+            }
+        }
+        (1, 0) | (1, 3) => ProxyHeader {
+            source: None,
+            destination: None,
+        },
+        (1, _) => return Err("Invalid addresses in PROXY protocol header"),
+        _ => return Err("Unsupported PROXY protocol command"),
+    };
+    Ok(Some((header, len)))
+}
+
+impl ProxyHeader {
+    /// Parses a header of either version at the start of the input.
+    /// Returns the header and its length, or `None` if more bytes are needed.
+    pub fn parse(input: &[u8]) -> Result<Option<(ProxyHeader, usize)>, &'static str> {
+        match starts_with_proxy_header(input) {
+            None => Ok(None),
+            Some(false) => Err("Missing PROXY protocol header"),
+            Some(true) if input.starts_with(V1_PREFIX) => parse_v1(input),
+            Some(true) => parse_v2(input),
+        }
+    }
+
+    /// Encodes the header as text, as sent by load balancers using version 1.
+    pub fn encode_v1(&self) -> Vec<u8> {
+        match (self.source, self.destination) {
+            (Some(source), Some(destination)) if source.is_ipv4() == destination.is_ipv4() => format!(
+                "PROXY {} {} {} {} {}\r\n",
+                if source.is_ipv4() { "TCP4" } else { "TCP6" },
+                source.ip(),
+                destination.ip(),
+                source.port(),
+                destination.port()
+            )
+            .into_bytes(),
+            _ => b"PROXY UNKNOWN\r\n".to_vec(),
+        }
+    }
+
+    /// Encodes the header in binary, as sent by load balancers using version 2.
+    pub fn encode_v2(&self) -> Vec<u8> {
+        let mut header = V2_SIGNATURE.to_vec();
+        match (self.source, self.destination) {
+            (Some(SocketAddr::V4(source)), Some(SocketAddr::V4(destination))) => {
+                header.extend_from_slice(&[0x21, 0x11, 0, 12]);
+                header.extend_from_slice(&source.ip().octets());
+                header.extend_from_slice(&destination.ip().octets());
+                header.extend_from_slice(&source.port().to_be_bytes());
+                header.extend_from_slice(&destination.port().to_be_bytes());
+            }
+            (Some(SocketAddr::V6(source)), Some(SocketAddr::V6(destination))) => {
+                header.extend_from_slice(&[0x21, 0x21, 0, 36]);
+                header.extend_from_slice(&source.ip().octets());
+                header.extend_from_slice(&destination.ip().octets());
+                header.extend_from_slice(&source.port().to_be_bytes());
+                header.extend_from_slice(&destination.port().to_be_bytes());
+            }
+            _ => header.extend_from_slice(&[0x20, 0x00, 0, 0]),
+        }
+        header
+    }
+}
+
+/// Wraps a stream and strips the PROXY protocol header at its start.
+///
+/// The header is read by [ProxyProtocolReader::read_header], or by the first read otherwise.
+/// The bytes received after the header are kept and returned by the following reads, so the reader can be given to a [Connection](super::connection::Connection).
+#[derive(Debug)]
+pub struct ProxyProtocolReader<S> {
+    stream: S,
+    required: bool,
+    header: Option<Option<ProxyHeader>>,
+    buffer: Vec<u8>,
+    position: usize,
+}
+
+impl<S> ProxyProtocolReader<S> {
+    /// Creates a reader that rejects streams without a header.
+    pub fn new(stream: S) -> Self {
+        ProxyProtocolReader {
+            stream,
+            required: true,
+            header: None,
+            buffer: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// Creates a reader that also accepts streams without a header.
+    /// Clients connecting directly can then forge their address, so this should only be used when the server cannot be reached without the load balancer.
+    pub fn optional(stream: S) -> Self {
+        ProxyProtocolReader {
+            required: false,
+            ..ProxyProtocolReader::new(stream)
+        }
+    }
+
+    /// The header, if it was read and there was one.
+    pub fn header(&self) -> Option<ProxyHeader> {
+        self.header.flatten()
+    }
+
+    pub fn get_ref(&self) -> &S {
+        &self.stream
+    }
+
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.stream
+    }
+}
+
+impl<S: Read> ProxyProtocolReader<S> {
+    /// Reads the header at the start of the stream if it was not read yet.
+    /// Returns `None` if the stream has no header and the reader is [optional](ProxyProtocolReader::optional).
+    pub fn read_header(&mut self) -> Result<Option<ProxyHeader>, NetworkError> {
+        if let Some(header) = self.header {
+            return Ok(header);
+        }
+        let mut chunk = [0; 256];
+        loop {
+            if !self.required && starts_with_proxy_header(&self.buffer) == Some(false) {
+                self.header = Some(None);
+                return Ok(None);
+            }
+            if let Some((header, len)) = ProxyHeader::parse(&self.buffer)? {
+                self.position = len;
+                self.header = Some(Some(header));
+                return Ok(Some(header));
+            }
+            let read = self.stream.read(&mut chunk)?;
+            if read == 0 {
+                return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+            }
+            self.buffer.extend_from_slice(&chunk[..read]);
+        }
+    }
+}
+
+impl<S: Read> Read for ProxyProtocolReader<S> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.header.is_none() {
+            self.read_header().map_err(|e| match e {
+                NetworkError::Io(e) => e,
+                e => std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()),
+            })?;
+        }
+        if self.position < self.buffer.len() {
+            let len = buf.len().min(self.buffer.len() - self.position);
+            buf[..len].copy_from_slice(&self.buffer[self.position..self.position + len]);
+            self.position += len;
+            if self.position == self.buffer.len() {
+                self.buffer = Vec::new();
+                self.position = 0;
+            }
+            return Ok(len);
+        }
+        self.stream.read(buf)
+    }
+}
+
+impl<S: Write> Write for ProxyProtocolReader<S> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.stream.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::{connection::*, framer::FrameReader};
+    use crate::packets::{handshake, ConnectionState, VarInt};
+
+    fn hello() -> Vec<u8> {
+        let mut stream = Vec::new();
+        let mut connection = Connection::new(&mut stream);
+        connection
+            .send_serverbound(Serverbound::Handshake(handshake::ServerboundPacket::Hello {
+                protocol_version: VarInt(765),
+                server_address: "localhost",
+                server_port: 25565,
+                next_state: ConnectionState::Login,
+            }))
+            .unwrap();
+        stream
+    }
+
+    #[test]
+    fn proxy_header_round_trip() {
+        for header in [
+            ProxyHeader {
+                source: Some("192.168.1.12:54321".parse().unwrap()),
+                destination: Some("10.0.0.1:25565".parse().unwrap()),
+            },
+            ProxyHeader {
+                source: Some("[2001:db8::1]:54321".parse().unwrap()),
+                destination: Some("[::1]:25565".parse().unwrap()),
+            },
+            ProxyHeader {
+                source: None,
+                destination: None,
+            },
+        ] {
+            for encoded in [header.encode_v1(), header.encode_v2()] {
+                assert_eq!(ProxyHeader::parse(&encoded), Ok(Some((header, encoded.len()))));
+                assert_eq!(ProxyHeader::parse(&encoded[..encoded.len() - 1]), Ok(None));
+            }
+        }
+        assert_eq!(starts_with_proxy_header(&hello()), Some(false));
+        assert!(ProxyHeader::parse(b"PROXY TCP4 127.0.0.1 ::1 1 2\r\n").is_err());
+    }
+
+    #[test]
+    fn strip_header() {
+        let header = ProxyHeader {
+            source: Some("192.168.1.12:54321".parse().unwrap()),
+            destination: Some("10.0.0.1:25565".parse().unwrap()),
+        };
+        let mut stream = header.encode_v2();
+        stream.extend_from_slice(&hello());
+
+        let mut connection = Connection::new(ProxyProtocolReader::new(stream.as_slice()));
+        assert_eq!(connection.get_mut().read_header().unwrap(), Some(header));
+        assert!(matches!(
+            connection.receive_serverbound().unwrap(),
+            Serverbound::Handshake(handshake::ServerboundPacket::Hello { server_port: 25565, .. })
+        ));
+
+        let mut framer = FrameReader::new();
+        framer.push(&stream[..20]);
+        assert_eq!(framer.take_proxy_header().unwrap(), None);
+        framer.push(&stream[20..]);
+        assert_eq!(framer.take_proxy_header().unwrap(), Some(header));
+        assert!(framer.next_frame().unwrap().is_some());
+
+        let hello = hello();
+        let mut connection = Connection::new(ProxyProtocolReader::optional(hello.as_slice()));
+        assert!(connection.receive_serverbound().is_ok());
+        assert_eq!(connection.get_ref().header(), None);
+        let mut connection = Connection::new(ProxyProtocolReader::new(hello.as_slice()));
+        assert!(connection.receive_serverbound().is_err());
+    }
+}
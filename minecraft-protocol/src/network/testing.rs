@@ -0,0 +1,36 @@
+//! Fixtures shared by the tests that send packets through a [Connection].
+
+use super::connection::{Connection, Serverbound};
+use crate::packets::{config, handshake, login, play_serverbound, ConnectionState, VarInt};
+use std::io::Cursor;
+
+/// The packets of a client that logs in, plays, and goes back to the configuration.
+pub(crate) fn login_sequence() -> Vec<Serverbound<'static>> {
+    vec![
+        Serverbound::Handshake(handshake::ServerboundPacket::Hello {
+            protocol_version: VarInt(764),
+            server_address: "localhost",
+            server_port: 25565,
+            next_state: ConnectionState::Login,
+        }),
+        Serverbound::Login(login::ServerboundPacket::LoginStart {
+            username: "bot",
+            player_uuid: 0,
+        }),
+        Serverbound::Login(login::ServerboundPacket::LoginAcknowledged),
+        Serverbound::Config(config::ServerboundPacket::FinishConfiguration),
+        Serverbound::Play(play_serverbound::ServerboundPacket::KeepAlive { keep_alive_id: 5 }),
+        Serverbound::Play(play_serverbound::ServerboundPacket::AcknowledgeConfiguration),
+        Serverbound::Config(config::ServerboundPacket::KeepAlive { id: 6 }),
+    ]
+}
+
+/// Writes packets with a connection in `state`, and returns a connection in the same state that reads them back.
+pub(crate) fn loopback(state: ConnectionState, write: impl FnOnce(&mut Connection<Vec<u8>>)) -> Connection<Cursor<Vec<u8>>> {
+    let mut writer = Connection::new(Vec::new());
+    writer.set_state(state);
+    write(&mut writer);
+    let mut reader = Connection::new(Cursor::new(writer.into_inner()));
+    reader.set_state(state);
+    reader
+}
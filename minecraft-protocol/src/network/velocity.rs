@@ -0,0 +1,182 @@
+//! Velocity modern forwarding, which gives the identity of players to the servers behind a Velocity proxy.
+//!
+//! During the login, the backend server sends a [LoginPluginRequest](login::ClientboundPacket::LoginPluginRequest) on the [CHANNEL] channel.
+//! The proxy answers with a [LoginPluginResponse](login::ServerboundPacket::LoginPluginResponse) containing a [VelocityPlayerInfo], signed with a secret shared by the proxy and the backend.
+//! See [Velocity's documentation](https://docs.papermc.io/velocity/player-information-forwarding) for details.
+
+use crate::{
+    components::players::Property,
+    packets::login::{self, GameProfile, ProfileProperty},
+    *,
+};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// The plugin channel used for modern forwarding.
+pub const CHANNEL: &str = "velocity:player_info";
+
+/// The first version of the forwarding payload.
+pub const MODERN_DEFAULT: u8 = 1;
+/// Adds the chat signing key of the player, for 1.19.
+pub const MODERN_WITH_KEY: u8 = 2;
+/// Adds the UUID of the key holder, for 1.19.1 and 1.19.2.
+pub const MODERN_WITH_KEY_V2: u8 = 3;
+/// Same content as [MODERN_DEFAULT], for 1.19.3 and later where the chat session is sent after login.
+pub const MODERN_LAZY_SESSION: u8 = 4;
+
+/// The length of the HMAC-SHA256 signature prefixing the payload.
+pub const SIGNATURE_LENGTH: usize = 32;
+
+/// The payload of the [LoginPluginRequest](login::ClientboundPacket::LoginPluginRequest) for each version.
+const REQUESTED_VERSIONS: [u8; 5] = [0, MODERN_DEFAULT, MODERN_WITH_KEY, MODERN_WITH_KEY_V2, MODERN_LAZY_SESSION];
+
+/// The information forwarded by the proxy.
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, MinecraftPacketPart)]
+pub struct VelocityPlayerInfo<'a> {
+    /// The version of the payload, at most the one requested by the backend.
+    pub version: VarInt,
+    /// The IP address of the player, as seen by the proxy.
+    pub client_address: &'a str,
+    pub uuid: UUID,
+    pub username: &'a str,
+    pub properties: Array<'a, Property<'a>, VarInt>,
+    /// The chat signing key of the player, for versions [MODERN_WITH_KEY] and [MODERN_WITH_KEY_V2].
+    pub key_data: RawBytes<'a>,
+}
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Computes the signature of a payload with the forwarding secret.
+pub fn sign(secret: &[u8], payload: &[u8]) -> [u8; SIGNATURE_LENGTH] {
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts keys of any size");
+    mac.update(payload);
+    mac.finalize().into_bytes().into()
+}
+
+/// Checks the signature prefixing forwarded data, and returns the payload that follows it.
+/// The comparison runs in constant time.
+pub fn verify<'a>(secret: &[u8], data: &'a [u8]) -> Result<&'a [u8], &'static str> {
+    if data.len() < SIGNATURE_LENGTH {
+        return Err("The forwarded data is too short to be signed");
+    }
+    let (signature, payload) = data.split_at(SIGNATURE_LENGTH);
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts keys of any size");
+    mac.update(payload);
+    mac.verify_slice(signature).map_err(|_| "Invalid forwarding signature")?;
+    Ok(payload)
+}
+
+/// Builds the request sent by the backend server.
+/// The proxy answers with a payload of the requested version at most.
+pub fn forwarding_request(message_id: i32, requested_version: u8) -> login::ClientboundPacket<'static> {
+    let version = (requested_version as usize).min(MODERN_LAZY_SESSION as usize);
+    login::ClientboundPacket::LoginPluginRequest {
+        message_id: VarInt(message_id),
+        channel: CHANNEL,
+        data: RawBytes {
+            data: match version {
+                0 | 1 => &[],
+                _ => &REQUESTED_VERSIONS[version..=version],
+            },
+        },
+    }
+}
+
+/// Reads the version requested by the backend in a [LoginPluginRequest](login::ClientboundPacket::LoginPluginRequest), on the proxy side.
+pub fn requested_version(data: &[u8]) -> u8 {
+    data.first().copied().unwrap_or(MODERN_DEFAULT)
+}
+
+impl<'a> VelocityPlayerInfo<'a> {
+    /// Builds the payload of a player on the proxy side.
+    pub fn new(version: u8, client_address: &'a str, profile: &'a GameProfile) -> Self {
+        let properties: Vec<Property> = profile
+            .properties
+            .iter()
+            .map(|property| Property {
+                name: &property.name,
+                value: &property.value,
+                signature: property.signature.as_deref(),
+            })
+            .collect();
+        VelocityPlayerInfo {
+            version: VarInt(version as i32),
+            client_address,
+            uuid: profile.uuid,
+            username: &profile.username,
+            properties: properties.into(),
+            key_data: RawBytes::default(),
+        }
+    }
+
+    /// Serializes and signs the payload, to be sent in a [LoginPluginResponse](login::ServerboundPacket::LoginPluginResponse) by the proxy.
+    pub fn encode_signed(self, secret: &[u8]) -> Result<Vec<u8>, &'static str> {
+        let payload = self.serialize_minecraft_packet()?;
+        let mut data = sign(secret, &payload).to_vec();
+        data.extend_from_slice(&payload);
+        Ok(data)
+    }
+
+    /// Verifies the signature and parses the payload, on the backend side.
+    pub fn decode_verified(data: &'a [u8], secret: &[u8]) -> Result<Self, ParseError> {
+        let payload = verify(secret, data)?;
+        let player_info = VelocityPlayerInfo::deserialize_uncompressed_minecraft_packet(payload)?;
+        if player_info.version.0 < MODERN_DEFAULT as i32 || player_info.version.0 > MODERN_LAZY_SESSION as i32 {
+            return Err("Unsupported forwarding version".into());
+        }
+        Ok(player_info)
+    }
+
+    /// The identity of the player, to be sent in [LoginSuccess](login::ClientboundPacket::LoginSuccess) instead of an offline profile.
+    pub fn to_profile(&self) -> GameProfile {
+        GameProfile {
+            uuid: self.uuid,
+            username: self.username.to_string(),
+            properties: self
+                .properties
+                .items
+                .iter()
+                .map(|property| ProfileProperty {
+                    name: property.name.to_string(),
+                    value: property.value.to_string(),
+                    signature: property.signature.map(|signature| signature.to_string()),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signed_round_trip() {
+        let secret = b"forwarding secret";
+        let mut profile = GameProfile::offline("Notch");
+        profile.properties.push(ProfileProperty {
+            name: "textures".to_string(),
+            value: "e30=".to_string(),
+            signature: Some("c2lnbmF0dXJl".to_string()),
+        });
+
+        let request = forwarding_request(1, MODERN_LAZY_SESSION);
+        let version = match request {
+            login::ClientboundPacket::LoginPluginRequest { channel: CHANNEL, data, .. } => requested_version(data.data),
+            _ => unreachable!(),
+        };
+        assert_eq!(version, MODERN_LAZY_SESSION);
+
+        let data = VelocityPlayerInfo::new(version, "127.0.0.1", &profile).encode_signed(secret).unwrap();
+        let player_info = VelocityPlayerInfo::decode_verified(&data, secret).unwrap();
+        assert_eq!(player_info.client_address, "127.0.0.1");
+        assert_eq!(player_info.uuid, UUID::offline_player("Notch"));
+        assert_eq!(player_info.to_profile(), profile);
+
+        assert!(VelocityPlayerInfo::decode_verified(&data, b"wrong secret").is_err());
+        let mut tampered = data.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(VelocityPlayerInfo::decode_verified(&tampered, secret).is_err());
+    }
+}
@@ -0,0 +1,350 @@
+//! Generation of arbitrary protocol values with [arbitrary], enabled by the `arbitrary` feature.
+//!
+//! The derive macros implement [Arbitrary] for packets and components, and tagged enums get an `arbitrary_variant` method to generate a chosen variant.
+//! The types below have invariants that random data would break, so they only generate values that can be sent in the [protocol version](ProtocolVersion) of the current scope.
+//! Any generated value that serializes successfully is expected to deserialize to itself.
+
+use super::{version::ProtocolVersion, *};
+use crate::{
+    components::{chat::PreviousMessage, chunk::PalettedData, players::*},
+    nbt::{arrays::NbtList, NbtTag},
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+use std::collections::HashMap;
+
+impl<'a> Arbitrary<'a> for VarInt {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(VarInt(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for VarLong {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(VarLong(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Position {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // The coordinates are packed in 26, 12 and 26 bits
+        Ok(Position {
+            x: u.int_in_range(-(1 << 25)..=(1 << 25) - 1)?,
+            y: u.int_in_range(-(1 << 11)..=(1 << 11) - 1)?,
+            z: u.int_in_range(-(1 << 25)..=(1 << 25) - 1)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Chat<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        match ProtocolVersion::current().nbt_text_components() {
+            true => Ok(Chat::Nbt(u.arbitrary()?)),
+            false => Ok(Chat::Json(u.arbitrary()?)),
+        }
+    }
+}
+
+impl<'a> Arbitrary<'a> for RawBytes<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(RawBytes { data: u.arbitrary()? })
+    }
+}
+
+impl<'a, T, U> Arbitrary<'a> for Array<'a, T, U>
+where
+    T: MinecraftPacketPart<'a> + std::fmt::Debug + Arbitrary<'a>,
+    U: MinecraftPacketPart<'a> + TryFrom<usize>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut items: Vec<T> = u.arbitrary()?;
+        // The length has to fit in the prefix
+        while U::try_from(items.len()).is_err() {
+            items.pop();
+        }
+        Ok(items.into())
+    }
+}
+
+impl<'a, V: MinecraftPacketPart<'a> + Arbitrary<'a>, const N: usize> Arbitrary<'a> for FixedSizeArray<'a, V, N> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let items = (0..N).map(|_| u.arbitrary()).collect::<Result<_>>()?;
+        Ok(FixedSizeArray { items, _phantom: std::marker::PhantomData })
+    }
+}
+
+impl<'a, K, V, U> Arbitrary<'a> for Map<'a, K, V, U>
+where
+    K: MinecraftPacketPart<'a> + std::fmt::Debug + Ord + Arbitrary<'a>,
+    V: MinecraftPacketPart<'a> + std::fmt::Debug + Arbitrary<'a>,
+    U: MinecraftPacketPart<'a> + TryFrom<usize>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut items: BTreeMap<K, V> = u.arbitrary()?;
+        while U::try_from(items.len()).is_err() {
+            items.pop_last();
+        }
+        Ok(items.into())
+    }
+}
+
+impl<'a, T: Arbitrary<'a>, const PROTOCOL: i32> Arbitrary<'a> for SinceVersion<T, PROTOCOL> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        match Self::is_present() {
+            true => Ok(SinceVersion(Some(u.arbitrary()?))),
+            false => Ok(SinceVersion(None)),
+        }
+    }
+}
+
+impl<'a> Arbitrary<'a> for PreviousMessage {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // The signature is only sent for the message id 0
+        let signature: Option<[u8; 256]> = u.arbitrary()?;
+        let message_id = match (&signature, u.arbitrary()?) {
+            (Some(_), _) => 0,
+            (None, 0) => 1,
+            (None, message_id) => message_id,
+        };
+        Ok(PreviousMessage { message_id: VarInt(message_id), signature })
+    }
+}
+
+impl<'a> Arbitrary<'a> for PlayersInfos<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // All the players have the same actions, in the order of the bits of the mask
+        let mask: u8 = u.int_in_range(0..=0b11_1111)?;
+        let mut players_infos = Vec::new();
+        for _ in 0..u.int_in_range(0..=4)? {
+            let player_uuid = u.arbitrary()?;
+            let mut actions = Vec::new();
+            for bit in (0..6).filter(|bit| mask & (1 << bit) != 0) {
+                actions.push(match bit {
+                    0 => PlayerActions::AddPlayer(u.arbitrary()?),
+                    1 => PlayerActions::InitializeChat(u.arbitrary()?),
+                    2 => PlayerActions::UpdateGamemodes(u.arbitrary()?),
+                    3 => PlayerActions::UpdateListed(u.arbitrary()?),
+                    4 => PlayerActions::Ping(u.arbitrary()?),
+                    _ => PlayerActions::UpdateDisplayName(u.arbitrary()?),
+                });
+            }
+            players_infos.push(PlayerInfos { player_uuid, actions });
+        }
+        Ok(PlayersInfos { players_infos })
+    }
+}
+
+impl<'a, const LBITS: u8, const HBITS: u8, const DBITS: u8, const TRUNC: usize> Arbitrary<'a> for PalettedData<LBITS, HBITS, DBITS, TRUNC> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // There are always TRUNC entries, which are indices in the palette or values fitting in DBITS bits
+        Ok(match u.choose_index(3)? {
+            0 => PalettedData::Single { value: u.arbitrary()? },
+            1 => {
+                // Palettes too large for HBITS bits are sent as raw values, so their values must fit in DBITS bits
+                let palette_len = u.int_in_range(1..=2usize << HBITS)?;
+                let palette = match palette_len > 1 << HBITS {
+                    true => (0..palette_len).map(|_| u.int_in_range(0..=(1u32 << DBITS) - 1)).collect::<Result<_>>()?,
+                    false => (0..palette_len).map(|_| u.arbitrary()).collect::<Result<_>>()?,
+                };
+                let max_index = (palette_len - 1).min(u8::MAX as usize) as u8;
+                let indexed = (0..TRUNC).map(|_| u.int_in_range(0..=max_index)).collect::<Result<_>>()?;
+                PalettedData::Paletted { palette, indexed }
+            }
+            _ => {
+                let values = (0..TRUNC).map(|_| u.int_in_range(0..=(1u32 << DBITS) - 1)).collect::<Result<_>>()?;
+                PalettedData::Raw { values }
+            }
+        })
+    }
+}
+
+/// The nesting of generated NBT, far below the decoding limit so that generation stays cheap.
+const NBT_DEPTH: usize = 4;
+
+impl<'a> Arbitrary<'a> for NbtTag {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_nbt_tag(u, NBT_DEPTH)
+    }
+}
+
+impl<'a> Arbitrary<'a> for NbtList {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_nbt_list(u, NBT_DEPTH)
+    }
+}
+
+/// Root compounds are not generated since they only exist in files.
+fn arbitrary_nbt_tag(u: &mut Unstructured, depth: usize) -> Result<NbtTag> {
+    Ok(match u.choose_index(if depth > 0 { 13 } else { 11 })? {
+        0 => NbtTag::Null,
+        1 => NbtTag::Byte(u.arbitrary()?),
+        2 => NbtTag::Short(u.arbitrary()?),
+        3 => NbtTag::Int(u.arbitrary()?),
+        4 => NbtTag::Long(u.arbitrary()?),
+        5 => NbtTag::Float(u.arbitrary()?),
+        6 => NbtTag::Double(u.arbitrary()?),
+        7 => NbtTag::ByteArray(u.arbitrary()?),
+        8 => NbtTag::IntArray(u.arbitrary()?),
+        9 => NbtTag::LongArray(u.arbitrary()?),
+        10 => NbtTag::String(u.arbitrary()?),
+        11 => NbtTag::List(arbitrary_nbt_list(u, depth - 1)?),
+        _ => NbtTag::Compound(arbitrary_nbt_compound(u, depth - 1)?),
+    })
+}
+
+/// Empty lists are always parsed as [NbtList::None], whatever their type.
+fn arbitrary_nbt_list(u: &mut Unstructured, depth: usize) -> Result<NbtList> {
+    fn non_empty<T>(items: Vec<T>, list: fn(Vec<T>) -> NbtList) -> NbtList {
+        match items.is_empty() {
+            true => NbtList::None,
+            false => list(items),
+        }
+    }
+
+    Ok(match u.choose_index(if depth > 0 { 13 } else { 11 })? {
+        0 => NbtList::None,
+        1 => non_empty(u.arbitrary()?, NbtList::Byte),
+        2 => non_empty(u.arbitrary()?, NbtList::Short),
+        3 => non_empty(u.arbitrary()?, NbtList::Int),
+        4 => non_empty(u.arbitrary()?, NbtList::Long),
+        5 => non_empty(u.arbitrary()?, NbtList::Float),
+        6 => non_empty(u.arbitrary()?, NbtList::Double),
+        7 => non_empty(u.arbitrary()?, NbtList::ByteArray),
+        8 => non_empty(u.arbitrary()?, NbtList::IntArray),
+        9 => non_empty(u.arbitrary()?, NbtList::LongArray),
+        10 => non_empty(u.arbitrary()?, NbtList::String),
+        11 => {
+            let len = u.int_in_range(0..=4)?;
+            non_empty((0..len).map(|_| arbitrary_nbt_list(u, depth - 1)).collect::<Result<_>>()?, NbtList::List)
+        }
+        _ => {
+            let len = u.int_in_range(0..=4)?;
+            non_empty((0..len).map(|_| arbitrary_nbt_compound(u, depth - 1)).collect::<Result<_>>()?, NbtList::Compound)
+        }
+    })
+}
+
+/// [NbtTag::Null] is not generated in compounds since it marks their end.
+fn arbitrary_nbt_compound(u: &mut Unstructured, depth: usize) -> Result<HashMap<String, NbtTag>> {
+    let mut compound = HashMap::new();
+    for _ in 0..u.int_in_range(0..=4)? {
+        let name = u.arbitrary()?;
+        let tag = arbitrary_nbt_tag(u, depth)?;
+        if !tag.is_null() {
+            compound.insert(name, tag);
+        }
+    }
+    Ok(compound)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::chunk::Chunk;
+
+    const SAMPLES: usize = 64;
+
+    /// Buffers of pseudorandom bytes of various sizes, always the same so that failures are reproducible.
+    fn samples() -> impl Iterator<Item = Vec<u8>> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        (0..SAMPLES).map(move |i| {
+            let len = 16 << (i % 8);
+            (0..len)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    state as u8
+                })
+                .collect()
+        })
+    }
+
+    /// Checks that `deserialize(serialize(value)) == value`, skipping values that cannot be serialized.
+    /// NaN is never equal to itself, so values containing it are only checked to be parsed entirely.
+    /// Values that are not parsed back as they were built can give a function returning the expected value.
+    /// Evaluates to whether the value was checked.
+    macro_rules! check_round_trip {
+        ($part:ty, $value:expr) => {
+            check_round_trip!($part, $value, std::convert::identity)
+        };
+        ($part:ty, $value:expr, $expected:expr) => {{
+            let value = $value;
+            match value.serialize_minecraft_packet() {
+                Ok(serialized) => {
+                    assert_eq!(value.serialized_size(), serialized.len(), "wrong size for {:?}", value);
+                    let parsed = <$part>::deserialize_uncompressed_minecraft_packet(&serialized)
+                        .unwrap_or_else(|e| panic!("{} while parsing {:?}", e, value));
+                    #[allow(clippy::eq_op)]
+                    match value == value {
+                        true => assert_eq!(parsed, ($expected)(value)),
+                        false => assert_eq!(parsed.serialized_size(), serialized.len()),
+                    }
+                    true
+                }
+                Err(_) => false,
+            }
+        }};
+    }
+
+    /// Paletted data is sent as raw values when the palette is too large for HBITS bits, and is parsed back as such.
+    fn as_sent<const LBITS: u8, const HBITS: u8, const DBITS: u8, const TRUNC: usize>(
+        data: &PalettedData<LBITS, HBITS, DBITS, TRUNC>,
+    ) -> PalettedData<LBITS, HBITS, DBITS, TRUNC> {
+        match data {
+            PalettedData::Paletted { palette, indexed } if palette.len() > 1 << HBITS => PalettedData::Raw {
+                values: indexed.iter().map(|index| palette[*index as usize]).collect(),
+            },
+            data => data.clone(),
+        }
+    }
+
+    /// Checks every variant of a packet enum, each of which must round-trip at least once.
+    macro_rules! check_variants {
+        ($packet:ty, $kind:ty) => {
+            for kind in <$kind>::ALL {
+                let mut checked = 0;
+                for data in samples() {
+                    if let Ok(packet) = <$packet>::arbitrary_variant(*kind, &mut Unstructured::new(&data)) {
+                        if check_round_trip!($packet, packet) {
+                            checked += 1;
+                        }
+                    }
+                }
+                assert!(checked > 0, "{:?} was never checked in {:?}", kind, ProtocolVersion::current());
+            }
+        };
+    }
+
+    #[test]
+    fn round_trips() {
+        use crate::packets::{config, handshake, login, play_clientbound, play_serverbound, status};
+
+        for version in ProtocolVersion::ALL {
+            version.scope(|| {
+                check_variants!(handshake::ServerboundPacket, handshake::ServerboundPacketKind);
+                check_variants!(status::ClientboundPacket, status::ClientboundPacketKind);
+                check_variants!(status::ServerboundPacket, status::ServerboundPacketKind);
+                check_variants!(login::ClientboundPacket, login::ClientboundPacketKind);
+                check_variants!(login::ServerboundPacket, login::ServerboundPacketKind);
+                check_variants!(config::ClientboundPacket, config::ClientboundPacketKind);
+                check_variants!(config::ServerboundPacket, config::ServerboundPacketKind);
+                check_variants!(play_clientbound::ClientboundPacket, play_clientbound::ClientboundPacketKind);
+                check_variants!(play_serverbound::ServerboundPacket, play_serverbound::ServerboundPacketKind);
+
+                for data in samples() {
+                    let mut u = Unstructured::new(&data);
+                    if let Ok(chunk) = Chunk::arbitrary(&mut u) {
+                        check_round_trip!(Chunk, chunk, |chunk: Chunk| Chunk {
+                            blocks: as_sent(&chunk.blocks),
+                            biomes: as_sent(&chunk.biomes),
+                            ..chunk
+                        });
+                    }
+                    if let Ok(tag) = NbtTag::arbitrary(&mut u) {
+                        check_round_trip!(NbtTag, tag);
+                    }
+                }
+            });
+        }
+    }
+}
@@ -5,6 +5,7 @@ use super::*;
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ClientboundPacket<'a> {
     PluginMessage {
         /// Name of the [plugin channel](https://wiki.vg/Plugin_channel) used to send the data.
@@ -40,6 +41,8 @@ pub enum ClientboundPacket<'a> {
     },
 
     RessourcePakck {
+        /// The unique identifier of the resource pack, added in 1.20.3.
+        uuid: SinceVersion<UUID, 765>,
         /// The URL to the resource pack.
         url: &'a str,
         /// A 40 character hexadecimal and lowercase SHA-1 hash of the resource pack file.
@@ -58,12 +61,19 @@ pub enum ClientboundPacket<'a> {
 
     UpdateTags {
         tags: Map<'a, Identifier<'a>, Map<'a, Identifier<'a>, Array<'a, VarInt, VarInt>, VarInt>, VarInt>
-    }
+    },
+
+    /// Added in 1.20.3.
+    RemoveResourcePack {
+        /// The resource pack to remove, or all of them if `None`.
+        uuid: Option<UUID>,
+    },
 }
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ServerboundPacket<'a> {
     /// Sent when the player connects, or when settings are changed.
     ClientInformations {
@@ -105,6 +115,8 @@ pub enum ServerboundPacket<'a> {
     },
     
     ResourcePackResponse {
+        /// The unique identifier of the resource pack, added in 1.20.3.
+        uuid: SinceVersion<UUID, 765>,
         result: resource_pack::ResourcePackStatus,
     },
 }
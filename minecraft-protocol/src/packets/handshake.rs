@@ -3,13 +3,16 @@ use super::*;
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ServerboundPacket<'a> {
     /// This causes the server to switch into the target state
     Hello {
-        /// See [protocol version numbers](https://wiki.vg/Protocol_version_numbers) (currently 754 in Minecraft 1.16.5).
+        /// See [protocol version numbers](https://wiki.vg/Protocol_version_numbers).
+        /// The packets are encoded for this version, see [ProtocolVersion](super::version::ProtocolVersion) for the supported ones.
         protocol_version: VarInt,
         /// Hostname or IP, e.g. localhost or 127.0.0.1, that was used to connect.
         /// The Notchian server does not use this information.
+        /// Proxies using BungeeCord forwarding append the identity of the player, see [BungeeCordForwarding](crate::network::bungeecord::BungeeCordForwarding).
         /// Note that SRV records are a complete redirect, e.g. if _minecraft._tcp.example.com points to mc.example.org, users connecting to example.com will provide mc.example.org as server address in addition to connecting to it.
         server_address: &'a str,
         /// Default is 25565.
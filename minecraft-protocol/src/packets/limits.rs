@@ -0,0 +1,213 @@
+//! Limits on the sizes claimed by untrusted packets.
+//!
+//! Lengths are read from the input, so a forged packet can claim huge arrays or deeply nested NBT.
+//! Packets are parsed with [DecodeLimits::default] unless other limits are set with [MinecraftPacketPart::deserialize_uncompressed_minecraft_packet_with_limits] or [DecodeLimits::scope].
+//! Trusted input can be parsed with [DecodeLimits::unlimited].
+
+use super::serializer::ParseError;
+use std::cell::Cell;
+
+thread_local! {
+    static LIMITS: Cell<Option<DecodeLimits>> = const { Cell::new(None) };
+    static NBT_BYTES: Cell<usize> = const { Cell::new(0) };
+    static NBT_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The maximum sizes accepted while deserializing.
+/// The default values are the ones of the Notchian server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeLimits {
+    /// The maximum length of an uncompressed packet, in bytes.
+    pub max_packet_size: usize,
+    /// The maximum number of items in an [Array](super::Array) or a [Map](super::Map).
+    pub max_array_length: usize,
+    /// The maximum number of UTF-16 code units in a string.
+    /// The encoded string can take up to three times as many bytes.
+    pub max_string_length: usize,
+    /// The maximum number of nested NBT compounds and lists.
+    pub max_nbt_depth: usize,
+    /// The maximum number of NBT bytes in a single packet.
+    pub max_nbt_size: usize,
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        DecodeLimits {
+            max_packet_size: crate::network::MAX_UNCOMPRESSED_PACKET_SIZE,
+            max_array_length: crate::network::MAX_UNCOMPRESSED_PACKET_SIZE,
+            max_string_length: 32767,
+            max_nbt_depth: 512,
+            max_nbt_size: 2_097_152,
+        }
+    }
+}
+
+/// Restores the previous limits when the scope ends, even if it panics.
+struct ScopeGuard {
+    previous_limits: Option<DecodeLimits>,
+    previous_nbt_bytes: usize,
+}
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        LIMITS.with(|limits| limits.set(self.previous_limits));
+        NBT_BYTES.with(|bytes| bytes.set(self.previous_nbt_bytes));
+    }
+}
+
+impl DecodeLimits {
+    /// Limits that accept anything, for trusted input.
+    pub fn unlimited() -> Self {
+        DecodeLimits {
+            max_packet_size: usize::MAX,
+            max_array_length: usize::MAX,
+            max_string_length: usize::MAX,
+            max_nbt_depth: usize::MAX,
+            max_nbt_size: usize::MAX,
+        }
+    }
+
+    /// The limits of the current scope, if any.
+    pub fn current() -> Option<DecodeLimits> {
+        LIMITS.with(|limits| limits.get())
+    }
+
+    /// Runs `f` with these limits enforced on every deserialization it does.
+    /// The NBT size is accounted for the whole scope, so a scope should cover a single packet.
+    pub fn scope<T>(self, f: impl FnOnce() -> T) -> T {
+        let _guard = ScopeGuard {
+            previous_limits: LIMITS.with(|limits| limits.replace(Some(self))),
+            previous_nbt_bytes: NBT_BYTES.with(|bytes| bytes.replace(0)),
+        };
+        f()
+    }
+
+    /// Runs `f` in the current scope, or with the default limits if there is none.
+    pub(crate) fn scope_or_default<T>(f: impl FnOnce() -> T) -> T {
+        match DecodeLimits::current() {
+            Some(_) => f(),
+            None => DecodeLimits::default().scope(f),
+        }
+    }
+
+    pub(crate) fn check_packet_size(len: usize) -> Result<(), ParseError> {
+        match DecodeLimits::current() {
+            Some(limits) if len > limits.max_packet_size => Err("The packet is longer than the maximum packet size.".into()),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn check_array_length(len: usize) -> Result<(), ParseError> {
+        match DecodeLimits::current() {
+            Some(limits) if len > limits.max_array_length => Err("The array is longer than the maximum array length.".into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the length in bytes of a string before it is decoded.
+    pub(crate) fn check_string_bytes(len: usize) -> Result<(), ParseError> {
+        match DecodeLimits::current() {
+            Some(limits) if len > limits.max_string_length.saturating_mul(3) => Err("The string is longer than the maximum string length.".into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the length of a decoded string, counted as the Notchian server does.
+    pub(crate) fn check_string(string: &str) -> Result<(), ParseError> {
+        match DecodeLimits::current() {
+            Some(limits) if string.encode_utf16().count() > limits.max_string_length => Err("The string is longer than the maximum string length.".into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// The number of NBT bytes that can still be parsed in the current scope.
+    pub(crate) fn nbt_bytes_left() -> usize {
+        match DecodeLimits::current() {
+            Some(limits) => limits.max_nbt_size.saturating_sub(NBT_BYTES.with(|bytes| bytes.get())),
+            None => usize::MAX,
+        }
+    }
+
+    pub(crate) fn consume_nbt_bytes(len: usize) {
+        NBT_BYTES.with(|bytes| bytes.set(bytes.get().saturating_add(len)));
+    }
+}
+
+/// Counts the nesting of NBT compounds and lists.
+/// The depth is decremented when the guard is dropped.
+pub(crate) struct NbtDepthGuard(());
+
+impl NbtDepthGuard {
+    pub(crate) fn enter() -> Result<NbtDepthGuard, &'static str> {
+        let depth = NBT_DEPTH.with(|depth| {
+            depth.set(depth.get() + 1);
+            depth.get()
+        });
+        let guard = NbtDepthGuard(());
+        match DecodeLimits::current() {
+            Some(limits) if depth > limits.max_nbt_depth => Err("NBT data is nested deeper than the maximum NBT depth."),
+            _ => Ok(guard),
+        }
+    }
+}
+
+impl Drop for NbtDepthGuard {
+    fn drop(&mut self) {
+        NBT_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nbt::NbtTag, packets::*};
+
+    #[test]
+    fn limits_are_enforced() {
+        let limits = DecodeLimits {
+            max_string_length: 4,
+            max_array_length: 2,
+            ..Default::default()
+        };
+
+        let data = "hello".to_string().serialize_minecraft_packet().unwrap();
+        assert!(<&str>::deserialize_uncompressed_minecraft_packet(&data).is_ok());
+        let error = <&str>::deserialize_uncompressed_minecraft_packet_with_limits(&data, limits).unwrap_err();
+        assert_eq!(error.message, "The string is longer than the maximum string length.");
+
+        let array: Array<u8, VarInt> = vec![1, 2, 3].into();
+        let data = array.serialize_minecraft_packet().unwrap();
+        assert!(<Array<u8, VarInt>>::deserialize_uncompressed_minecraft_packet(&data).is_ok());
+        assert!(<Array<u8, VarInt>>::deserialize_uncompressed_minecraft_packet_with_limits(&data, limits).is_err());
+
+        // A forged length is rejected before anything is allocated
+        let data = [0xff, 0xff, 0xff, 0xff, 0x07];
+        assert!(<Array<u8, VarInt>>::deserialize_uncompressed_minecraft_packet_with_limits(&data, limits).is_err());
+        assert_eq!(DecodeLimits::current(), None);
+    }
+
+    #[test]
+    fn nbt_limits() {
+        // Lists of lists nested 100 times
+        let mut data = vec![9];
+        for _ in 0..100 {
+            data.extend_from_slice(&[9, 0, 0, 0, 1]);
+        }
+        data.extend_from_slice(&[0, 0, 0, 0, 0]);
+        let limits = DecodeLimits {
+            max_nbt_depth: 50,
+            ..Default::default()
+        };
+        assert!(NbtTag::deserialize_uncompressed_minecraft_packet(&data).is_ok());
+        let error = NbtTag::deserialize_uncompressed_minecraft_packet_with_limits(&data, limits).unwrap_err();
+        assert_eq!(error.message, "NBT data is nested deeper than the maximum NBT depth.");
+
+        // The default limits apply when none are given
+        let mut data = vec![7];
+        data.extend_from_slice(&3_000_000i32.to_be_bytes());
+        data.resize(3_000_005, 0);
+        let error = NbtTag::deserialize_uncompressed_minecraft_packet(&data).unwrap_err();
+        assert_eq!(error.message, "NBT data is larger than the maximum NBT size.");
+        assert!(NbtTag::deserialize_uncompressed_minecraft_packet_with_limits(&data, DecodeLimits::unlimited()).is_ok());
+    }
+}
@@ -1,13 +1,17 @@
 //! For general information about login, see [the wiki](https://wiki.vg/Protocol#Login).
 
 use crate::*;
+use serde::{Deserialize, Serialize};
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ClientboundPacket<'a> {
     Disconnect {
-        reason: Chat<'a>,
+        /// A JSON text component.
+        /// Unlike other [Chat] fields, it is still sent as JSON since 1.20.3.
+        reason: &'a str,
     },
 
     /// See [Protocol Encryption](https://wiki.vg/Protocol_Encryption) for details.
@@ -22,7 +26,7 @@ pub enum ClientboundPacket<'a> {
         verify_token: Array<'a, u8, VarInt>,
     },
 
-    /// This packet switches the connection state to [ConnectionState::Play].
+    /// The client answers with [ServerboundPacket::LoginAcknowledged], which switches the connection state to [ConnectionState::Configuration].
     ///
     /// **Warning**: The (notchian) server might take a bit to fully transition to the `Play` state, so it's recommended to wait before sending `Play` packets, either by setting a timeout, or waiting for Play packets from the server (usually [PlayerInfo](super::play_clientbound::ClientboundPacket::PlayerInfo)).
     /// The notchian client doesn't send any (non-keep alive) packets until the next tick/time update packet.
@@ -34,10 +38,11 @@ pub enum ClientboundPacket<'a> {
 
     /// Enables compression.
     /// If compression is enabled, all following packets are encoded in the compressed packet format.
-    /// Negative or zero values will disable compression, meaning the packet format should remain in the uncompressed packet format.
+    /// Negative values will disable compression, meaning the packet format should remain in the uncompressed packet format.
+    /// A threshold of zero compresses every packet.
     /// However, this packet is entirely optional, and if not sent, compression will also not be enabled (the notchian server does not send the packet when compression is disabled).
     SetCompression {
-        /// Maximum size of a packet before it is compressed
+        /// Minimum size of a packet for it to be compressed
         threshold: VarInt,
     },
 
@@ -58,6 +63,7 @@ pub enum ClientboundPacket<'a> {
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ServerboundPacket<'a> {
     LoginStart {
         /// Player's Username
@@ -84,5 +90,228 @@ pub enum ServerboundPacket<'a> {
         data: Option<RawBytes<'a>>,
     },
 
+    /// This packet switches the connection state to [ConnectionState::Configuration].
+    ///
+    /// *Response to [ClientboundPacket::LoginSuccess]*
     LoginAcknowledged,
 }
+
+/// The identity of a player, as sent in [ClientboundPacket::LoginSuccess].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GameProfile {
+    pub uuid: UUID,
+    pub username: String,
+    pub properties: Vec<ProfileProperty>,
+}
+
+/// An owned [Property](components::players::Property), such as the skin of the player.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileProperty {
+    pub name: String,
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+impl GameProfile {
+    /// The profile given to a player by servers in offline mode.
+    pub fn offline(username: &str) -> GameProfile {
+        GameProfile {
+            uuid: UUID::offline_player(username),
+            username: username.to_string(),
+            properties: Vec::new(),
+        }
+    }
+
+    /// Builds the [ClientboundPacket::LoginSuccess] packet for this profile.
+    pub fn login_success(&self) -> ClientboundPacket<'_> {
+        let properties: Vec<components::players::Property> = self
+            .properties
+            .iter()
+            .map(|property| components::players::Property {
+                name: &property.name,
+                value: &property.value,
+                signature: property.signature.as_deref(),
+            })
+            .collect();
+        ClientboundPacket::LoginSuccess {
+            uuid: self.uuid,
+            username: &self.username,
+            properties: properties.into(),
+        }
+    }
+}
+
+/// The progress of a [ServerLogin].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginStep {
+    /// Waiting for [ServerboundPacket::LoginStart].
+    Start,
+    /// [ClientboundPacket::LoginSuccess] was sent, waiting for [ServerboundPacket::LoginAcknowledged].
+    Acknowledgement,
+    /// The connection is in the [ConnectionState::Configuration] state.
+    Done,
+}
+
+/// Drives the login sequence of an offline mode server.
+///
+/// It answers [ServerboundPacket::LoginStart] with [ClientboundPacket::SetCompression] if compression is enabled, then with [ClientboundPacket::LoginSuccess].
+/// The login is complete once the client sends [ServerboundPacket::LoginAcknowledged].
+///
+/// ```no_run
+/// use minecraft_protocol::{
+///     network::connection::Connection,
+///     packets::{login::ServerLogin, ConnectionState},
+/// };
+/// use std::net::TcpListener;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (stream, _) = TcpListener::bind("127.0.0.1:25565")?.accept()?;
+/// let mut connection = Connection::new(stream);
+/// connection.receive_serverbound()?; // The handshake
+/// let profile = ServerLogin::new().compression(256).run(&mut connection)?;
+/// assert_eq!(connection.state(), ConnectionState::Configuration);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ServerLogin {
+    compression: Option<u32>,
+    properties: Vec<ProfileProperty>,
+    step: LoginStep,
+    profile: Option<GameProfile>,
+}
+
+impl Default for ServerLogin {
+    fn default() -> Self {
+        ServerLogin::new()
+    }
+}
+
+impl ServerLogin {
+    pub fn new() -> Self {
+        ServerLogin {
+            compression: None,
+            properties: Vec::new(),
+            step: LoginStep::Start,
+            profile: None,
+        }
+    }
+
+    /// Enables compression for packets of at least `threshold` bytes, so zero compresses every packet.
+    pub fn compression(mut self, threshold: u32) -> Self {
+        self.compression = Some(threshold);
+        self
+    }
+
+    /// Sets the properties sent in [ClientboundPacket::LoginSuccess], such as the skin of the player.
+    pub fn properties(mut self, properties: Vec<ProfileProperty>) -> Self {
+        self.properties = properties;
+        self
+    }
+
+    pub fn step(&self) -> LoginStep {
+        self.step
+    }
+
+    /// The profile of the player, known once [ServerboundPacket::LoginStart] is received.
+    pub fn profile(&self) -> Option<&GameProfile> {
+        self.profile.as_ref()
+    }
+
+    pub fn into_profile(self) -> Option<GameProfile> {
+        self.profile
+    }
+
+    /// Handles a packet sent by the client.
+    /// Returns the packets to send in response, in order.
+    pub fn receive(&mut self, packet: ServerboundPacket) -> Result<Vec<ClientboundPacket<'_>>, &'static str> {
+        match (self.step, packet) {
+            (LoginStep::Start, ServerboundPacket::LoginStart { username, .. }) => {
+                if username.is_empty() || username.len() > 16 {
+                    return Err("Invalid username");
+                }
+                let mut profile = GameProfile::offline(username);
+                profile.properties = std::mem::take(&mut self.properties);
+                self.profile = Some(profile);
+                self.step = LoginStep::Acknowledgement;
+
+                let mut responses = Vec::new();
+                if let Some(threshold) = self.compression {
+                    responses.push(ClientboundPacket::SetCompression {
+                        threshold: VarInt(threshold as i32),
+                    });
+                }
+                if let Some(profile) = &self.profile {
+                    responses.push(profile.login_success());
+                }
+                Ok(responses)
+            }
+            (LoginStep::Acknowledgement, ServerboundPacket::LoginAcknowledged) => {
+                self.step = LoginStep::Done;
+                Ok(Vec::new())
+            }
+            (_, ServerboundPacket::LoginPluginResponse { .. }) => Ok(Vec::new()),
+            _ => Err("Unexpected packet during login"),
+        }
+    }
+
+    /// Runs the login sequence on a connection in the [ConnectionState::Login] state.
+    /// Returns the profile of the player once the connection is in the [ConnectionState::Configuration] state.
+    pub fn run<S: std::io::Read + std::io::Write>(
+        mut self,
+        connection: &mut crate::network::connection::Connection<S>,
+    ) -> Result<GameProfile, crate::network::NetworkError> {
+        use crate::network::connection::{Clientbound, Serverbound};
+
+        while self.step != LoginStep::Done {
+            let packet = match connection.receive_serverbound()? {
+                Serverbound::Login(packet) => packet,
+                _ => return Err("The connection is not in the login state".into()),
+            };
+            for response in self.receive(packet)? {
+                connection.send_clientbound(Clientbound::Login(response))?;
+            }
+        }
+        Ok(self.profile.expect("The profile is known once the login is done"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::{connection::Serverbound, testing::loopback};
+
+    #[test]
+    fn offline_login() {
+        let uuid = GameProfile::offline("Notch").uuid;
+        assert_eq!(uuid.to_hyphenated_string(), "b50ad385-829d-3141-a216-7e7d7539ba7f");
+        assert_eq!(UUID::parse_str(&uuid.to_simple_string()), Some(uuid));
+        assert_eq!(UUID::parse_str(&uuid.to_hyphenated_string()), Some(uuid));
+
+        let mut server = loopback(ConnectionState::Login, |client| {
+            client.send_serverbound(Serverbound::Login(ServerboundPacket::LoginStart { username: "Notch", player_uuid: 0 })).unwrap();
+            client.set_compression(Some(256));
+            client.send_serverbound(Serverbound::Login(ServerboundPacket::LoginAcknowledged)).unwrap();
+        });
+        let mut login = ServerLogin::new().compression(256);
+        let packet = match server.receive_serverbound().unwrap() {
+            Serverbound::Login(packet) => packet,
+            _ => unreachable!(),
+        };
+        let responses = login.receive(packet).unwrap();
+        assert!(matches!(responses[0], ClientboundPacket::SetCompression { threshold: VarInt(256) }));
+        assert!(matches!(responses[1], ClientboundPacket::LoginSuccess { username: "Notch", .. }));
+        drop(responses);
+
+        server.set_compression(Some(256));
+        let packet = match server.receive_serverbound().unwrap() {
+            Serverbound::Login(packet) => packet,
+            _ => unreachable!(),
+        };
+        login.receive(packet).unwrap();
+        assert_eq!(login.step(), LoginStep::Done);
+        assert_eq!(server.state(), ConnectionState::Configuration);
+    }
+}
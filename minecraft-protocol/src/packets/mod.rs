@@ -4,13 +4,23 @@ pub mod serializer;
 pub mod config;
 pub use minecraft_protocol_derive::*;
 use serializer::*;
+use version::SinceVersion;
 use std::{convert::TryFrom, collections::BTreeMap, ops::AddAssign};
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
 pub mod handshake;
+pub mod limits;
 pub mod login;
+pub mod owned;
+pub mod raw;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_impls;
 pub mod status;
+pub mod version;
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
 pub struct VarInt(pub i32);
 impl TryFrom<VarInt> for usize {
     type Error = std::num::TryFromIntError;
@@ -39,6 +49,7 @@ impl From<i32> for VarInt {
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
 pub struct VarLong(pub i64);
 impl TryFrom<VarLong> for usize {
     type Error = std::num::TryFromIntError;
@@ -55,6 +66,7 @@ impl TryFrom<usize> for VarLong {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Position {
     pub x: i32,
     pub y: i16,
@@ -71,6 +83,7 @@ impl AddAssign<Position> for Position {
 
 
 #[derive(Debug, PartialEq, Clone, MinecraftPacketPart)] 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct GlobalPosition<'a> {
     dimension: Identifier<'a>,
     position: Position,
@@ -78,6 +91,7 @@ pub struct GlobalPosition<'a> {
 
 #[minecraft_enum(VarInt)]
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Direction {
     South = 1,
     West,
@@ -86,10 +100,88 @@ pub enum Direction {
 }
 
 pub type UUID = u128;
+
+/// Helpers on [UUID], which is an alias of `u128`.
+pub trait UUIDExt: Sized {
+    /// The UUID given to a player by servers in offline mode, like Java's `UUID.nameUUIDFromBytes(("OfflinePlayer:" + name).getBytes(UTF_8))`.
+    fn offline_player(username: &str) -> Self;
+    /// Formats the UUID with hyphens, like `069a79f4-44e9-4726-a5be-fca90e38aaf5`.
+    fn to_hyphenated_string(&self) -> String;
+    /// Formats the UUID without hyphens, as the session server does.
+    fn to_simple_string(&self) -> String;
+    /// Parses a UUID with or without hyphens.
+    fn parse_str(uuid: &str) -> Option<Self>;
+}
+
+impl UUIDExt for UUID {
+    fn offline_player(username: &str) -> UUID {
+        use md5::{Digest, Md5};
+
+        let mut hash: [u8; 16] = Md5::digest(format!("OfflinePlayer:{}", username)).into();
+        // Version 3 (name based, MD5) and IETF variant
+        hash[6] = (hash[6] & 0x0f) | 0x30;
+        hash[8] = (hash[8] & 0x3f) | 0x80;
+        UUID::from_be_bytes(hash)
+    }
+
+    fn to_hyphenated_string(&self) -> String {
+        let simple = self.to_simple_string();
+        format!("{}-{}-{}-{}-{}", &simple[..8], &simple[8..12], &simple[12..16], &simple[16..20], &simple[20..])
+    }
+
+    fn to_simple_string(&self) -> String {
+        format!("{:032x}", self)
+    }
+
+    fn parse_str(uuid: &str) -> Option<UUID> {
+        let simple: String = match uuid.len() {
+            32 => uuid.to_string(),
+            36 if [8, 13, 18, 23].iter().all(|&i| uuid.as_bytes()[i] == b'-') => uuid.chars().filter(|c| *c != '-').collect(),
+            _ => return None,
+        };
+        if !simple.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        UUID::from_str_radix(&simple, 16).ok()
+    }
+}
 pub type Angle = u8;
-/// Json encoded data, stored in a String.
-/// See [the wiki](https://wiki.vg/Chat).
-pub type Chat<'a> = &'a str; /// TODO: Check is 
+/// A text component.
+/// See [the wiki](https://wiki.vg/Text_formatting).
+///
+/// Text components are sent as JSON strings, and as NBT since [1.20.3](version::ProtocolVersion::V1_20_3).
+/// They are parsed in the format of the current [ProtocolVersion](version::ProtocolVersion), and converted when sent to a version using the other format.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Chat<'a> {
+    Json(&'a str),
+    Nbt(crate::nbt::NbtTag),
+}
+
+impl<'a> Chat<'a> {
+    /// The component as JSON.
+    /// Invalid JSON is treated as plain text, as the Notchian client does.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Chat::Json(json) => serde_json::from_str(json).unwrap_or_else(|_| serde_json::Value::String(json.to_string())),
+            Chat::Nbt(tag) => tag.to_json(),
+        }
+    }
+
+    /// The component as NBT.
+    pub fn to_nbt(&self) -> crate::nbt::NbtTag {
+        match self {
+            Chat::Json(_) => crate::nbt::NbtTag::from_json(&self.to_json()),
+            Chat::Nbt(tag) => tag.clone(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Chat<'a> {
+    fn from(json: &'a str) -> Self {
+        Chat::Json(json)
+    }
+}
+
 /// Identifiers are a namespaced location, in the form of `minecraft:thing`.
 /// If the namespace is not provided, it defaults to `minecraft` (i.e. thing is `minecraft:thing`).
 /// Custom content should always be in its own namespace, not the default one.
@@ -171,9 +263,9 @@ impl<'a, K: std::fmt::Debug + MinecraftPacketPart<'a>, V: std::fmt::Debug + Mine
 }
 
 /// The possible packets are different for each state.
-#[cfg_attr(test, derive(PartialEq))]
 #[minecraft_enum(VarInt)]
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ConnectionState {
     /// The possible packets are listed in [handshake].
     HandShake,
@@ -183,4 +275,7 @@ pub enum ConnectionState {
     Login,
     /// The possible packets are listed in [play_clientbound] and [play_serverbound].
     Play,
+    /// The possible packets are listed in [config].
+    /// This state is entered after [login] and cannot be requested in a [handshake].
+    Configuration,
 }
@@ -0,0 +1,166 @@
+//! Packets that own the bytes they were parsed from.
+//!
+//! Parsed packets borrow strings and byte arrays from their input, so they cannot outlive the buffer they were read into.
+//! [Owned] stores a packet along with its buffer, so that it can be sent to another thread or kept across await points without being parsed again.
+
+use super::*;
+use std::fmt;
+use yoke::{Yoke, Yokeable};
+
+/// A packet stored along with the bytes it borrows from.
+/// `P` is the `'static` version of the packet type, such as `ClientboundPacket<'static>`, which the derive macro makes [Yokeable].
+pub struct Owned<P: for<'a> Yokeable<'a>> {
+    inner: Yoke<P, Vec<u8>>,
+}
+
+impl<P: for<'a> Yokeable<'a>> Owned<P> {
+    /// Parses a packet that borrows from `data` with a custom parsing function.
+    pub fn try_new<E>(data: Vec<u8>, parse: impl for<'a> FnOnce(&'a [u8]) -> Result<<P as Yokeable<'a>>::Output, E>) -> Result<Self, E> {
+        Ok(Owned {
+            inner: Yoke::try_attach_to_cart(data, parse)?,
+        })
+    }
+
+    /// Parses a whole packet.
+    pub fn deserialize(data: Vec<u8>) -> Result<Self, ParseError>
+    where
+        for<'a> <P as Yokeable<'a>>::Output: MinecraftPacketPart<'a>,
+    {
+        Owned::try_new(data, |input| MinecraftPacketPart::deserialize_uncompressed_minecraft_packet(input))
+    }
+
+    /// The packet, borrowing from the bytes stored alongside it.
+    pub fn get(&self) -> &<P as Yokeable<'_>>::Output {
+        self.inner.get()
+    }
+
+    /// Modifies the packet in place, for example to rewrite it before forwarding it.
+    /// The closure cannot store borrowed data other than `'static` values and the packet's own, so the packet cannot outlive what it borrows.
+    pub fn with_mut<'a, F>(&'a mut self, f: F)
+    where
+        F: 'static + for<'b> FnOnce(&'b mut <P as Yokeable<'a>>::Output),
+    {
+        self.inner.with_mut(f)
+    }
+
+    /// The bytes the packet was parsed from.
+    /// They are not updated by [Owned::with_mut].
+    pub fn data(&self) -> &[u8] {
+        self.inner.backing_cart()
+    }
+}
+
+impl<P: for<'a> Yokeable<'a>> fmt::Debug for Owned<P>
+where
+    for<'a> <P as Yokeable<'a>>::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packets::play_clientbound::ClientboundPacket;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    fn system_chat_message(text: &str) -> Vec<u8> {
+        ClientboundPacket::SystemChatMessage { content: Chat::Json(text), overlay: false }.serialize_minecraft_packet().unwrap()
+    }
+
+    #[test]
+    fn owned_packet_moves_across_threads() {
+        let frame = system_chat_message(r#"{"text":"Hello"}"#);
+        let packet: Owned<ClientboundPacket<'static>> = Owned::deserialize(frame.clone()).unwrap();
+
+        let packet = std::thread::spawn(move || packet).join().unwrap();
+        assert_eq!(packet.data(), frame.as_slice());
+        assert_eq!(
+            packet.get(),
+            &ClientboundPacket::SystemChatMessage { content: Chat::Json(r#"{"text":"Hello"}"#), overlay: false }
+        );
+    }
+
+    #[test]
+    fn owned_packet_is_mutable() {
+        let mut packet: Owned<ClientboundPacket<'static>> = Owned::deserialize(system_chat_message(r#"{"text":"Hello"}"#)).unwrap();
+        packet.with_mut(|packet| {
+            if let ClientboundPacket::SystemChatMessage { content, overlay } = packet {
+                *content = Chat::Json(r#"{"text":"Bye"}"#);
+                *overlay = true;
+            }
+        });
+        assert_eq!(packet.get(), &ClientboundPacket::SystemChatMessage { content: Chat::Json(r#"{"text":"Bye"}"#), overlay: true });
+        assert_eq!(packet.data(), system_chat_message(r#"{"text":"Hello"}"#).as_slice());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(Owned::<ClientboundPacket<'static>>::deserialize(vec![0x7F]).is_err());
+        let mut frame = system_chat_message(r#"{"text":"Hello"}"#);
+        frame.pop();
+        assert!(Owned::<ClientboundPacket<'static>>::deserialize(frame).is_err());
+
+        let result: Result<Owned<ClientboundPacket<'static>>, &str> = Owned::try_new(vec![1, 2, 3], |data| {
+            assert_eq!(data, [1, 2, 3]);
+            Err("Invalid packet")
+        });
+        assert_eq!(result.unwrap_err(), "Invalid packet");
+    }
+
+    /// Checks that the bytes it borrows are still valid when it is dropped.
+    struct DropCheck<'a> {
+        data: &'a [u8],
+        drops: Arc<AtomicUsize>,
+    }
+
+    impl Drop for DropCheck<'_> {
+        fn drop(&mut self) {
+            assert_eq!(self.data, [1, 2, 3]);
+            self.drops.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    unsafe impl<'a> Yokeable<'a> for DropCheck<'static> {
+        type Output = DropCheck<'a>;
+
+        fn transform(&'a self) -> &'a Self::Output {
+            self
+        }
+
+        fn transform_owned(self) -> Self::Output {
+            self
+        }
+
+        unsafe fn make(from: Self::Output) -> Self {
+            std::mem::transmute::<DropCheck<'a>, DropCheck<'static>>(from)
+        }
+
+        fn transform_mut<F>(&'a mut self, f: F)
+        where
+            F: 'static + for<'b> FnOnce(&'b mut Self::Output),
+        {
+            unsafe { f(std::mem::transmute::<&mut Self, &mut Self::Output>(self)) }
+        }
+    }
+
+    #[test]
+    fn packet_is_dropped_before_data() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let owned: Owned<DropCheck<'static>> = Owned::try_new(vec![1, 2, 3], |data| {
+            Ok::<_, ()>(DropCheck {
+                data,
+                drops: Arc::clone(&drops),
+            })
+        })
+        .unwrap();
+        assert_eq!(owned.get().data, [1, 2, 3]);
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+        drop(owned);
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+    }
+}
@@ -11,6 +11,7 @@ use crate::nbt::NbtTag;
 #[derive(Debug, MinecraftPacketPart)]
 #[allow(clippy::large_enum_variant)] // TODO: fix this
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ClientboundPacket<'a> {
     /// The delimiter for a bundle of packets. When received, the client should store every subsequent packet it receives, and wait until another delimiter is received. Once that happens, the client is guaranteed to process every packet in the bundle on the same tick.
     BundleDelimiter,
@@ -328,6 +329,9 @@ pub enum ClientboundPacket<'a> {
         player_acceleration_y: f32,
         /// Z velocity of the player being pushed by the explosion.
         player_acceleration_z: f32,
+        /// The block interaction, particles and sound of the explosion, added in 1.20.3.
+        /// These fields are not supported and are left undecoded, since the particles are numbered by a registry that this crate does not have for 1.20.3.
+        effects: SinceVersion<RawBytes<'a>, 765>,
     },    
 
     /// Tells the client to unload a chunk column.
@@ -633,14 +637,15 @@ pub enum ClientboundPacket<'a> {
         index: VarInt,
         /// Cryptography, the signature consists of the Sender UUID, Session UUID from the Player Session packet, Index, Salt, Timestamp in epoch seconds, the length of the original chat content, the original content itself, the length of Previous Messages, and all of the Previous message signatures.
         /// These values are hashed with SHA-256 and signed using the RSA cryptosystem. Modifying any of these values in the packet will cause this signature to fail. This buffer is always 256 bytes long and it is not length-prefixed.
+        #[cfg_attr(feature = "serde", serde(serialize_with = "serde_impls::serialize_optional_bytes"))]
         message_signature: Option<[u8; 256]>,
-        message: Chat<'a>,
+        message: &'a str,
         /// Represents the time the message was signed as milliseconds since the [epoch](https://en.wikipedia.org/wiki/Unix_time),
         /// used to check if the message was received within 2 minutes of it being sent.
         timestamp: i64,
         /// Cryptography, used for validating the message signature.
         salt: i64,
-        previous_messages: Array<'a, chat::PreviousMessage<'a>, VarInt>,
+        previous_messages: Array<'a, chat::PreviousMessage, VarInt>,
         unsigned_content: Option<Chat<'a>>,
         /// If the message has been filtered
         filter: chat::FilterType<'a>,
@@ -738,6 +743,8 @@ pub enum ClientboundPacket<'a> {
     },
 
     SendResourcePack {
+        /// The unique identifier of the resource pack, added in 1.20.3.
+        uuid: SinceVersion<UUID, 765>,
         /// The URL to the resource pack
         url: &'a str,
         /// A 40 character hexadecimal and lowercase SHA-1 hash of the resource pack file. (must be lower case in order to work)
@@ -1175,5 +1182,30 @@ pub enum ClientboundPacket<'a> {
         /// More information on tags is available at: https://minecraft.gamepedia.com/Tag
         /// And a list of all tags is here: https://minecraft.gamepedia.com/Tag#List_of_tags
         tags: Map<'a, Identifier<'a>, Array<'a, tags::Tag<'a>, VarInt>, VarInt>,
-    },    
+    },
+
+    /// Removes a score, added in 1.20.3.
+    /// Scores were removed by [ClientboundPacket::UpdateScore] before.
+    ResetScore {
+        entity_name: &'a str,
+        /// The objective of the score to remove, or all the scores of the entity if `None`.
+        objective_name: Option<&'a str>,
+    },
+
+    /// Added in 1.20.3.
+    RemoveResourcePack {
+        /// The resource pack to remove, or all of them if `None`.
+        uuid: Option<UUID>,
+    },
+
+    /// Added in 1.20.3.
+    SetTickingState {
+        tick_rate: f32,
+        is_frozen: bool,
+    },
+
+    /// Advances the game by some ticks while it is frozen, added in 1.20.3.
+    StepTick {
+        tick_steps: VarInt,
+    },
 }
@@ -6,6 +6,7 @@ use crate::components::*;
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ServerboundPacket<'a> {
     /// *Response to [ClientboundPacket::SynchronizePlayerPosition]*
     ConfirmTeleportation {
@@ -48,7 +49,7 @@ pub enum ServerboundPacket<'a> {
     /// *See also [ClientboundPacket::ChatMessage]*
     ChatMessage {
         /// The message may not be longer than 256 characters or else the server will kick the client.
-        message: Chat<'a>,
+        message: &'a str,
         timestamp: i64,
         /// The salt used to verify the signature hash.
         salt: u64,
@@ -397,6 +398,8 @@ pub enum ServerboundPacket<'a> {
 
     /// *Response to [ClientboundPacket::ResourcePackSend]*
     ResourcePackStatus {
+        /// The unique identifier of the resource pack, added in 1.20.3.
+        uuid: SinceVersion<UUID, 765>,
         status: resource_pack::ResourcePackStatus,
     },
 
@@ -570,4 +573,11 @@ pub enum ServerboundPacket<'a> {
         hand: slots::Hand,
         sequence: VarInt,
     },
+
+    /// Sent when a slot of a crafter is toggled, added in 1.20.3.
+    ChangeContainerSlotState {
+        slot_id: VarInt,
+        window_id: VarInt,
+        state: bool,
+    },
 }
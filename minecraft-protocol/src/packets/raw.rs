@@ -0,0 +1,91 @@
+//! Lazy decoding of packets.
+//!
+//! Only the packet id is read, so that packets can be filtered by kind and forwarded without parsing their fields.
+//! This also lets proxies forward packets that this crate cannot parse.
+//!
+//! ```
+//! use minecraft_protocol::{
+//!     packets::{play_clientbound::{ClientboundPacket, ClientboundPacketKind}, raw::RawPacket, Chat},
+//!     MinecraftPacketPart,
+//! };
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let frame = ClientboundPacket::SystemChatMessage { content: Chat::Json(r#"{"text":"Hello"}"#), overlay: false }.serialize_minecraft_packet()?;
+//! let packet = RawPacket::peek(&frame)?;
+//! match ClientboundPacketKind::from_packet_id(packet.id) {
+//!     Some(ClientboundPacketKind::SystemChatMessage) => println!("{:?}", packet.decode::<ClientboundPacket>()?),
+//!     _ => println!("Forwarded {} bytes", frame.len()),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{limits::DecodeLimits, version::ProtocolVersion, *};
+
+/// A packet of which only the id has been read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawPacket<'a> {
+    /// The packet id, as numbered by the packet enums.
+    pub id: i32,
+    /// The version for which the fields are encoded.
+    pub version: ProtocolVersion,
+    /// The fields of the packet, left undecoded.
+    pub body: &'a [u8],
+}
+
+impl<'a> RawPacket<'a> {
+    /// Reads the packet id at the start of a decompressed frame, whose fields are encoded for [ProtocolVersion::current].
+    pub fn peek(frame: &'a [u8]) -> Result<RawPacket<'a>, ParseError> {
+        let (id, body) = VarInt::deserialize_minecraft_packet_part(frame).map_err(|e| e.in_field("packet_id"))?;
+        Ok(RawPacket {
+            id: id.0,
+            version: ProtocolVersion::current(),
+            body,
+        })
+    }
+
+    /// Parses the fields of the packet with a packet enum, with [DecodeLimits::default] unless other limits are in scope.
+    pub fn decode<T: MinecraftPacketEnum<'a>>(&self) -> Result<T, ParseError> {
+        let (packet, rest) = self
+            .version
+            .scope(|| DecodeLimits::scope_or_default(|| T::deserialize_variant(self.id, self.body)))
+            .map_err(|e| e.with_offset(self.body.len()))?;
+        if !rest.is_empty() {
+            return Err(ParseError::new("There are still unparsed bytes after parsing.")
+                .at(rest.len())
+                .with_offset(self.body.len()));
+        }
+        Ok(packet)
+    }
+
+    /// Writes the packet id and the body back into a frame.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut frame = Vec::with_capacity(self.body.len() + 5);
+        VarInt(self.id)
+            .serialize_minecraft_packet_part(&mut frame)
+            .expect("VarInts are always serializable");
+        frame.extend_from_slice(self.body);
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packets::play_clientbound::{ClientboundPacket, ClientboundPacketKind};
+
+    #[test]
+    fn peek_and_decode() {
+        let frame = ClientboundPacket::StepTick { tick_steps: VarInt(3) }.serialize_minecraft_packet().unwrap();
+        let packet = RawPacket::peek(&frame).unwrap();
+        assert_eq!(ClientboundPacketKind::from_packet_id(packet.id), Some(ClientboundPacketKind::StepTick));
+        assert_eq!(packet.serialize(), frame);
+        assert!(matches!(packet.decode(), Ok(ClientboundPacket::StepTick { tick_steps: VarInt(3) })));
+
+        // Packets are forwarded unchanged even if their fields cannot be parsed
+        let unparsable = RawPacket { body: &[0xFF; 6], ..packet };
+        assert!(unparsable.decode::<ClientboundPacket>().is_err());
+        assert_eq!(RawPacket::peek(&unparsable.serialize()).unwrap(), unparsable);
+        assert!(RawPacket { id: 0x7FFF, ..packet }.decode::<ClientboundPacket>().is_err());
+    }
+}
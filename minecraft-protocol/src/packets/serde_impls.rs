@@ -0,0 +1,91 @@
+//! Serialization of the protocol types with [serde], enabled by the `serde` feature.
+//!
+//! Packets are serialized as they are represented in Rust, which is mostly useful for logging and inspecting traffic.
+//! [VarInt] and [VarLong] are plain numbers, [Array] and [FixedSizeArray] are sequences, [Map] is a map and [Chat] is its JSON component.
+//! [RawBytes] is a hexadecimal string in human-readable formats and a byte string otherwise.
+//!
+//! Deserialization is not provided since packets borrow their strings and byte arrays from the frames they are parsed from.
+
+use super::*;
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+impl<'a, T: MinecraftPacketPart<'a> + std::fmt::Debug + Serialize, U: MinecraftPacketPart<'a>> Serialize for Array<'a, T, U> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.items.serialize(serializer)
+    }
+}
+
+impl<'a, V: MinecraftPacketPart<'a> + Serialize, const N: usize> Serialize for FixedSizeArray<'a, V, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.items.serialize(serializer)
+    }
+}
+
+impl<'a, K, V, U> Serialize for Map<'a, K, V, U>
+where
+    K: MinecraftPacketPart<'a> + std::fmt::Debug + Serialize,
+    V: MinecraftPacketPart<'a> + std::fmt::Debug + Serialize,
+    U: MinecraftPacketPart<'a>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.items.len()))?;
+        for (key, value) in &self.items {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'a> Serialize for RawBytes<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.data, serializer)
+    }
+}
+
+impl<'a> Serialize for Chat<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_json().serialize(serializer)
+    }
+}
+
+fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+        serializer.serialize_str(&hex)
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Serializes fixed-size byte arrays such as signatures, which serde does not support beyond 32 elements.
+pub(crate) fn serialize_optional_bytes<S: Serializer, const N: usize>(bytes: &Option<[u8; N]>, serializer: S) -> Result<S::Ok, S::Error> {
+    match bytes {
+        Some(bytes) => serializer.serialize_some(&RawBytes { data: bytes }),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packets::play_clientbound::ClientboundPacket;
+
+    #[test]
+    fn packets_serialize_to_json() {
+        let packet = ClientboundPacket::SystemChatMessage {
+            content: Chat::Json(r#"{"text":"Hello"}"#),
+            overlay: false,
+        };
+        assert_eq!(
+            serde_json::to_value(&packet).unwrap(),
+            serde_json::json!({"SystemChatMessage": {"content": {"text": "Hello"}, "overlay": false}})
+        );
+
+        let packet = ClientboundPacket::StepTick { tick_steps: VarInt(3) };
+        assert_eq!(serde_json::to_value(&packet).unwrap(), serde_json::json!({"StepTick": {"tick_steps": 3}}));
+
+        let bytes: Array<u8, VarInt> = vec![1, 2].into();
+        assert_eq!(serde_json::to_value(&bytes).unwrap(), serde_json::json!([1, 2]));
+        assert_eq!(serde_json::to_value(RawBytes { data: &[0xca, 0xfe] }).unwrap(), serde_json::json!("cafe"));
+    }
+}
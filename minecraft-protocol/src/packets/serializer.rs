@@ -1,30 +1,137 @@
 use std::convert::{TryFrom, TryInto};
 
-use super::*;
+use super::{limits::DecodeLimits, version::ProtocolVersion, *};
+
+/// An error that occurred while parsing a packet.
+///
+/// The derive macro records the path of the field that failed to parse, so that the error can be located in the packet:
+/// `Missing byte while parsing i32. (at ClientboundPacket::ChunkData.value.chunk_z, offset 5, 0 bytes remaining)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// What went wrong.
+    pub message: &'static str,
+    /// The fields in which the error occurred, from the outermost to the innermost.
+    /// Enum variants are named `Enum::Variant`, so the first element is the packet enum and variant when parsing a packet.
+    pub path: Vec<&'static str>,
+    /// The number of bytes that were left in the input at the start of the innermost field that failed.
+    pub remaining: Option<usize>,
+    /// The position of the failed field from the start of the packet.
+    /// Only known when the error went through [MinecraftPacketPart::deserialize_uncompressed_minecraft_packet].
+    pub offset: Option<usize>,
+}
+
+impl ParseError {
+    pub fn new(message: &'static str) -> Self {
+        ParseError {
+            message,
+            path: Vec::new(),
+            remaining: None,
+            offset: None,
+        }
+    }
+
+    /// Records the number of remaining bytes, if the position of the error is not known yet.
+    pub fn at(mut self, remaining: usize) -> Self {
+        if self.remaining.is_none() {
+            self.remaining = Some(remaining);
+        }
+        self
+    }
+
+    /// Prepends a field or variant name to the path.
+    pub fn in_field(mut self, name: &'static str) -> Self {
+        self.path.insert(0, name);
+        self
+    }
+
+    /// Computes the offset from the total length of the packet.
+    pub fn with_offset(mut self, total_len: usize) -> Self {
+        if let Some(remaining) = self.remaining {
+            self.offset = Some(total_len.saturating_sub(remaining));
+        }
+        self
+    }
+
+    /// The packet enum and variant in which the error occurred, like `ClientboundPacket::ChunkData`.
+    pub fn packet(&self) -> Option<&'static str> {
+        self.path.first().copied().filter(|name| name.contains("::"))
+    }
+}
+
+impl From<&'static str> for ParseError {
+    fn from(message: &'static str) -> Self {
+        ParseError::new(message)
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+        if self.path.is_empty() && self.remaining.is_none() {
+            return Ok(());
+        }
+        write!(f, " (at ")?;
+        match self.path.is_empty() {
+            true => write!(f, "top level")?,
+            false => write!(f, "{}", self.path.join("."))?,
+        }
+        if let Some(offset) = self.offset {
+            write!(f, ", offset {}", offset)?;
+        }
+        if let Some(remaining) = self.remaining {
+            write!(f, ", {} bytes remaining", remaining)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl std::error::Error for ParseError {}
 
 /// Update the test trait too
 #[cfg(not(test))]
 pub trait MinecraftPacketPart<'a>: Sized 
 {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str>;
-    fn deserialize_minecraft_packet_part(input: &'a [u8]) -> Result<(Self, &'a [u8]), &'static str>;
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str>;
+    fn deserialize_minecraft_packet_part(input: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError>;
+
+    /// The exact number of bytes written by [serialize_minecraft_packet_part](MinecraftPacketPart::serialize_minecraft_packet_part), so that buffers and length prefixes can be prepared in advance.
+    /// The default implementation serializes the value into a throwaway buffer, so it should be overridden when the size can be computed directly.
+    /// The size of values that cannot be serialized is unspecified.
+    fn serialized_size(&self) -> usize {
+        let mut buffer = Vec::new();
+        let _ = self.serialize_minecraft_packet_part(&mut buffer);
+        buffer.len()
+    }
 
-    fn serialize_minecraft_packet(self) -> Result<Vec<u8>, &'static str> {
+    fn serialize_minecraft_packet(&self) -> Result<Vec<u8>, &'static str> {
         let mut buffer = Vec::new();
         self.serialize_minecraft_packet_part(&mut buffer)?;
         Ok(buffer)
     }
 
-    fn deserialize_uncompressed_minecraft_packet(input: &'a [u8]) -> Result<Self, &'static str> {
-        let (result, input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)?;
-        if !input.is_empty() {
-            return Err("There are still unparsed bytes after parsing.");
-        }
-        Ok(result)
+    /// Deserializes a whole packet, with [DecodeLimits::default] unless other limits are in scope.
+    fn deserialize_uncompressed_minecraft_packet(input: &'a [u8]) -> Result<Self, ParseError> {
+        DecodeLimits::scope_or_default(|| {
+            DecodeLimits::check_packet_size(input.len())?;
+            let (result, rest) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)
+                .map_err(|e| e.with_offset(input.len()))?;
+            if !rest.is_empty() {
+                return Err(ParseError::new("There are still unparsed bytes after parsing.")
+                    .at(rest.len())
+                    .with_offset(input.len()));
+            }
+            Ok(result)
+        })
     }
 
-    fn deserialize_n(mut input: &'a [u8], n: usize) -> Result<(Vec<Self>, &'a [u8]), &'static str> {
-        let mut result = Vec::with_capacity(n);
+    /// Deserializes an untrusted packet, rejecting it if it claims more resources than allowed by the limits.
+    fn deserialize_uncompressed_minecraft_packet_with_limits(input: &'a [u8], limits: DecodeLimits) -> Result<Self, ParseError> {
+        limits.scope(|| Self::deserialize_uncompressed_minecraft_packet(input))
+    }
+
+    fn deserialize_n(mut input: &'a [u8], n: usize) -> Result<(Vec<Self>, &'a [u8]), ParseError> {
+        // The count may come from untrusted input, so it cannot be used to allocate more than the input could contain
+        let mut result = Vec::with_capacity(n.min(input.len()));
         for _ in 0..n {
             let (item, new_input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)?;
             input = new_input;
@@ -38,25 +145,47 @@ pub trait MinecraftPacketPart<'a>: Sized
 #[cfg(test)]
 pub trait MinecraftPacketPart<'a>: Sized + PartialEq
 {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str>;
-    fn deserialize_minecraft_packet_part(input: &'a [u8]) -> Result<(Self, &'a [u8]), &'static str>;
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str>;
+    fn deserialize_minecraft_packet_part(input: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError>;
+
+    /// The exact number of bytes written by [serialize_minecraft_packet_part](MinecraftPacketPart::serialize_minecraft_packet_part), so that buffers and length prefixes can be prepared in advance.
+    /// The default implementation serializes the value into a throwaway buffer, so it should be overridden when the size can be computed directly.
+    /// The size of values that cannot be serialized is unspecified.
+    fn serialized_size(&self) -> usize {
+        let mut buffer = Vec::new();
+        let _ = self.serialize_minecraft_packet_part(&mut buffer);
+        buffer.len()
+    }
 
-    fn serialize_minecraft_packet(self) -> Result<Vec<u8>, &'static str> {
+    fn serialize_minecraft_packet(&self) -> Result<Vec<u8>, &'static str> {
         let mut buffer = Vec::new();
         self.serialize_minecraft_packet_part(&mut buffer)?;
         Ok(buffer)
     }
 
-    fn deserialize_uncompressed_minecraft_packet(input: &'a [u8]) -> Result<Self, &'static str> {
-        let (result, input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)?;
-        if !input.is_empty() {
-            return Err("There are still unparsed bytes after parsing.");
-        }
-        Ok(result)
+    /// Deserializes a whole packet, with [DecodeLimits::default] unless other limits are in scope.
+    fn deserialize_uncompressed_minecraft_packet(input: &'a [u8]) -> Result<Self, ParseError> {
+        DecodeLimits::scope_or_default(|| {
+            DecodeLimits::check_packet_size(input.len())?;
+            let (result, rest) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)
+                .map_err(|e| e.with_offset(input.len()))?;
+            if !rest.is_empty() {
+                return Err(ParseError::new("There are still unparsed bytes after parsing.")
+                    .at(rest.len())
+                    .with_offset(input.len()));
+            }
+            Ok(result)
+        })
     }
 
-    fn deserialize_n(mut input: &'a [u8], n: usize) -> Result<(Vec<Self>, &'a [u8]), &'static str> {
-        let mut result = Vec::with_capacity(n);
+    /// Deserializes an untrusted packet, rejecting it if it claims more resources than allowed by the limits.
+    fn deserialize_uncompressed_minecraft_packet_with_limits(input: &'a [u8], limits: DecodeLimits) -> Result<Self, ParseError> {
+        limits.scope(|| Self::deserialize_uncompressed_minecraft_packet(input))
+    }
+
+    fn deserialize_n(mut input: &'a [u8], n: usize) -> Result<(Vec<Self>, &'a [u8]), ParseError> {
+        // The count may come from untrusted input, so it cannot be used to allocate more than the input could contain
+        let mut result = Vec::with_capacity(n.min(input.len()));
         for _ in 0..n {
             let (item, new_input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)?;
             input = new_input;
@@ -66,16 +195,27 @@ pub trait MinecraftPacketPart<'a>: Sized + PartialEq
     }
 }
 
+/// An enum of which each variant is prefixed by its id, such as the packet enums.
+/// Implemented by the derive macro for enums with a `#[discriminant]` attribute.
+pub trait MinecraftPacketEnum<'a>: MinecraftPacketPart<'a> {
+    /// Parses the fields of the variant with this id, when the id has already been read.
+    fn deserialize_variant(id: i32, input: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError>;
+}
+
 mod integers {
     use super::*;
 
     impl<'a> MinecraftPacketPart<'a> for bool {
-        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
-            output.push(self as u8);
+        fn serialized_size(&self) -> usize {
+            std::mem::size_of::<bool>()
+        }
+
+        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+            output.push(*self as u8);
             Ok(())
         }
 
-        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), &'static str> {
+        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), ParseError> {
             let (value, input) = input
                 .split_first()
                 .ok_or("Missing byte while parsing bool.")?;
@@ -84,12 +224,16 @@ mod integers {
     }
 
     impl<'a> MinecraftPacketPart<'a> for i8 {
-        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        fn serialized_size(&self) -> usize {
+            std::mem::size_of::<i8>()
+        }
+
+        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
             output.push(self.to_le_bytes()[0]);
             Ok(())
         }
 
-        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), &'static str> {
+        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), ParseError> {
             let (value, input) = input
                 .split_first()
                 .ok_or("Missing byte while parsing i8.")?;
@@ -98,12 +242,16 @@ mod integers {
     }
 
     impl<'a> MinecraftPacketPart<'a> for u8 {
-        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
-            output.push(self);
+        fn serialized_size(&self) -> usize {
+            std::mem::size_of::<u8>()
+        }
+
+        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+            output.push(*self);
             Ok(())
         }
 
-        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), &'static str> {
+        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), ParseError> {
             let (value, input) = input
                 .split_first()
                 .ok_or("Missing byte while parsing u8.")?;
@@ -112,14 +260,18 @@ mod integers {
     }
 
     impl<'a> MinecraftPacketPart<'a> for (i8, i8, i8) {
-        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        fn serialized_size(&self) -> usize {
+            std::mem::size_of::<(i8, i8, i8)>()
+        }
+
+        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
             output.push(self.0.to_le_bytes()[0]);
             output.push(self.1.to_le_bytes()[0]);
             output.push(self.2.to_le_bytes()[0]);
             Ok(())
         }
 
-        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), &'static str> {
+        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), ParseError> {
             let (first_byte, input) = input
                 .split_first()
                 .ok_or("Missing byte while parsing (i8, i8, i8).")?;
@@ -141,16 +293,20 @@ mod integers {
     }
 
     impl<'a> MinecraftPacketPart<'a> for i16 {
-        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        fn serialized_size(&self) -> usize {
+            std::mem::size_of::<i16>()
+        }
+
+        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
             let bytes = self.to_le_bytes();
             output.push(bytes[1]);
             output.push(bytes[0]);
             Ok(())
         }
 
-        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), &'static str> {
+        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), ParseError> {
             if input.len() < 2 {
-                return Err("Missing byte while parsing i16.");
+                return Err("Missing byte while parsing i16.".into());
             }
             Ok(unsafe {
                 (
@@ -162,16 +318,20 @@ mod integers {
     }
 
     impl<'a> MinecraftPacketPart<'a> for u16 {
-        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        fn serialized_size(&self) -> usize {
+            std::mem::size_of::<u16>()
+        }
+
+        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
             let bytes = self.to_le_bytes();
             output.push(bytes[1]);
             output.push(bytes[0]);
             Ok(())
         }
 
-        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), &'static str> {
+        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), ParseError> {
             if input.len() < 2 {
-                return Err("Missing byte while parsing u16.");
+                return Err("Missing byte while parsing u16.".into());
             }
             Ok(unsafe {
                 (
@@ -183,7 +343,11 @@ mod integers {
     }
 
     impl<'a> MinecraftPacketPart<'a> for i32 {
-        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        fn serialized_size(&self) -> usize {
+            std::mem::size_of::<i32>()
+        }
+
+        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
             let bytes = self.to_le_bytes();
             output.push(bytes[3]);
             output.push(bytes[2]);
@@ -192,9 +356,9 @@ mod integers {
             Ok(())
         }
 
-        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), &'static str> {
+        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), ParseError> {
             if input.len() < 4 {
-                return Err("Missing byte while parsing i32.");
+                return Err("Missing byte while parsing i32.".into());
             }
             Ok(unsafe {
                 (
@@ -206,7 +370,11 @@ mod integers {
     }
 
     impl<'a> MinecraftPacketPart<'a> for u32 {
-        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        fn serialized_size(&self) -> usize {
+            std::mem::size_of::<u32>()
+        }
+
+        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
             let bytes = self.to_le_bytes();
             output.push(bytes[3]);
             output.push(bytes[2]);
@@ -215,9 +383,9 @@ mod integers {
             Ok(())
         }
 
-        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), &'static str> {
+        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), ParseError> {
             if input.len() < 4 {
-                return Err("Missing byte while parsing u32.");
+                return Err("Missing byte while parsing u32.".into());
             }
             Ok(unsafe {
                 (
@@ -229,7 +397,11 @@ mod integers {
     }
 
     impl<'a> MinecraftPacketPart<'a> for i64 {
-        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        fn serialized_size(&self) -> usize {
+            std::mem::size_of::<i64>()
+        }
+
+        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
             let bytes = self.to_le_bytes();
             output.push(bytes[7]);
             output.push(bytes[6]);
@@ -242,9 +414,9 @@ mod integers {
             Ok(())
         }
 
-        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), &'static str> {
+        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), ParseError> {
             if input.len() < 8 {
-                return Err("Missing byte while parsing i64.");
+                return Err("Missing byte while parsing i64.".into());
             }
             Ok(unsafe {
                 (
@@ -256,7 +428,11 @@ mod integers {
     }
 
     impl<'a> MinecraftPacketPart<'a> for u64 {
-        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        fn serialized_size(&self) -> usize {
+            std::mem::size_of::<u64>()
+        }
+
+        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
             let bytes = self.to_le_bytes();
             output.push(bytes[7]);
             output.push(bytes[6]);
@@ -269,9 +445,9 @@ mod integers {
             Ok(())
         }
 
-        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), &'static str> {
+        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), ParseError> {
             if input.len() < 8 {
-                return Err("Missing byte while parsing i64.");
+                return Err("Missing byte while parsing i64.".into());
             }
             Ok(unsafe {
                 (
@@ -283,7 +459,11 @@ mod integers {
     }
 
     impl<'a> MinecraftPacketPart<'a> for u128 {
-        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        fn serialized_size(&self) -> usize {
+            std::mem::size_of::<u128>()
+        }
+
+        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
             let bytes = self.to_le_bytes();
             output.push(bytes[15]);
             output.push(bytes[14]);
@@ -304,9 +484,9 @@ mod integers {
             Ok(())
         }
 
-        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), &'static str> {
+        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), ParseError> {
             if input.len() < 16 {
-                return Err("Missing byte while parsing u128 (UUID).");
+                return Err("Missing byte while parsing u128 (UUID).".into());
             }
             Ok(unsafe {
                 (
@@ -318,7 +498,11 @@ mod integers {
     }
 
     impl<'a> MinecraftPacketPart<'a> for f32 {
-        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        fn serialized_size(&self) -> usize {
+            std::mem::size_of::<f32>()
+        }
+
+        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
             let bytes = self.to_le_bytes();
             output.push(bytes[3]);
             output.push(bytes[2]);
@@ -327,9 +511,9 @@ mod integers {
             Ok(())
         }
 
-        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), &'static str> {
+        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), ParseError> {
             if input.len() < 4 {
-                return Err("Missing byte while parsing f32.");
+                return Err("Missing byte while parsing f32.".into());
             }
             unsafe {
                 let number = input.get_unchecked(..4);
@@ -347,7 +531,11 @@ mod integers {
     }
 
     impl<'a> MinecraftPacketPart<'a> for f64 {
-        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        fn serialized_size(&self) -> usize {
+            std::mem::size_of::<f64>()
+        }
+
+        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
             let bytes = self.to_le_bytes();
             output.push(bytes[7]);
             output.push(bytes[6]);
@@ -360,9 +548,9 @@ mod integers {
             Ok(())
         }
 
-        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), &'static str> {
+        fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(Self, &[u8]), ParseError> {
             if input.len() < 8 {
-                return Err("Missing byte while parsing f64.");
+                return Err("Missing byte while parsing f64.".into());
             }
             unsafe {
                 let number = input.get_unchecked(..8);
@@ -384,7 +572,12 @@ mod integers {
     }
 
     impl<'a> MinecraftPacketPart<'a> for VarInt {
-        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        fn serialized_size(&self) -> usize {
+            let bits = u32::BITS - (self.0 as u32).leading_zeros();
+            (bits.max(1) as usize).div_ceil(7)
+        }
+
+        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
             let mut value: u32 = unsafe { std::mem::transmute(self.0) };
 
             loop {
@@ -403,7 +596,7 @@ mod integers {
 
         fn deserialize_minecraft_packet_part(
             mut input: &[u8],
-        ) -> Result<(Self, &[u8]), &'static str> {
+        ) -> Result<(Self, &[u8]), ParseError> {
             let mut result: u32 = 0;
             let mut num_read: u32 = 0;
 
@@ -422,7 +615,7 @@ mod integers {
 
                 num_read += 1;
                 if num_read > 5 {
-                    return Err("VarInt is too big");
+                    return Err("VarInt is too big".into());
                 }
 
                 if read & 0b10000000 == 0 {
@@ -434,7 +627,12 @@ mod integers {
     }
 
     impl<'a> MinecraftPacketPart<'a> for VarLong {
-        fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        fn serialized_size(&self) -> usize {
+            let bits = u64::BITS - (self.0 as u64).leading_zeros();
+            (bits.max(1) as usize).div_ceil(7)
+        }
+
+        fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
             let mut value: u64 = unsafe { std::mem::transmute(self.0) };
 
             loop {
@@ -453,21 +651,22 @@ mod integers {
 
         fn deserialize_minecraft_packet_part(
             mut input: &[u8],
-        ) -> Result<(Self, &[u8]), &'static str> {
+        ) -> Result<(Self, &[u8]), ParseError> {
             let mut result: u64 = 0;
             let mut num_read: u64 = 0;
 
             loop {
-                let (read, new_input) = input.split_first().unwrap();
+                let (read, new_input) =
+                    input.split_first().ok_or("Not enough bytes for varlong!")?;
                 let read = *read;
                 input = new_input;
+                if num_read == 10 {
+                    return Err("VarLong is too big".into());
+                }
                 let value = (read & 0b01111111) as u64;
                 result |= value << (7 * num_read);
 
                 num_read += 1;
-                if num_read > 10 {
-                    return Err("VarLong is too big");
-                }
 
                 if read & 0b10000000 == 0 {
                     let result: i64 = unsafe { std::mem::transmute(result) };
@@ -503,6 +702,7 @@ mod integers {
                 VarInt(*input)
                     .serialize_minecraft_packet_part(&mut buffer)
                     .unwrap();
+                assert_eq!(VarInt(*input).serialized_size(), buffer.len());
                 outputs.push(buffer);
             }
 
@@ -621,6 +821,8 @@ mod integers {
             for idx in 0..inputs.len() {
                 assert_eq!(outputs[idx], expected_outputs[idx]);
             }
+
+            assert!(VarLong::deserialize_minecraft_packet_part(&[255; 11]).is_err());
         }
 
         #[test]
@@ -680,55 +882,100 @@ mod integers {
 }
 
 impl<'a> MinecraftPacketPart<'a> for &'a str {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialized_size(&self) -> usize {
+        VarInt(self.len() as i32).serialized_size() + self.len()
+    }
+
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
         let len = VarInt(self.len().try_into().map_err(|_| "String too long")?);
         len.serialize_minecraft_packet_part(output)?;
         output.extend_from_slice(self.as_bytes());
         Ok(())
     }
 
-    fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(&str, &[u8]), &'static str> {
+    fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(&str, &[u8]), ParseError> {
         let (len, input) = VarInt::deserialize_minecraft_packet_part(input)?;
         if len.0 <= 0 {
             return Ok(("", input));
         }
         let len: usize = len.0 as usize;
+        DecodeLimits::check_string_bytes(len)?;
         if len > input.len() {
-            return Err("String claims ownership of too much data");
+            return Err("String claims ownership of too much data".into());
         }
         let (slice, input) = input.split_at(len);
         let string = std::str::from_utf8(slice).map_err(|_| "Invalid utf8")?;
+        DecodeLimits::check_string(string)?;
 
         Ok((string, input))
     }
 }
 
 impl<'a> MinecraftPacketPart<'a> for String {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialized_size(&self) -> usize {
+        VarInt(self.len() as i32).serialized_size() + self.len()
+    }
+
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
         let len = VarInt(self.len().try_into().map_err(|_| "String too long")?);
         len.serialize_minecraft_packet_part(output)?;
         output.extend_from_slice(self.as_bytes());
         Ok(())
     }
 
-    fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(String, &[u8]), &'static str> {
+    fn deserialize_minecraft_packet_part(input: &[u8]) -> Result<(String, &[u8]), ParseError> {
         let (len, input) = VarInt::deserialize_minecraft_packet_part(input)?;
         if len.0 <= 0 {
             return Ok((String::new(), input));
         }
         let len: usize = len.0 as usize;
+        DecodeLimits::check_string_bytes(len)?;
         if len > input.len() {
-            return Err("String claims ownership of too much data");
+            return Err("String claims ownership of too much data".into());
         }
         let (slice, input) = input.split_at(len);
         let string = String::from_utf8(slice.to_vec()).map_err(|_| "Invalid utf8")?;
+        DecodeLimits::check_string(&string)?;
 
         Ok((string, input))
     }
 }
 
+impl<'a> MinecraftPacketPart<'a> for Chat<'a> {
+    fn serialized_size(&self) -> usize {
+        match (ProtocolVersion::current().nbt_text_components(), self) {
+            (false, Chat::Json(json)) => json.serialized_size(),
+            (false, chat) => chat.to_json().to_string().serialized_size(),
+            (true, Chat::Nbt(tag)) => tag.serialized_size(),
+            (true, chat) => chat.to_nbt().serialized_size(),
+        }
+    }
+
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        match (ProtocolVersion::current().nbt_text_components(), self) {
+            (false, Chat::Json(json)) => json.serialize_minecraft_packet_part(output),
+            (false, chat) => chat.to_json().to_string().serialize_minecraft_packet_part(output),
+            (true, Chat::Nbt(tag)) => tag.serialize_minecraft_packet_part(output),
+            (true, chat) => chat.to_nbt().serialize_minecraft_packet_part(output),
+        }
+    }
+
+    fn deserialize_minecraft_packet_part(input: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError> {
+        if ProtocolVersion::current().nbt_text_components() {
+            let (tag, input) = crate::nbt::NbtTag::deserialize_minecraft_packet_part(input)?;
+            return Ok((Chat::Nbt(tag), input));
+        }
+        let (json, input) = <&str>::deserialize_minecraft_packet_part(input)?;
+        Ok((Chat::Json(json), input))
+    }
+}
+
 impl<'a> MinecraftPacketPart<'a> for Position {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialized_size(&self) -> usize {
+        8
+    }
+
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
         let x = match self.x < 0 {
             true => (self.x + 2i32.pow(26)) as u64,
             false => self.x as u64,
@@ -748,9 +995,9 @@ impl<'a> MinecraftPacketPart<'a> for Position {
 
     fn deserialize_minecraft_packet_part(
         input: &'a [u8],
-    ) -> Result<(Self, &'a [u8]), &'static str> {
+    ) -> Result<(Self, &'a [u8]), ParseError> {
         if input.len() < 8 {
-            return Err("Missing bytes in position");
+            return Err("Missing bytes in position".into());
         }
         let (bytes, input) = input.split_at(8);
         let total = unsafe {
@@ -784,14 +1031,18 @@ impl<'a> MinecraftPacketPart<'a> for Position {
 }
 
 impl<'a> MinecraftPacketPart<'a> for RawBytes<'a> {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialized_size(&self) -> usize {
+        self.data.len()
+    }
+
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
         output.extend_from_slice(self.data);
         Ok(())
     }
 
     fn deserialize_minecraft_packet_part(
         input: &'a [u8],
-    ) -> Result<(Self, &'a [u8]), &'static str> {
+    ) -> Result<(Self, &'a [u8]), ParseError> {
         let data = input;
         Ok((RawBytes { data }, &mut []))
     }
@@ -803,23 +1054,29 @@ impl<
         U: MinecraftPacketPart<'a> + TryFrom<usize> + TryInto<usize>,
     > MinecraftPacketPart<'a> for Array<'a, T, U>
 {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
         let len: U = U::try_from(self.items.len())
             .map_err(|_| "The array lenght cannot be serialized due to its type.")?;
         len.serialize_minecraft_packet_part(output)?;
-        for item in self.items {
+        for item in &self.items {
             item.serialize_minecraft_packet_part(output)?;
         }
         Ok(())
     }
 
+    fn serialized_size(&self) -> usize {
+        let len_size = U::try_from(self.items.len()).map(|len| len.serialized_size()).unwrap_or(0);
+        len_size + self.items.iter().map(|item| item.serialized_size()).sum::<usize>()
+    }
+
     fn deserialize_minecraft_packet_part(
         input: &'a [u8],
-    ) -> Result<(Self, &'a [u8]), &'static str> {
+    ) -> Result<(Self, &'a [u8]), ParseError> {
         let (len, mut input) = U::deserialize_minecraft_packet_part(input)?;
         let len: usize = len
             .try_into()
             .map_err(|_| "The array lenght cannot be deserialized due to its type.")?;
+        DecodeLimits::check_array_length(len)?;
         let mut items = Vec::new();
         for _ in 0..len {
             let (item, new_input) = T::deserialize_minecraft_packet_part(input)?;
@@ -841,14 +1098,18 @@ impl<
     'a,
     const N: usize,
     > MinecraftPacketPart<'a> for [u8; N] {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
-        output.extend_from_slice(&self);
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        output.extend_from_slice(self);
         Ok(())
     }
 
-    fn deserialize_minecraft_packet_part(input: &'a [u8]) -> Result<(Self, &'a [u8]), &'static str> {
+    fn serialized_size(&self) -> usize {
+        N
+    }
+
+    fn deserialize_minecraft_packet_part(input: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError> {
         if input.len() < N {
-            return Err("Not enough data to deserialize");
+            return Err("Not enough data to deserialize".into());
         }
 
         let (data, rest) = input.split_at(N);
@@ -860,21 +1121,25 @@ impl<
 #[cfg_attr(test, derive(PartialEq))]
 pub struct FixedSizeArray<'a, V: MinecraftPacketPart<'a>, const N: usize> {
     pub items: Vec<V>,
-    _phantom: std::marker::PhantomData<&'a ()>,
+    pub(super) _phantom: std::marker::PhantomData<&'a ()>,
 }
 
 impl<'a, V: MinecraftPacketPart<'a>, const N: usize> MinecraftPacketPart<'a> for FixedSizeArray<'a, V, N> {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
         if self.items.len() != N {
             return Err("The vector length is not the expected one");
         }
-        for item in self.items {
+        for item in &self.items {
             item.serialize_minecraft_packet_part(output)?;
         }
         Ok(())
     }
 
-    fn deserialize_minecraft_packet_part(mut input: &'a [u8]) -> Result<(Self, &'a [u8]), &'static str> {
+    fn serialized_size(&self) -> usize {
+        self.items.iter().map(|item| item.serialized_size()).sum()
+    }
+
+    fn deserialize_minecraft_packet_part(mut input: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError> {
         let mut items = Vec::new();
         for _ in 0..N {
             let (item, new_input) = V::deserialize_minecraft_packet_part(input)?;
@@ -893,25 +1158,32 @@ impl<
         U: MinecraftPacketPart<'a> + TryFrom<usize> + TryInto<usize>,
     > MinecraftPacketPart<'a> for Map<'a, K, V, U>
 {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
         let len = U::try_from(self.items.len())
             .map_err(|_| "The map lenght cannot be serialized due to its type.")?;
         len.serialize_minecraft_packet_part(output)?;
-        for (key, value) in self.items.into_iter() {
+        for (key, value) in self.items.iter() {
             key.serialize_minecraft_packet_part(output)?;
             value.serialize_minecraft_packet_part(output)?;
         }
         Ok(())
     }
 
+    fn serialized_size(&self) -> usize {
+        let len_size = U::try_from(self.items.len()).map(|len| len.serialized_size()).unwrap_or(0);
+        let items_size: usize = self.items.iter().map(|(key, value)| key.serialized_size() + value.serialized_size()).sum();
+        len_size + items_size
+    }
+
     fn deserialize_minecraft_packet_part(
         input: &'a [u8],
-    ) -> Result<(Self, &'a [u8]), &'static str> {
+    ) -> Result<(Self, &'a [u8]), ParseError> {
         let mut items = std::collections::BTreeMap::new();
         let (len, mut input) = U::deserialize_minecraft_packet_part(input)?;
         let len: usize = len
             .try_into()
             .map_err(|_| "The map lenght cannot be deserialized due to its type.")?;
+        DecodeLimits::check_array_length(len)?;
 
         for _ in 0..len {
             let (key, new_input) = K::deserialize_minecraft_packet_part(input)?;
@@ -931,7 +1203,7 @@ impl<
 }
 
 impl<'a, T: MinecraftPacketPart<'a>> MinecraftPacketPart<'a> for Option<T> {
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
         if let Some(value) = self {
             true.serialize_minecraft_packet_part(output)?;
             value.serialize_minecraft_packet_part(output)?;
@@ -941,9 +1213,13 @@ impl<'a, T: MinecraftPacketPart<'a>> MinecraftPacketPart<'a> for Option<T> {
         Ok(())
     }
 
+    fn serialized_size(&self) -> usize {
+        1 + self.as_ref().map(|value| value.serialized_size()).unwrap_or(0)
+    }
+
     fn deserialize_minecraft_packet_part(
         input: &'a [u8],
-    ) -> Result<(Self, &'a [u8]), &'static str> {
+    ) -> Result<(Self, &'a [u8]), ParseError> {
         let (is_some, input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)?;
         if is_some {
             let (value, input) = MinecraftPacketPart::deserialize_minecraft_packet_part(input)?;
@@ -965,3 +1241,20 @@ fn print_varint() {
     varint.serialize_minecraft_packet_part(&mut data).unwrap();
     println!("{:?}", data);
 }
+
+#[test]
+fn parse_error_location() {
+    use crate::packets::login::ServerboundPacket;
+
+    // LoginStart with a truncated UUID
+    let data = [0, 3, b'a', b'b', b'c', 1, 2, 3, 4];
+    let error = ServerboundPacket::deserialize_uncompressed_minecraft_packet(&data).unwrap_err();
+    assert_eq!(error.packet(), Some("ServerboundPacket::LoginStart"));
+    assert_eq!(error.path, vec!["ServerboundPacket::LoginStart", "player_uuid"]);
+    assert_eq!(error.remaining, Some(4));
+    assert_eq!(error.offset, Some(5));
+
+    let error = ServerboundPacket::deserialize_uncompressed_minecraft_packet(&[0x42]).unwrap_err();
+    assert_eq!(error.path, vec!["ServerboundPacket"]);
+    assert_eq!(error.offset, Some(0));
+}
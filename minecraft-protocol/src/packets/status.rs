@@ -1,10 +1,12 @@
 //! For general information about pinging servers, see [the wiki](https://wiki.vg/Server_List_Ping).
 
 use crate::*;
+use serde::{Deserialize, Serialize};
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ClientboundPacket<'a> {
     /// *Response to [ServerboundPacket::Request]*
     Response {
@@ -21,6 +23,7 @@ pub enum ClientboundPacket<'a> {
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, MinecraftPacketPart)]
 #[discriminant(VarInt)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ServerboundPacket {
     /// *Request for [ClientboundPacket::Response]*
     Request,
@@ -31,3 +34,196 @@ pub enum ServerboundPacket {
         payload: i64,
     },
 }
+
+/// The content of [ClientboundPacket::Response], as displayed in the server list.
+///
+/// ```
+/// use minecraft_protocol::packets::status::StatusResponse;
+///
+/// let json = StatusResponse::builder("1.20.4", 765)
+///     .players(20, 1)
+///     .sample("Notch", "069a79f4-44e9-4726-a5be-fca90e38aaf5")
+///     .description("A Minecraft Server")
+///     .build()
+///     .to_json();
+/// let status = StatusResponse::parse(&json).unwrap();
+/// assert_eq!(status.version.protocol, 765);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusResponse {
+    pub version: StatusVersion,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub players: Option<StatusPlayers>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<Description>,
+    /// A PNG image of 64x64 pixels, as a data URL (`data:image/png;base64,...`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favicon: Option<String>,
+    /// Whether the server requires chat messages to be signed.
+    #[serde(default)]
+    pub enforces_secure_chat: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusVersion {
+    /// The name of the version, displayed when the protocol does not match.
+    pub name: String,
+    /// The protocol version, compared with the one of the client.
+    pub protocol: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusPlayers {
+    pub max: i32,
+    pub online: i32,
+    /// Some of the players that are online, displayed when hovering the player count.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sample: Vec<StatusPlayerSample>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusPlayerSample {
+    pub name: String,
+    /// The UUID of the player, with hyphens.
+    pub id: String,
+}
+
+/// The message of the day, either plain text or a [chat component](https://wiki.vg/Chat).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Description {
+    Text(String),
+    Component(serde_json::Value),
+}
+
+impl Description {
+    /// Concatenates the text of the component and its children, without formatting.
+    pub fn to_plain_text(&self) -> String {
+        fn push_text(value: &serde_json::Value, output: &mut String) {
+            match value {
+                serde_json::Value::String(text) => output.push_str(text),
+                serde_json::Value::Array(values) => values.iter().for_each(|value| push_text(value, output)),
+                serde_json::Value::Object(object) => {
+                    if let Some(serde_json::Value::String(text)) = object.get("text") {
+                        output.push_str(text);
+                    }
+                    if let Some(extra) = object.get("extra") {
+                        push_text(extra, output);
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        match self {
+            Description::Text(text) => text.clone(),
+            Description::Component(component) => {
+                let mut output = String::new();
+                push_text(component, &mut output);
+                output
+            }
+        }
+    }
+}
+
+impl StatusResponse {
+    pub fn builder(version_name: impl Into<String>, protocol: i32) -> StatusResponseBuilder {
+        StatusResponseBuilder {
+            status: StatusResponse {
+                version: StatusVersion {
+                    name: version_name.into(),
+                    protocol,
+                },
+                players: None,
+                description: None,
+                favicon: None,
+                enforces_secure_chat: false,
+            },
+        }
+    }
+
+    /// Parses the `json_response` of [ClientboundPacket::Response].
+    pub fn parse(json_response: &str) -> Result<StatusResponse, serde_json::Error> {
+        serde_json::from_str(json_response)
+    }
+
+    /// Serializes the status, to be sent in [ClientboundPacket::Response].
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("A status response is always serializable")
+    }
+}
+
+/// Builds a [StatusResponse], see [StatusResponse::builder].
+#[derive(Debug, Clone)]
+pub struct StatusResponseBuilder {
+    status: StatusResponse,
+}
+
+impl StatusResponseBuilder {
+    pub fn players(mut self, max: i32, online: i32) -> Self {
+        let sample = self.status.players.take().map(|players| players.sample).unwrap_or_default();
+        self.status.players = Some(StatusPlayers { max, online, sample });
+        self
+    }
+
+    /// Adds a player to the sample.
+    /// The player count is set to zero if [StatusResponseBuilder::players] was not called.
+    pub fn sample(mut self, name: impl Into<String>, id: impl Into<String>) -> Self {
+        let players = self.status.players.get_or_insert(StatusPlayers {
+            max: 0,
+            online: 0,
+            sample: Vec::new(),
+        });
+        players.sample.push(StatusPlayerSample {
+            name: name.into(),
+            id: id.into(),
+        });
+        self
+    }
+
+    pub fn description(mut self, text: impl Into<String>) -> Self {
+        self.status.description = Some(Description::Text(text.into()));
+        self
+    }
+
+    pub fn description_component(mut self, component: serde_json::Value) -> Self {
+        self.status.description = Some(Description::Component(component));
+        self
+    }
+
+    /// Sets the favicon from a data URL (`data:image/png;base64,...`).
+    pub fn favicon(mut self, data_url: impl Into<String>) -> Self {
+        self.status.favicon = Some(data_url.into());
+        self
+    }
+
+    pub fn enforces_secure_chat(mut self, enforces_secure_chat: bool) -> Self {
+        self.status.enforces_secure_chat = enforces_secure_chat;
+        self
+    }
+
+    pub fn build(self) -> StatusResponse {
+        self.status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_vanilla_status() {
+        let json = r#"{"version":{"name":"1.20.4","protocol":765},"enforcesSecureChat":true,"description":{"text":"","extra":[{"text":"A "},{"color":"gold","text":"Minecraft"},{"text":" Server"}]},"players":{"max":20,"online":1,"sample":[{"name":"Notch","id":"069a79f4-44e9-4726-a5be-fca90e38aaf5"}]}}"#;
+        let status = StatusResponse::parse(json).unwrap();
+        assert_eq!(status.version.name, "1.20.4");
+        assert!(status.enforces_secure_chat);
+        assert_eq!(status.players.as_ref().unwrap().sample[0].name, "Notch");
+        assert_eq!(status.description.as_ref().unwrap().to_plain_text(), "A Minecraft Server");
+        assert_eq!(StatusResponse::parse(&status.to_json()).unwrap(), status);
+
+        let status = StatusResponse::parse(r#"{"version":{"name":"1.8","protocol":47},"description":"Old server"}"#).unwrap();
+        assert_eq!(status.description, Some(Description::Text("Old server".to_string())));
+        assert_eq!(status.players, None);
+    }
+}
@@ -0,0 +1,318 @@
+//! The protocol versions supported by the packet enums.
+//!
+//! The packet enums number their variants with the ids of [ProtocolVersion::V1_20_2], followed by the packets that were added in later versions.
+//! Each version has a table translating these canonical ids from and to the ids it uses on the network.
+//! Fields that differ between versions are encoded according to the version of the current [scope](ProtocolVersion::scope), see [SinceVersion] and [Chat](super::Chat).
+//! Outside of a scope, packets are encoded for [ProtocolVersion::V1_20_2].
+//!
+//! Only 1.20.2 and 1.20.3/1.20.4 are supported, since the neighbouring versions cannot be described by a table of packet ids:
+//! 1.20.1 and older have no configuration state, and 1.20.5 replaced the NBT of item slots with data components.
+//!
+//! The block interaction, particles and sound added to [Explosion](super::play_clientbound::ClientboundPacket::Explosion) in 1.20.3 are not supported either.
+//! They are left undecoded as [RawBytes](super::RawBytes), so they can be forwarded but not read or built.
+//!
+//! [Connection](crate::network::connection::Connection) and the codecs select the version from the [Hello](super::handshake::ServerboundPacket::Hello) packet, so this is only needed when encoding packets by hand:
+//!
+//! ```
+//! use minecraft_protocol::{
+//!     packets::{play_clientbound::ClientboundPacket, version::{PacketDirection, ProtocolVersion}, ConnectionState},
+//!     MinecraftPacketPart,
+//! };
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let packet = ClientboundPacket::KeepAlive { keep_alive_id: 1 };
+//! let mut data = ProtocolVersion::V1_20_3.scope(|| packet.serialize_minecraft_packet())?;
+//! ProtocolVersion::V1_20_3.frame_to_wire(ConnectionState::Play, PacketDirection::Clientbound, &mut data)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{serializer::ParseError, ConnectionState, MinecraftPacketPart, VarInt};
+use std::cell::Cell;
+
+thread_local! {
+    static VERSION: Cell<ProtocolVersion> = const { Cell::new(ProtocolVersion::V1_20_2) };
+}
+
+/// A version of the protocol, numbered as in the [Hello](super::handshake::ServerboundPacket::Hello) packet.
+/// See [protocol version numbers](https://wiki.vg/Protocol_version_numbers).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ProtocolVersion {
+    /// Minecraft 1.20.2, whose packet ids are used by the packet enums.
+    V1_20_2 = 764,
+    /// Minecraft 1.20.3 and 1.20.4.
+    V1_20_3 = 765,
+}
+
+/// The side a packet is sent to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum PacketDirection {
+    Serverbound,
+    Clientbound,
+}
+
+/// Runs of consecutive packet ids, as (first network id, first canonical id, number of packets).
+type IdRuns = &'static [(i32, i32, i32)];
+
+impl ProtocolVersion {
+    /// All the supported versions, from the oldest to the latest.
+    pub const ALL: [ProtocolVersion; 2] = [ProtocolVersion::V1_20_2, ProtocolVersion::V1_20_3];
+
+    pub const LATEST: ProtocolVersion = ProtocolVersion::V1_20_3;
+
+    /// Returns the version with this protocol number, if it is supported.
+    pub fn from_protocol(protocol: i32) -> Option<ProtocolVersion> {
+        ProtocolVersion::ALL.iter().copied().find(|version| version.protocol() == protocol)
+    }
+
+    /// The number sent in the [Hello](super::handshake::ServerboundPacket::Hello) packet.
+    pub fn protocol(self) -> i32 {
+        self as i32
+    }
+
+    /// The name of the latest release using this version.
+    pub fn name(self) -> &'static str {
+        match self {
+            ProtocolVersion::V1_20_2 => "1.20.2",
+            ProtocolVersion::V1_20_3 => "1.20.4",
+        }
+    }
+
+    /// Returns true if [text components](super::Chat) are sent as NBT instead of JSON.
+    /// The [login](super::login) packets still use JSON.
+    pub fn nbt_text_components(self) -> bool {
+        self >= ProtocolVersion::V1_20_3
+    }
+
+    /// The version that packets are encoded for on the current thread.
+    pub fn current() -> ProtocolVersion {
+        VERSION.with(|version| version.get())
+    }
+
+    /// Runs `f` with packets encoded and decoded for this version.
+    pub fn scope<T>(self, f: impl FnOnce() -> T) -> T {
+        struct Guard(ProtocolVersion);
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                VERSION.with(|version| version.set(self.0));
+            }
+        }
+
+        let _guard = Guard(VERSION.with(|version| version.replace(self)));
+        f()
+    }
+
+    fn id_runs(self, state: ConnectionState, direction: PacketDirection) -> IdRuns {
+        use ConnectionState::*;
+        use PacketDirection::*;
+
+        match (self, state, direction) {
+            (_, HandShake, Serverbound) => &[(0, 0, 1)],
+            (_, HandShake, Clientbound) => &[],
+            (_, Status, _) => &[(0, 0, 2)],
+            (_, Login, Serverbound) => &[(0, 0, 4)],
+            (_, Login, Clientbound) => &[(0, 0, 5)],
+            (ProtocolVersion::V1_20_2, Configuration, Serverbound) => &[(0, 0, 6)],
+            (ProtocolVersion::V1_20_2, Configuration, Clientbound) => &[(0, 0, 9)],
+            (ProtocolVersion::V1_20_2, Play, Serverbound) => &[(0, 0, 0x36)],
+            (ProtocolVersion::V1_20_2, Play, Clientbound) => &[(0, 0, 0x71)],
+            (ProtocolVersion::V1_20_3, Configuration, Serverbound) => &[(0, 0, 6)],
+            // RemoveResourcePack was inserted before the resource pack packet
+            (ProtocolVersion::V1_20_3, Configuration, Clientbound) => &[(0, 0, 6), (6, 9, 1), (7, 6, 3)],
+            // ChangeContainerSlotState was inserted before PluginMessage
+            (ProtocolVersion::V1_20_3, Play, Serverbound) => &[(0, 0, 0x0F), (0x0F, 0x36, 1), (0x10, 0x0F, 0x27)],
+            // ResetScore and RemoveResourcePack were inserted before the resource pack packet, and the ticking packets before UpdateAdvancements
+            (ProtocolVersion::V1_20_3, Play, Clientbound) => {
+                &[(0, 0, 0x42), (0x42, 0x71, 2), (0x44, 0x42, 0x2A), (0x6E, 0x73, 2), (0x70, 0x6C, 5)]
+            }
+        }
+    }
+
+    /// The id of a packet in this version, from its id in the packet enums.
+    /// Returns `None` if the packet does not exist in this version.
+    pub fn packet_id(self, state: ConnectionState, direction: PacketDirection, canonical_id: i32) -> Option<i32> {
+        self.id_runs(state, direction)
+            .iter()
+            .find(|(_, canonical, len)| (*canonical..canonical + len).contains(&canonical_id))
+            .map(|(network, canonical, _)| network + canonical_id - canonical)
+    }
+
+    /// The id of a packet in the packet enums, from its id in this version.
+    /// Returns `None` if the id is unknown in this version.
+    pub fn canonical_packet_id(self, state: ConnectionState, direction: PacketDirection, id: i32) -> Option<i32> {
+        self.id_runs(state, direction)
+            .iter()
+            .find(|(network, _, len)| (*network..network + len).contains(&id))
+            .map(|(network, canonical, _)| canonical + id - network)
+    }
+
+    /// Replaces the packet id at the start of a frame received from a peer using this version, so that the frame can be parsed by the packet enums.
+    pub fn frame_to_canonical(self, state: ConnectionState, direction: PacketDirection, frame: &mut Vec<u8>) -> Result<(), ParseError> {
+        let (id, rest) = VarInt::deserialize_minecraft_packet_part(frame)?;
+        let canonical_id = self
+            .canonical_packet_id(state, direction, id.0)
+            .ok_or("Unknown packet id for this protocol version")?;
+        replace_packet_id(frame, frame.len() - rest.len(), canonical_id)
+    }
+
+    /// Replaces the packet id at the start of a frame serialized by the packet enums, so that it can be sent to a peer using this version.
+    pub fn frame_to_wire(self, state: ConnectionState, direction: PacketDirection, frame: &mut Vec<u8>) -> Result<(), ParseError> {
+        let (id, rest) = VarInt::deserialize_minecraft_packet_part(frame)?;
+        let network_id = self
+            .packet_id(state, direction, id.0)
+            .ok_or("This packet does not exist in this protocol version")?;
+        replace_packet_id(frame, frame.len() - rest.len(), network_id)
+    }
+}
+
+fn replace_packet_id(frame: &mut Vec<u8>, id_len: usize, id: i32) -> Result<(), ParseError> {
+    let mut encoded_id = Vec::new();
+    VarInt(id).serialize_minecraft_packet_part(&mut encoded_id)?;
+    frame.splice(..id_len, encoded_id);
+    Ok(())
+}
+
+/// A field that was added in a later protocol version.
+///
+/// It is `None` when parsed for an older version, and it is omitted when sent to an older version.
+/// Sending `None` to a version that has the field is an error.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+pub struct SinceVersion<T, const PROTOCOL: i32>(pub Option<T>);
+
+impl<T, const PROTOCOL: i32> SinceVersion<T, PROTOCOL> {
+    /// Returns true if the field exists in the version of the current scope.
+    pub fn is_present() -> bool {
+        ProtocolVersion::current().protocol() >= PROTOCOL
+    }
+}
+
+impl<T, const PROTOCOL: i32> Default for SinceVersion<T, PROTOCOL> {
+    fn default() -> Self {
+        SinceVersion(None)
+    }
+}
+
+impl<T, const PROTOCOL: i32> From<T> for SinceVersion<T, PROTOCOL> {
+    fn from(value: T) -> Self {
+        SinceVersion(Some(value))
+    }
+}
+
+impl<'a, T: MinecraftPacketPart<'a>, const PROTOCOL: i32> MinecraftPacketPart<'a> for SinceVersion<T, PROTOCOL> {
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        match (Self::is_present(), &self.0) {
+            (true, Some(value)) => value.serialize_minecraft_packet_part(output),
+            (true, None) => Err("A field required by this protocol version is missing."),
+            (false, _) => Ok(()),
+        }
+    }
+
+    fn serialized_size(&self) -> usize {
+        match (Self::is_present(), &self.0) {
+            (true, Some(value)) => value.serialized_size(),
+            _ => 0,
+        }
+    }
+
+    fn deserialize_minecraft_packet_part(input: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError> {
+        if !Self::is_present() {
+            return Ok((SinceVersion(None), input));
+        }
+        let (value, input) = T::deserialize_minecraft_packet_part(input)?;
+        Ok((SinceVersion(Some(value)), input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packets::{play_clientbound::ClientboundPacket, play_serverbound::ServerboundPacket, Chat, RawBytes, VarInt};
+
+    #[test]
+    fn id_tables() {
+        for version in ProtocolVersion::ALL {
+            for state in [ConnectionState::Configuration, ConnectionState::Play] {
+                for direction in [PacketDirection::Serverbound, PacketDirection::Clientbound] {
+                    for id in 0..0x80 {
+                        if let Some(canonical_id) = version.canonical_packet_id(state, direction, id) {
+                            assert_eq!(version.packet_id(state, direction, canonical_id), Some(id));
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut frame = ClientboundPacket::SetTickingState { tick_rate: 20.0, is_frozen: false }.serialize_minecraft_packet().unwrap();
+        assert!(ProtocolVersion::V1_20_2.frame_to_wire(ConnectionState::Play, PacketDirection::Clientbound, &mut frame.clone()).is_err());
+        ProtocolVersion::V1_20_3.frame_to_wire(ConnectionState::Play, PacketDirection::Clientbound, &mut frame).unwrap();
+        assert_eq!(frame[0], 0x6E);
+        ProtocolVersion::V1_20_3.frame_to_canonical(ConnectionState::Play, PacketDirection::Clientbound, &mut frame).unwrap();
+        assert!(matches!(ClientboundPacket::deserialize_uncompressed_minecraft_packet(&frame), Ok(ClientboundPacket::SetTickingState { .. })));
+
+        let mut frame = ServerboundPacket::PluginMessage { identifier: "minecraft:brand", data: RawBytes { data: b"\x07vanilla" } }.serialize_minecraft_packet().unwrap();
+        assert_eq!(frame[0], 0x0F);
+        ProtocolVersion::V1_20_3.frame_to_wire(ConnectionState::Play, PacketDirection::Serverbound, &mut frame).unwrap();
+        assert_eq!(frame[0], 0x10);
+    }
+
+    #[test]
+    fn field_differences() {
+        let packet = || ClientboundPacket::SendResourcePack {
+            uuid: SinceVersion(Some(42)),
+            url: "https://example.com/pack.zip",
+            hash: "",
+            forced: true,
+            prompt_message: Some(Chat::Json(r#"{"text":"Please accept","bold":true}"#)),
+        };
+
+        let old = packet().serialize_minecraft_packet().unwrap();
+        let new = ProtocolVersion::V1_20_3.scope(|| packet().serialize_minecraft_packet()).unwrap();
+        assert_eq!(ProtocolVersion::current(), ProtocolVersion::V1_20_2);
+
+        match ClientboundPacket::deserialize_uncompressed_minecraft_packet(&old).unwrap() {
+            ClientboundPacket::SendResourcePack { uuid, prompt_message, .. } => {
+                assert_eq!(uuid, SinceVersion(None));
+                assert!(matches!(prompt_message, Some(Chat::Json(_))));
+            }
+            _ => unreachable!(),
+        }
+        let parsed = ProtocolVersion::V1_20_3.scope(|| ClientboundPacket::deserialize_uncompressed_minecraft_packet(&new)).unwrap();
+        match parsed {
+            ClientboundPacket::SendResourcePack { uuid, prompt_message: Some(Chat::Nbt(prompt)), .. } => {
+                assert_eq!(uuid, SinceVersion(Some(42)));
+                assert_eq!(Chat::Nbt(prompt).to_json(), serde_json::json!({"text": "Please accept", "bold": true}));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn entity_metadata_chat() {
+        use crate::components::entity::{EntityMetadata, EntityMetadataValue};
+
+        let packet = || {
+            let mut metadata = EntityMetadata { items: Default::default() };
+            metadata.items.insert(2, EntityMetadataValue::OptionChat { chat: Some(Chat::Nbt(crate::nbt::NbtTag::String("Steve".to_string()))) });
+            metadata.items.insert(3, EntityMetadataValue::Bool { value: true });
+            metadata.items.insert(23, EntityMetadataValue::Chat { chat: Chat::Nbt(crate::nbt::NbtTag::String("Hello".to_string())) });
+            ClientboundPacket::SetEntityMetadata { entity_id: VarInt(7), metadata }
+        };
+
+        let data = ProtocolVersion::V1_20_3.scope(|| packet().serialize_minecraft_packet()).unwrap();
+        let parsed = ProtocolVersion::V1_20_3.scope(|| ClientboundPacket::deserialize_uncompressed_minecraft_packet(&data)).unwrap();
+        assert_eq!(parsed, packet());
+
+        // The names are converted to JSON for older versions
+        let data = packet().serialize_minecraft_packet().unwrap();
+        match ClientboundPacket::deserialize_uncompressed_minecraft_packet(&data).unwrap() {
+            ClientboundPacket::SetEntityMetadata { metadata, .. } => match &metadata.items[&23] {
+                EntityMetadataValue::Chat { chat: Chat::Json(json) } => assert_eq!(*json, "\"Hello\""),
+                value => panic!("unexpected value {:?}", value),
+            },
+            _ => unreachable!(),
+        }
+    }
+}
@@ -1,11 +1,7 @@
 
-//! This test was automatically generated. Please run the proxy example to regenerate it.
-//! 
-//! ```
-//! cargo run --example proxy
-//! ```
+//! This test was generated from a packet captured by the proxy example.
 
-use minecraft_protocol::{MinecraftPacketPart, packets::play_clientbound::ClientboundPacket};
+use minecraft_protocol::{MinecraftPacketPart, network::{read_packet, send_packet}, packets::play_clientbound::ClientboundPacket};
 
 #[test]
 fn auto_play_clientbound_b() {
@@ -14,8 +10,13 @@ fn auto_play_clientbound_b() {
 
     match packet_deserialized.serialize_minecraft_packet() {
         Ok(packet) => {
-            //reserialize let _reserialized = ClientboundPacket::deserialize_uncompressed_minecraft_packet(&packet).unwrap();
-            //reserialize assert!(matches!(ClientboundPacket::deserialize_uncompressed_minecraft_packet(input).unwrap(), _reserialized));
+            assert_eq!(packet, input);
+            let reparsed = ClientboundPacket::deserialize_uncompressed_minecraft_packet(&packet).unwrap();
+            assert_eq!(reparsed.serialize_minecraft_packet().unwrap(), packet);
+
+            let mut frame = Vec::new();
+            send_packet(&mut frame, packet.clone(), Some(0)).unwrap();
+            assert_eq!(read_packet(frame.as_slice(), Some(0)).unwrap(), packet);
         }
         Err(e) => panic!("Failed to serialize packet: {:?}", e),
     };
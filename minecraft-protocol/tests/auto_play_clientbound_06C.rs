@@ -1,21 +1,23 @@
 
-//! This test was automatically generated. Please run the proxy example to regenerate it.
-//! 
-//! ```
-//! cargo run --example proxy
-//! ```
+//! This test was generated from a packet captured by the proxy example.
+//! The NBT compounds were reordered by name, the order in which they are serialized.
 
-use minecraft_protocol::{MinecraftPacketPart, packets::play_clientbound::ClientboundPacket};
+use minecraft_protocol::{MinecraftPacketPart, network::{read_packet, send_packet}, packets::play_clientbound::ClientboundPacket};
 
 #[test]
 fn auto_play_clientbound_6c() {
-    let input = &[108, 1, 99, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 121, 101, 108, 108, 111, 119, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 58, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 119, 97, 108, 107, 95, 111, 110, 95, 112, 111, 119, 100, 101, 114, 95, 115, 110, 111, 119, 95, 119, 105, 116, 104, 95, 108, 101, 97, 116, 104, 101, 114, 95, 98, 111, 111, 116, 115, 1, 32, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 108, 101, 101, 112, 95, 105, 110, 95, 98, 101, 100, 1, 83, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 119, 97, 108, 107, 95, 111, 110, 95, 112, 111, 119, 100, 101, 114, 95, 115, 110, 111, 119, 95, 119, 105, 116, 104, 95, 108, 101, 97, 116, 104, 101, 114, 95, 98, 111, 111, 116, 115, 46, 116, 105, 116, 108, 101, 34, 125, 89, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 119, 97, 108, 107, 95, 111, 110, 95, 112, 111, 119, 100, 101, 114, 95, 115, 110, 111, 119, 95, 119, 105, 116, 104, 95, 108, 101, 97, 116, 104, 101, 114, 95, 98, 111, 111, 116, 115, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 179, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 65, 132, 0, 0, 1, 1, 38, 119, 97, 108, 107, 95, 111, 110, 95, 112, 111, 119, 100, 101, 114, 95, 115, 110, 111, 119, 95, 119, 105, 116, 104, 95, 108, 101, 97, 116, 104, 101, 114, 95, 98, 111, 111, 116, 115, 1, 60, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 102, 111, 111, 100, 47, 99, 111, 111, 107, 101, 100, 95, 112, 111, 114, 107, 99, 104, 111, 112, 95, 102, 114, 111, 109, 95, 99, 97, 109, 112, 102, 105, 114, 101, 95, 99, 111, 111, 107, 105, 110, 103, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 12, 104, 97, 115, 95, 112, 111, 114, 107, 99, 104, 111, 112, 0, 34, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 97, 120, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 0, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 117, 114, 112, 108, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 57, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 99, 114, 97, 102, 116, 95, 100, 101, 99, 111, 114, 97, 116, 101, 100, 95, 112, 111, 116, 95, 117, 115, 105, 110, 103, 95, 111, 110, 108, 121, 95, 115, 104, 101, 114, 100, 115, 1, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 97, 108, 118, 97, 103, 101, 95, 115, 104, 101, 114, 100, 1, 82, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 99, 114, 97, 102, 116, 95, 100, 101, 99, 111, 114, 97, 116, 101, 100, 95, 112, 111, 116, 95, 117, 115, 105, 110, 103, 95, 111, 110, 108, 121, 95, 115, 104, 101, 114, 100, 115, 46, 116, 105, 116, 108, 101, 34, 125, 88, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 99, 114, 97, 102, 116, 95, 100, 101, 99, 111, 114, 97, 116, 101, 100, 95, 112, 111, 116, 95, 117, 115, 105, 110, 103, 95, 111, 110, 108, 121, 95, 115, 104, 101, 114, 100, 115, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 138, 2, 1, 10, 10, 0, 14, 66, 108, 111, 99, 107, 69, 110, 116, 105, 116, 121, 84, 97, 103, 9, 0, 6, 115, 104, 101, 114, 100, 115, 8, 0, 0, 0, 4, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 114, 105, 99, 107, 0, 29, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 101, 97, 114, 116, 95, 112, 111, 116, 116, 101, 114, 121, 95, 115, 104, 101, 114, 100, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 114, 105, 99, 107, 0, 32, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 120, 112, 108, 111, 114, 101, 114, 95, 112, 111, 116, 116, 101, 114, 121, 95, 115, 104, 101, 114, 100, 8, 0, 2, 105, 100, 0, 23, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 99, 111, 114, 97, 116, 101, 100, 95, 112, 111, 116, 0, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 65, 148, 0, 0, 1, 1, 29, 112, 111, 116, 95, 99, 114, 97, 102, 116, 101, 100, 95, 117, 115, 105, 110, 103, 95, 111, 110, 108, 121, 95, 115, 104, 101, 114, 100, 115, 1, 57, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 109, 97, 103, 101, 110, 116, 97, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 43, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 112, 108, 97, 121, 95, 106, 117, 107, 101, 98, 111, 120, 95, 105, 110, 95, 109, 101, 97, 100, 111, 119, 115, 1, 32, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 108, 101, 101, 112, 95, 105, 110, 95, 98, 101, 100, 1, 68, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 112, 108, 97, 121, 95, 106, 117, 107, 101, 98, 111, 120, 95, 105, 110, 95, 109, 101, 97, 100, 111, 119, 115, 46, 116, 105, 116, 108, 101, 34, 125, 74, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 112, 108, 97, 121, 95, 106, 117, 107, 101, 98, 111, 120, 95, 105, 110, 95, 109, 101, 97, 100, 111, 119, 115, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 160, 2, 1, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 65, 140, 0, 0, 1, 1, 23, 112, 108, 97, 121, 95, 106, 117, 107, 101, 98, 111, 120, 95, 105, 110, 95, 109, 101, 97, 100, 111, 119, 115, 1, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 110, 105, 112, 101, 114, 95, 100, 117, 101, 108, 1, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 104, 111, 111, 116, 95, 97, 114, 114, 111, 119, 1, 56, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 110, 105, 112, 101, 114, 95, 100, 117, 101, 108, 46, 116, 105, 116, 108, 101, 34, 125, 62, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 110, 105, 112, 101, 114, 95, 100, 117, 101, 108, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 249, 5, 1, 0, 1, 0, 0, 0, 2, 64, 64, 0, 0, 65, 72, 0, 0, 1, 1, 15, 107, 105, 108, 108, 101, 100, 95, 115, 107, 101, 108, 101, 116, 111, 110, 1, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 109, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 56, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 121, 101, 108, 108, 111, 119, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 97, 108, 118, 97, 103, 101, 95, 115, 104, 101, 114, 100, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 58, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 97, 108, 118, 97, 103, 101, 95, 115, 104, 101, 114, 100, 46, 116, 105, 116, 108, 101, 34, 125, 64, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 97, 108, 118, 97, 103, 101, 95, 115, 104, 101, 114, 100, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 193, 9, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 65, 148, 0, 0, 2, 6, 11, 100, 101, 115, 101, 114, 116, 95, 119, 101, 108, 108, 18, 116, 114, 97, 105, 108, 95, 114, 117, 105, 110, 115, 95, 99, 111, 109, 109, 111, 110, 15, 111, 99, 101, 97, 110, 95, 114, 117, 105, 110, 95, 119, 97, 114, 109, 14, 100, 101, 115, 101, 114, 116, 95, 112, 121, 114, 97, 109, 105, 100, 15, 111, 99, 101, 97, 110, 95, 114, 117, 105, 110, 95, 99, 111, 108, 100, 16, 116, 114, 97, 105, 108, 95, 114, 117, 105, 110, 115, 95, 114, 97, 114, 101, 1, 9, 104, 97, 115, 95, 115, 104, 101, 114, 100, 1, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 99, 114, 97, 102, 116, 105, 110, 103, 95, 116, 97, 98, 108, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 17, 117, 110, 108, 111, 99, 107, 95, 114, 105, 103, 104, 116, 95, 97, 119, 97, 121, 0, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 99, 97, 109, 112, 102, 105, 114, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 8, 104, 97, 115, 95, 99, 111, 97, 108, 0, 36, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 108, 97, 100, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 0, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 112, 97, 114, 114, 111, 116, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 63, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 112, 97, 114, 114, 111, 116, 46, 116, 105, 116, 108, 101, 34, 125, 69, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 112, 97, 114, 114, 111, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 253, 6, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 64, 224, 0, 0, 1, 1, 18, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 112, 97, 114, 114, 111, 116, 1, 35, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 99, 104, 101, 115, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 17, 104, 97, 115, 95, 108, 111, 116, 115, 95, 111, 102, 95, 105, 116, 101, 109, 115, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 115, 104, 105, 110, 121, 95, 103, 101, 97, 114, 1, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 109, 105, 110, 101, 95, 100, 105, 97, 109, 111, 110, 100, 1, 51, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 115, 104, 105, 110, 121, 95, 103, 101, 97, 114, 46, 116, 105, 116, 108, 101, 34, 125, 57, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 115, 104, 105, 110, 121, 95, 103, 101, 97, 114, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 189, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 192, 0, 0, 63, 0, 0, 0, 1, 4, 14, 100, 105, 97, 109, 111, 110, 100, 95, 104, 101, 108, 109, 101, 116, 18, 100, 105, 97, 109, 111, 110, 100, 95, 99, 104, 101, 115, 116, 112, 108, 97, 116, 101, 16, 100, 105, 97, 109, 111, 110, 100, 95, 108, 101, 103, 103, 105, 110, 103, 115, 13, 100, 105, 97, 109, 111, 110, 100, 95, 98, 111, 111, 116, 115, 1, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 114, 111, 119, 110, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 46, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 109, 97, 110, 103, 114, 111, 118, 101, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 47, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 114, 101, 100, 95, 115, 97, 110, 100, 115, 116, 111, 110, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 0, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 115, 104, 111, 118, 101, 108, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 0, 48, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 114, 101, 100, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 115, 112, 114, 117, 99, 101, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 99, 111, 109, 98, 97, 116, 47, 119, 111, 111, 100, 101, 110, 95, 115, 119, 111, 114, 100, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 0, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 104, 114, 111, 119, 95, 116, 114, 105, 100, 101, 110, 116, 1, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 1, 58, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 104, 114, 111, 119, 95, 116, 114, 105, 100, 101, 110, 116, 46, 116, 105, 116, 108, 101, 34, 125, 64, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 104, 114, 111, 119, 95, 116, 114, 105, 100, 101, 110, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 243, 8, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 65, 88, 0, 0, 1, 1, 12, 115, 104, 111, 116, 95, 116, 114, 105, 100, 101, 110, 116, 1, 45, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 109, 105, 115, 99, 47, 102, 105, 114, 101, 119, 111, 114, 107, 95, 114, 111, 99, 107, 101, 116, 95, 115, 105, 109, 112, 108, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 13, 104, 97, 115, 95, 103, 117, 110, 112, 111, 119, 100, 101, 114, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 121, 101, 108, 108, 111, 119, 95, 103, 108, 97, 122, 101, 100, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 21, 104, 97, 115, 95, 121, 101, 108, 108, 111, 119, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 117, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 109, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 97, 121, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 101, 101, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 97, 121, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 103, 114, 97, 121, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 114, 111, 119, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 34, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 104, 111, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 0, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 98, 117, 108, 108, 115, 101, 121, 101, 1, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 104, 111, 111, 116, 95, 97, 114, 114, 111, 119, 1, 53, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 98, 117, 108, 108, 115, 101, 121, 101, 46, 116, 105, 116, 108, 101, 34, 125, 59, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 98, 117, 108, 108, 115, 101, 121, 101, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 137, 5, 1, 0, 1, 0, 0, 0, 2, 64, 64, 0, 0, 65, 56, 0, 0, 1, 1, 8, 98, 117, 108, 108, 115, 101, 121, 101, 1, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 97, 99, 107, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 105, 110, 107, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 97, 99, 107, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 105, 114, 111, 110, 95, 116, 111, 111, 108, 115, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 115, 109, 101, 108, 116, 95, 105, 114, 111, 110, 1, 51, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 105, 114, 111, 110, 95, 116, 111, 111, 108, 115, 46, 116, 105, 116, 108, 101, 34, 125, 57, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 105, 114, 111, 110, 95, 116, 111, 111, 108, 115, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 154, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 128, 0, 0, 63, 128, 0, 0, 1, 1, 12, 105, 114, 111, 110, 95, 112, 105, 99, 107, 97, 120, 101, 1, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 117, 109, 109, 111, 110, 95, 105, 114, 111, 110, 95, 103, 111, 108, 101, 109, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 97, 100, 101, 1, 62, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 117, 109, 109, 111, 110, 95, 105, 114, 111, 110, 95, 103, 111, 108, 101, 109, 46, 116, 105, 116, 108, 101, 34, 125, 68, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 117, 109, 109, 111, 110, 95, 105, 114, 111, 110, 95, 103, 111, 108, 101, 109, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 173, 2, 1, 0, 2, 0, 0, 0, 2, 64, 0, 0, 0, 65, 0, 0, 0, 1, 1, 14, 115, 117, 109, 109, 111, 110, 101, 100, 95, 103, 111, 108, 101, 109, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 114, 111, 111, 116, 0, 1, 45, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 114, 111, 111, 116, 46, 116, 105, 116, 108, 101, 34, 125, 51, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 114, 111, 111, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 14, 1, 0, 0, 0, 0, 0, 1, 57, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 101, 120, 116, 117, 114, 101, 115, 47, 103, 117, 105, 47, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 47, 98, 97, 99, 107, 103, 114, 111, 117, 110, 100, 115, 47, 115, 116, 111, 110, 101, 46, 112, 110, 103, 0, 0, 0, 0, 63, 160, 0, 0, 1, 1, 14, 99, 114, 97, 102, 116, 105, 110, 103, 95, 116, 97, 98, 108, 101, 1, 36, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 111, 116, 101, 109, 95, 111, 102, 95, 117, 110, 100, 121, 105, 110, 103, 1, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 1, 61, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 111, 116, 101, 109, 95, 111, 102, 95, 117, 110, 100, 121, 105, 110, 103, 46, 116, 105, 116, 108, 101, 34, 125, 67, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 111, 116, 101, 109, 95, 111, 102, 95, 117, 110, 100, 121, 105, 110, 103, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 221, 8, 1, 0, 2, 0, 0, 0, 2, 64, 0, 0, 0, 65, 104, 0, 0, 1, 1, 10, 117, 115, 101, 100, 95, 116, 111, 116, 101, 109, 1, 60, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 103, 114, 97, 121, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 60, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 98, 108, 117, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 52, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 109, 97, 103, 101, 110, 116, 97, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 35, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 97, 118, 111, 105, 100, 95, 118, 105, 98, 114, 97, 116, 105, 111, 110, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 60, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 97, 118, 111, 105, 100, 95, 118, 105, 98, 114, 97, 116, 105, 111, 110, 46, 116, 105, 116, 108, 101, 34, 125, 66, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 97, 118, 111, 105, 100, 95, 118, 105, 98, 114, 97, 116, 105, 111, 110, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 141, 5, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 65, 140, 0, 0, 1, 1, 15, 97, 118, 111, 105, 100, 95, 118, 105, 98, 114, 97, 116, 105, 111, 110, 1, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 102, 111, 111, 100, 47, 99, 111, 111, 107, 101, 100, 95, 112, 111, 114, 107, 99, 104, 111, 112, 95, 102, 114, 111, 109, 95, 115, 109, 111, 107, 105, 110, 103, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 12, 104, 97, 115, 95, 112, 111, 114, 107, 99, 104, 111, 112, 0, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 111, 114, 97, 110, 103, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 39, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 108, 97, 115, 115, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 19, 104, 97, 115, 95, 115, 109, 101, 108, 116, 115, 95, 116, 111, 95, 103, 108, 97, 115, 115, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 0, 1, 1, 10, 105, 109, 112, 111, 115, 115, 105, 98, 108, 101, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 105, 110, 107, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 53, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 114, 101, 100, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 101, 100, 115, 116, 111, 110, 101, 47, 116, 110, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 13, 104, 97, 115, 95, 103, 117, 110, 112, 111, 119, 100, 101, 114, 0, 56, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 117, 114, 112, 108, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 55, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 46, 116, 105, 116, 108, 101, 34, 125, 61, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 152, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 65, 68, 0, 0, 1, 34, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 108, 97, 122, 101, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 97, 118, 101, 95, 115, 112, 105, 100, 101, 114, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 114, 101, 101, 112, 101, 114, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 114, 111, 119, 110, 101, 100, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 108, 100, 101, 114, 95, 103, 117, 97, 114, 100, 105, 97, 110, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 95, 100, 114, 97, 103, 111, 110, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 97, 110, 19, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 105, 116, 101, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 118, 111, 107, 101, 114, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 104, 97, 115, 116, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 117, 97, 114, 100, 105, 97, 110, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 111, 103, 108, 105, 110, 14, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 117, 115, 107, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 97, 103, 109, 97, 95, 99, 117, 98, 101, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 104, 97, 110, 116, 111, 109, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 95, 98, 114, 117, 116, 101, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 108, 108, 97, 103, 101, 114, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 97, 118, 97, 103, 101, 114, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 104, 117, 108, 107, 101, 114, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 118, 101, 114, 102, 105, 115, 104, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 107, 101, 108, 101, 116, 111, 110, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 108, 105, 109, 101, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 100, 101, 114, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 114, 97, 121, 13, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 105, 110, 100, 105, 99, 97, 116, 111, 114, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 99, 104, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 95, 115, 107, 101, 108, 101, 116, 111, 110, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 103, 108, 105, 110, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 95, 118, 105, 108, 108, 97, 103, 101, 114, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 102, 105, 101, 100, 95, 112, 105, 103, 108, 105, 110, 1, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 117, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 104, 111, 110, 101, 121, 95, 98, 108, 111, 99, 107, 95, 115, 108, 105, 100, 101, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 62, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 104, 111, 110, 101, 121, 95, 98, 108, 111, 99, 107, 95, 115, 108, 105, 100, 101, 46, 116, 105, 116, 108, 101, 34, 125, 68, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 104, 111, 110, 101, 121, 95, 98, 108, 111, 99, 107, 95, 115, 108, 105, 100, 101, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 131, 5, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 64, 160, 0, 0, 1, 1, 17, 104, 111, 110, 101, 121, 95, 98, 108, 111, 99, 107, 95, 115, 108, 105, 100, 101, 1, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 111, 108, 95, 98, 101, 116, 115, 121, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 53, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 111, 108, 95, 98, 101, 116, 115, 121, 46, 116, 105, 116, 108, 101, 34, 125, 59, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 111, 108, 95, 98, 101, 116, 115, 121, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 247, 8, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 64, 128, 0, 0, 1, 1, 13, 115, 104, 111, 116, 95, 99, 114, 111, 115, 115, 98, 111, 119, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 109, 105, 110, 101, 95, 115, 116, 111, 110, 101, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 114, 111, 111, 116, 1, 51, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 109, 105, 110, 101, 95, 115, 116, 111, 110, 101, 46, 116, 105, 116, 108, 101, 34, 125, 57, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 109, 105, 110, 101, 95, 115, 116, 111, 110, 101, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 139, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 63, 160, 0, 0, 1, 1, 9, 103, 101, 116, 95, 115, 116, 111, 110, 101, 1, 52, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 101, 97, 100, 95, 112, 111, 119, 101, 114, 95, 111, 102, 95, 99, 104, 105, 115, 101, 108, 101, 100, 95, 98, 111, 111, 107, 115, 104, 101, 108, 102, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 79, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 114, 101, 97, 100, 95, 112, 111, 119, 101, 114, 95, 102, 114, 111, 109, 95, 99, 104, 105, 115, 101, 108, 101, 100, 95, 98, 111, 111, 107, 115, 104, 101, 108, 102, 46, 116, 105, 116, 108, 101, 34, 125, 85, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 114, 101, 97, 100, 95, 112, 111, 119, 101, 114, 95, 102, 114, 111, 109, 95, 99, 104, 105, 115, 101, 108, 101, 100, 95, 98, 111, 111, 107, 115, 104, 101, 108, 102, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 137, 2, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 64, 192, 0, 0, 1, 2, 18, 99, 104, 105, 115, 101, 108, 101, 100, 95, 98, 111, 111, 107, 115, 104, 101, 108, 102, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 1, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 98, 108, 117, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 36, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 97, 100, 118, 101, 110, 116, 117, 114, 105, 110, 103, 95, 116, 105, 109, 101, 1, 32, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 108, 101, 101, 112, 95, 105, 110, 95, 98, 101, 100, 1, 61, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 97, 100, 118, 101, 110, 116, 117, 114, 105, 110, 103, 95, 116, 105, 109, 101, 46, 116, 105, 116, 108, 101, 34, 125, 67, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 97, 100, 118, 101, 110, 116, 117, 114, 105, 110, 103, 95, 116, 105, 109, 101, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 191, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 64, 0, 0, 0, 65, 120, 0, 0, 53, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 117, 115, 104, 114, 111, 111, 109, 95, 102, 105, 101, 108, 100, 115, 1, 27, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 102, 114, 111, 122, 101, 110, 95, 111, 99, 101, 97, 110, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 114, 111, 122, 101, 110, 95, 111, 99, 101, 97, 110, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 99, 111, 108, 100, 95, 111, 99, 101, 97, 110, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 111, 108, 100, 95, 111, 99, 101, 97, 110, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 111, 99, 101, 97, 110, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 99, 101, 97, 110, 1, 29, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 108, 117, 107, 101, 119, 97, 114, 109, 95, 111, 99, 101, 97, 110, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 108, 117, 107, 101, 119, 97, 114, 109, 95, 111, 99, 101, 97, 110, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 114, 109, 95, 111, 99, 101, 97, 110, 1, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 110, 121, 95, 115, 104, 111, 114, 101, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 119, 97, 109, 112, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 97, 110, 103, 114, 111, 118, 101, 95, 115, 119, 97, 109, 112, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 115, 108, 111, 112, 101, 115, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 112, 108, 97, 105, 110, 115, 1, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 98, 101, 97, 99, 104, 1, 34, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 103, 114, 97, 118, 101, 108, 108, 121, 95, 104, 105, 108, 108, 115, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 114, 111, 118, 101, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 104, 105, 108, 108, 115, 1, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 116, 97, 105, 103, 97, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 102, 111, 114, 101, 115, 116, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 97, 105, 103, 97, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 108, 97, 105, 110, 115, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 101, 97, 100, 111, 119, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 101, 97, 99, 104, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 111, 114, 101, 115, 116, 1, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 108, 100, 95, 103, 114, 111, 119, 116, 104, 95, 115, 112, 114, 117, 99, 101, 95, 116, 97, 105, 103, 97, 1, 23, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 108, 111, 119, 101, 114, 95, 102, 111, 114, 101, 115, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 105, 114, 99, 104, 95, 102, 111, 114, 101, 115, 116, 1, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 97, 114, 107, 95, 102, 111, 114, 101, 115, 116, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 97, 118, 97, 110, 110, 97, 95, 112, 108, 97, 116, 101, 97, 117, 1, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 97, 118, 97, 110, 110, 97, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 106, 117, 110, 103, 108, 101, 1, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 97, 100, 108, 97, 110, 100, 115, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 115, 101, 114, 116, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 111, 111, 100, 101, 100, 95, 98, 97, 100, 108, 97, 110, 100, 115, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 106, 97, 103, 103, 101, 100, 95, 112, 101, 97, 107, 115, 1, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 110, 121, 95, 112, 101, 97, 107, 115, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 114, 111, 122, 101, 110, 95, 114, 105, 118, 101, 114, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 105, 118, 101, 114, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 105, 99, 101, 95, 115, 112, 105, 107, 101, 115, 1, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 108, 100, 95, 103, 114, 111, 119, 116, 104, 95, 112, 105, 110, 101, 95, 116, 97, 105, 103, 97, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 117, 110, 102, 108, 111, 119, 101, 114, 95, 112, 108, 97, 105, 110, 115, 1, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 108, 100, 95, 103, 114, 111, 119, 116, 104, 95, 98, 105, 114, 99, 104, 95, 102, 111, 114, 101, 115, 116, 1, 23, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 97, 114, 115, 101, 95, 106, 117, 110, 103, 108, 101, 1, 23, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 97, 109, 98, 111, 111, 95, 106, 117, 110, 103, 108, 101, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 114, 111, 100, 101, 100, 95, 98, 97, 100, 108, 97, 110, 100, 115, 1, 27, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 115, 97, 118, 97, 110, 110, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 104, 101, 114, 114, 121, 95, 103, 114, 111, 118, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 114, 111, 122, 101, 110, 95, 112, 101, 97, 107, 115, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 114, 105, 112, 115, 116, 111, 110, 101, 95, 99, 97, 118, 101, 115, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 108, 117, 115, 104, 95, 99, 97, 118, 101, 115, 1, 19, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 100, 97, 114, 107, 1, 32, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 108, 101, 101, 112, 95, 105, 110, 95, 98, 101, 100, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 57, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 108, 101, 101, 112, 95, 105, 110, 95, 98, 101, 100, 46, 116, 105, 116, 108, 101, 34, 125, 63, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 108, 101, 101, 112, 95, 105, 110, 95, 98, 101, 100, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 170, 7, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 65, 132, 0, 0, 1, 1, 12, 115, 108, 101, 112, 116, 95, 105, 110, 95, 98, 101, 100, 1, 43, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 115, 97, 110, 100, 115, 116, 111, 110, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 98, 97, 109, 98, 111, 111, 95, 114, 97, 102, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 58, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 108, 108, 95, 101, 120, 99, 108, 117, 115, 105, 118, 101, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 115, 1, 47, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 110, 121, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 1, 83, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 108, 108, 95, 101, 120, 99, 108, 117, 115, 105, 118, 101, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 115, 46, 116, 105, 116, 108, 101, 34, 125, 89, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 108, 108, 95, 101, 120, 99, 108, 117, 115, 105, 118, 101, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 115, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 208, 9, 1, 0, 1, 0, 0, 0, 2, 64, 0, 0, 0, 63, 128, 0, 0, 8, 1, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 114, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 117, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 105, 98, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 114, 100, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 74, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 101, 110, 99, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 105, 100, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 76, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 121, 102, 105, 110, 100, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 118, 101, 114, 121, 95, 118, 101, 114, 121, 95, 102, 114, 105, 103, 104, 116, 101, 110, 105, 110, 103, 1, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 104, 114, 111, 119, 95, 116, 114, 105, 100, 101, 110, 116, 1, 66, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 118, 101, 114, 121, 95, 118, 101, 114, 121, 95, 102, 114, 105, 103, 104, 116, 101, 110, 105, 110, 103, 46, 116, 105, 116, 108, 101, 34, 125, 72, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 118, 101, 114, 121, 95, 118, 101, 114, 121, 95, 102, 114, 105, 103, 104, 116, 101, 110, 105, 110, 103, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 243, 8, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 64, 0, 0, 65, 88, 0, 0, 1, 1, 15, 115, 116, 114, 117, 99, 107, 95, 118, 105, 108, 108, 97, 103, 101, 114, 1, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 112, 105, 99, 107, 97, 120, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 0, 43, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 98, 105, 114, 99, 104, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 101, 101, 110, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 97, 99, 97, 99, 105, 97, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 99, 104, 101, 114, 114, 121, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 0, 1, 49, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 114, 111, 111, 116, 46, 116, 105, 116, 108, 101, 34, 125, 55, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 114, 111, 111, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 159, 8, 1, 0, 0, 0, 0, 0, 1, 61, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 101, 120, 116, 117, 114, 101, 115, 47, 103, 117, 105, 47, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 47, 98, 97, 99, 107, 103, 114, 111, 117, 110, 100, 115, 47, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 112, 110, 103, 0, 0, 0, 0, 65, 28, 0, 0, 1, 2, 16, 107, 105, 108, 108, 101, 100, 95, 115, 111, 109, 101, 116, 104, 105, 110, 103, 19, 107, 105, 108, 108, 101, 100, 95, 98, 121, 95, 115, 111, 109, 101, 116, 104, 105, 110, 103, 1, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 106, 117, 110, 103, 108, 101, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 104, 111, 111, 116, 95, 97, 114, 114, 111, 119, 1, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 1, 56, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 104, 111, 111, 116, 95, 97, 114, 114, 111, 119, 46, 116, 105, 116, 108, 101, 34, 125, 62, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 104, 111, 111, 116, 95, 97, 114, 114, 111, 119, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 248, 5, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 65, 64, 0, 0, 1, 1, 10, 115, 104, 111, 116, 95, 97, 114, 114, 111, 119, 1, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 119, 104, 105, 116, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 46, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 100, 97, 114, 107, 95, 111, 97, 107, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 48, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 109, 111, 98, 95, 110, 101, 97, 114, 95, 115, 99, 117, 108, 107, 95, 99, 97, 116, 97, 108, 121, 115, 116, 1, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 1, 73, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 107, 105, 108, 108, 95, 109, 111, 98, 95, 110, 101, 97, 114, 95, 115, 99, 117, 108, 107, 95, 99, 97, 116, 97, 108, 121, 115, 116, 46, 116, 105, 116, 108, 101, 34, 125, 79, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 107, 105, 108, 108, 95, 109, 111, 98, 95, 110, 101, 97, 114, 95, 115, 99, 117, 108, 107, 95, 99, 97, 116, 97, 108, 121, 115, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 223, 2, 1, 0, 1, 0, 0, 0, 2, 64, 0, 0, 0, 65, 48, 0, 0, 1, 1, 28, 107, 105, 108, 108, 95, 109, 111, 98, 95, 110, 101, 97, 114, 95, 115, 99, 117, 108, 107, 95, 99, 97, 116, 97, 108, 121, 115, 116, 1, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 108, 108, 95, 109, 111, 98, 115, 1, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 1, 58, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 107, 105, 108, 108, 95, 97, 108, 108, 95, 109, 111, 98, 115, 46, 116, 105, 116, 108, 101, 34, 125, 64, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 107, 105, 108, 108, 95, 97, 108, 108, 95, 109, 111, 98, 115, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 157, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 64, 0, 0, 0, 65, 32, 0, 0, 34, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 108, 97, 122, 101, 1, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 97, 118, 101, 95, 115, 112, 105, 100, 101, 114, 1, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 114, 101, 101, 112, 101, 114, 1, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 114, 111, 119, 110, 101, 100, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 108, 100, 101, 114, 95, 103, 117, 97, 114, 100, 105, 97, 110, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 95, 100, 114, 97, 103, 111, 110, 1, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 97, 110, 1, 19, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 105, 116, 101, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 118, 111, 107, 101, 114, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 104, 97, 115, 116, 1, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 117, 97, 114, 100, 105, 97, 110, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 111, 103, 108, 105, 110, 1, 14, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 117, 115, 107, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 97, 103, 109, 97, 95, 99, 117, 98, 101, 1, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 104, 97, 110, 116, 111, 109, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 95, 98, 114, 117, 116, 101, 1, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 108, 108, 97, 103, 101, 114, 1, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 97, 118, 97, 103, 101, 114, 1, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 104, 117, 108, 107, 101, 114, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 118, 101, 114, 102, 105, 115, 104, 1, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 107, 101, 108, 101, 116, 111, 110, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 108, 105, 109, 101, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 100, 101, 114, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 114, 97, 121, 1, 13, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 105, 110, 100, 105, 99, 97, 116, 111, 114, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 99, 104, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 95, 115, 107, 101, 108, 101, 116, 111, 110, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 103, 108, 105, 110, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 95, 118, 105, 108, 108, 97, 103, 101, 114, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 102, 105, 101, 100, 95, 112, 105, 103, 108, 105, 110, 1, 53, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 98, 114, 111, 119, 110, 95, 103, 108, 97, 122, 101, 100, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 20, 104, 97, 115, 95, 98, 114, 111, 119, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 108, 105, 103, 104, 116, 110, 105, 110, 103, 95, 114, 111, 100, 95, 119, 105, 116, 104, 95, 118, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 95, 102, 105, 114, 101, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 80, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 108, 105, 103, 104, 116, 110, 105, 110, 103, 95, 114, 111, 100, 95, 119, 105, 116, 104, 95, 118, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 95, 102, 105, 114, 101, 46, 116, 105, 116, 108, 101, 34, 125, 86, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 108, 105, 103, 104, 116, 110, 105, 110, 103, 95, 114, 111, 100, 95, 119, 105, 116, 104, 95, 118, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 95, 102, 105, 114, 101, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 139, 5, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 0, 0, 0, 0, 1, 1, 35, 108, 105, 103, 104, 116, 110, 105, 110, 103, 95, 114, 111, 100, 95, 119, 105, 116, 104, 95, 118, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 95, 102, 105, 114, 101, 1, 39, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 119, 111, 95, 98, 105, 114, 100, 115, 95, 111, 110, 101, 95, 97, 114, 114, 111, 119, 1, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 111, 108, 95, 98, 101, 116, 115, 121, 1, 64, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 119, 111, 95, 98, 105, 114, 100, 115, 95, 111, 110, 101, 95, 97, 114, 114, 111, 119, 46, 116, 105, 116, 108, 101, 34, 125, 70, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 119, 111, 95, 98, 105, 114, 100, 115, 95, 111, 110, 101, 95, 97, 114, 114, 111, 119, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 247, 8, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 64, 0, 0, 0, 64, 128, 0, 0, 1, 1, 9, 116, 119, 111, 95, 98, 105, 114, 100, 115, 1, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 119, 104, 111, 115, 95, 116, 104, 101, 95, 112, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 119, 1, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 111, 108, 95, 98, 101, 116, 115, 121, 1, 66, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 119, 104, 111, 115, 95, 116, 104, 101, 95, 112, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 119, 46, 116, 105, 116, 108, 101, 34, 125, 72, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 119, 104, 111, 115, 95, 116, 104, 101, 95, 112, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 119, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 247, 8, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 64, 160, 0, 0, 1, 1, 13, 107, 105, 108, 108, 95, 112, 105, 108, 108, 97, 103, 101, 114, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 97, 100, 101, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 50, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 97, 100, 101, 46, 116, 105, 116, 108, 101, 34, 125, 56, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 97, 100, 101, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 253, 5, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 65, 8, 0, 0, 1, 1, 6, 116, 114, 97, 100, 101, 100, 1, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 103, 104, 97, 115, 116, 1, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 112, 97, 114, 114, 111, 116, 1, 62, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 103, 104, 97, 115, 116, 46, 116, 105, 116, 108, 101, 34, 125, 68, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 103, 104, 97, 115, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 253, 6, 1, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 64, 224, 0, 0, 1, 1, 17, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 103, 104, 97, 115, 116, 1, 47, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 110, 121, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 72, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 110, 121, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 46, 116, 105, 116, 108, 101, 34, 125, 78, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 110, 121, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 196, 9, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 63, 128, 0, 0, 1, 16, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 105, 100, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 114, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 114, 100, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 111, 115, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 117, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 74, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 101, 110, 99, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 76, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 121, 102, 105, 110, 100, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 121, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 105, 98, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 73, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 101, 110, 116, 114, 121, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 111, 97, 115, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 73, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 97, 105, 115, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 108, 100, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 73, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 104, 97, 112, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 117, 110, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 97, 100, 101, 95, 97, 116, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 97, 100, 101, 1, 66, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 97, 100, 101, 95, 97, 116, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 46, 116, 105, 116, 108, 101, 34, 125, 72, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 97, 100, 101, 95, 97, 116, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 253, 5, 1, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 65, 16, 0, 0, 1, 1, 21, 116, 114, 97, 100, 101, 95, 97, 116, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 1, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 109, 105, 110, 101, 95, 100, 105, 97, 109, 111, 110, 100, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 105, 114, 111, 110, 95, 116, 111, 111, 108, 115, 1, 53, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 109, 105, 110, 101, 95, 100, 105, 97, 109, 111, 110, 100, 46, 116, 105, 116, 108, 101, 34, 125, 59, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 109, 105, 110, 101, 95, 100, 105, 97, 109, 111, 110, 100, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 252, 5, 1, 0, 0, 0, 0, 0, 2, 64, 160, 0, 0, 63, 128, 0, 0, 1, 1, 7, 100, 105, 97, 109, 111, 110, 100, 1, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 99, 121, 97, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 29, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 117, 112, 103, 114, 97, 100, 101, 95, 116, 111, 111, 108, 115, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 109, 105, 110, 101, 95, 115, 116, 111, 110, 101, 1, 54, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 117, 112, 103, 114, 97, 100, 101, 95, 116, 111, 111, 108, 115, 46, 116, 105, 116, 108, 101, 34, 125, 60, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 117, 112, 103, 114, 97, 100, 101, 95, 116, 111, 111, 108, 115, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 144, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 63, 160, 0, 0, 1, 1, 13, 115, 116, 111, 110, 101, 95, 112, 105, 99, 107, 97, 120, 101, 1, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 111, 97, 107, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 119, 104, 105, 116, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 115, 109, 101, 108, 116, 95, 105, 114, 111, 110, 1, 29, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 117, 112, 103, 114, 97, 100, 101, 95, 116, 111, 111, 108, 115, 1, 51, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 115, 109, 101, 108, 116, 95, 105, 114, 111, 110, 46, 116, 105, 116, 108, 101, 34, 125, 57, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 115, 109, 101, 108, 116, 95, 105, 114, 111, 110, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 130, 6, 1, 0, 0, 0, 0, 0, 2, 64, 64, 0, 0, 63, 160, 0, 0, 1, 1, 4, 105, 114, 111, 110, 1, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 102, 111, 111, 100, 47, 99, 111, 111, 107, 101, 100, 95, 112, 111, 114, 107, 99, 104, 111, 112, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 12, 104, 97, 115, 95, 112, 111, 114, 107, 99, 104, 111, 112, 0, 56, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 111, 114, 97, 110, 103, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 99, 121, 97, 110, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 42, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 102, 97, 108, 108, 95, 102, 114, 111, 109, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 67, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 102, 97, 108, 108, 95, 102, 114, 111, 109, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 46, 116, 105, 116, 108, 101, 34, 125, 73, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 102, 97, 108, 108, 95, 102, 114, 111, 109, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 229, 6, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 65, 156, 0, 0, 1, 1, 22, 102, 97, 108, 108, 95, 102, 114, 111, 109, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 1, 0, 99, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 117, 109, 109, 111, 110, 95, 105, 114, 111, 110, 95, 103, 111, 108, 101, 109, 1, 14, 115, 117, 109, 109, 111, 110, 101, 100, 95, 103, 111, 108, 101, 109, 0, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 109, 105, 110, 101, 95, 100, 105, 97, 109, 111, 110, 100, 1, 7, 100, 105, 97, 109, 111, 110, 100, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 115, 104, 105, 110, 121, 95, 103, 101, 97, 114, 4, 18, 100, 105, 97, 109, 111, 110, 100, 95, 99, 104, 101, 115, 116, 112, 108, 97, 116, 101, 1, 0, 0, 1, 139, 130, 234, 41, 80, 16, 100, 105, 97, 109, 111, 110, 100, 95, 108, 101, 103, 103, 105, 110, 103, 115, 0, 13, 100, 105, 97, 109, 111, 110, 100, 95, 98, 111, 111, 116, 115, 0, 14, 100, 105, 97, 109, 111, 110, 100, 95, 104, 101, 108, 109, 101, 116, 0, 46, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 100, 97, 114, 107, 95, 111, 97, 107, 95, 98, 111, 97, 116, 2, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 29, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 117, 112, 103, 114, 97, 100, 101, 95, 116, 111, 111, 108, 115, 1, 13, 115, 116, 111, 110, 101, 95, 112, 105, 99, 107, 97, 120, 101, 0, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 101, 101, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 97, 108, 118, 97, 103, 101, 95, 115, 104, 101, 114, 100, 7, 9, 104, 97, 115, 95, 115, 104, 101, 114, 100, 0, 15, 111, 99, 101, 97, 110, 95, 114, 117, 105, 110, 95, 99, 111, 108, 100, 0, 11, 100, 101, 115, 101, 114, 116, 95, 119, 101, 108, 108, 0, 16, 116, 114, 97, 105, 108, 95, 114, 117, 105, 110, 115, 95, 114, 97, 114, 101, 0, 18, 116, 114, 97, 105, 108, 95, 114, 117, 105, 110, 115, 95, 99, 111, 109, 109, 111, 110, 0, 14, 100, 101, 115, 101, 114, 116, 95, 112, 121, 114, 97, 109, 105, 100, 0, 15, 111, 99, 101, 97, 110, 95, 114, 117, 105, 110, 95, 119, 97, 114, 109, 0, 34, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 97, 120, 101, 2, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 1, 0, 0, 1, 139, 126, 9, 136, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 1, 10, 105, 109, 112, 111, 115, 115, 105, 98, 108, 101, 0, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 110, 105, 112, 101, 114, 95, 100, 117, 101, 108, 1, 15, 107, 105, 108, 108, 101, 100, 95, 115, 107, 101, 108, 101, 116, 111, 110, 0, 35, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 99, 104, 101, 115, 116, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 17, 104, 97, 115, 95, 108, 111, 116, 115, 95, 111, 102, 95, 105, 116, 101, 109, 115, 1, 0, 0, 1, 139, 130, 234, 84, 72, 52, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 109, 97, 103, 101, 110, 116, 97, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 53, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 114, 101, 100, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 117, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 117, 114, 112, 108, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 101, 100, 115, 116, 111, 110, 101, 47, 116, 110, 116, 2, 13, 104, 97, 115, 95, 103, 117, 110, 112, 111, 119, 100, 101, 114, 1, 0, 0, 1, 139, 134, 123, 176, 152, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 2, 19, 107, 105, 108, 108, 101, 100, 95, 98, 121, 95, 115, 111, 109, 101, 116, 104, 105, 110, 103, 1, 0, 0, 1, 139, 126, 8, 123, 88, 16, 107, 105, 108, 108, 101, 100, 95, 115, 111, 109, 101, 116, 104, 105, 110, 103, 0, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 103, 104, 97, 115, 116, 1, 17, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 103, 104, 97, 115, 116, 0, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 121, 101, 108, 108, 111, 119, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 47, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 110, 121, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 16, 74, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 101, 110, 99, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 114, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 117, 110, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 73, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 101, 110, 116, 114, 121, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 114, 100, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 105, 98, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 73, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 104, 97, 112, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 108, 100, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 121, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 111, 97, 115, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 76, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 121, 102, 105, 110, 100, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 105, 100, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 117, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 111, 115, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 73, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 97, 105, 115, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 97, 121, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 105, 110, 107, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 97, 99, 107, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 99, 114, 97, 102, 116, 105, 110, 103, 95, 116, 97, 98, 108, 101, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 17, 117, 110, 108, 111, 99, 107, 95, 114, 105, 103, 104, 116, 95, 97, 119, 97, 121, 1, 0, 0, 1, 139, 120, 164, 251, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 115, 109, 101, 108, 116, 95, 105, 114, 111, 110, 1, 4, 105, 114, 111, 110, 0, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 108, 108, 95, 109, 111, 98, 115, 34, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 108, 108, 97, 103, 101, 114, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 108, 97, 122, 101, 0, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 107, 101, 108, 101, 116, 111, 110, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 103, 108, 105, 110, 0, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 108, 100, 101, 114, 95, 103, 117, 97, 114, 100, 105, 97, 110, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 97, 118, 97, 103, 101, 114, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 104, 97, 115, 116, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 111, 103, 108, 105, 110, 0, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 117, 97, 114, 100, 105, 97, 110, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 105, 110, 100, 105, 99, 97, 116, 111, 114, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 97, 103, 109, 97, 95, 99, 117, 98, 101, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 95, 98, 114, 117, 116, 101, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 100, 101, 114, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 114, 101, 101, 112, 101, 114, 1, 0, 0, 1, 139, 134, 123, 172, 176, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 1, 0, 0, 1, 139, 131, 98, 204, 32, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 108, 105, 109, 101, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 118, 111, 107, 101, 114, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 104, 97, 110, 116, 111, 109, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 102, 105, 101, 100, 95, 112, 105, 103, 108, 105, 110, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 99, 104, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 95, 115, 107, 101, 108, 101, 116, 111, 110, 0, 14, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 117, 115, 107, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 95, 100, 114, 97, 103, 111, 110, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 104, 117, 108, 107, 101, 114, 0, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 97, 118, 101, 95, 115, 112, 105, 100, 101, 114, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 0, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 97, 110, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 118, 101, 114, 102, 105, 115, 104, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 114, 97, 121, 0, 19, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 105, 116, 101, 0, 13, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 114, 111, 119, 110, 101, 100, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 95, 118, 105, 108, 108, 97, 103, 101, 114, 0, 39, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 108, 97, 115, 115, 2, 19, 104, 97, 115, 95, 115, 109, 101, 108, 116, 115, 95, 116, 111, 95, 103, 108, 97, 115, 115, 1, 0, 0, 1, 139, 125, 134, 176, 200, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 56, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 117, 114, 112, 108, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 101, 101, 110, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 111, 114, 97, 110, 103, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 99, 121, 97, 110, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 60, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 98, 108, 117, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 98, 117, 108, 108, 115, 101, 121, 101, 1, 8, 98, 117, 108, 108, 115, 101, 121, 101, 0, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 119, 104, 111, 115, 95, 116, 104, 101, 95, 112, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 119, 1, 13, 107, 105, 108, 108, 95, 112, 105, 108, 108, 97, 103, 101, 114, 0, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 112, 105, 99, 107, 97, 120, 101, 2, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 1, 0, 0, 1, 139, 126, 9, 136, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 102, 111, 111, 100, 47, 99, 111, 111, 107, 101, 100, 95, 112, 111, 114, 107, 99, 104, 111, 112, 95, 102, 114, 111, 109, 95, 115, 109, 111, 107, 105, 110, 103, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 12, 104, 97, 115, 95, 112, 111, 114, 107, 99, 104, 111, 112, 1, 0, 0, 1, 139, 130, 234, 224, 232, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 111, 108, 95, 98, 101, 116, 115, 121, 1, 13, 115, 104, 111, 116, 95, 99, 114, 111, 115, 115, 98, 111, 119, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 109, 105, 110, 101, 95, 115, 116, 111, 110, 101, 1, 9, 103, 101, 116, 95, 115, 116, 111, 110, 101, 0, 43, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 115, 97, 110, 100, 115, 116, 111, 110, 101, 2, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 97, 100, 101, 95, 97, 116, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 1, 21, 116, 114, 97, 100, 101, 95, 97, 116, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 0, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 34, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 108, 108, 97, 103, 101, 114, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 108, 97, 122, 101, 0, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 107, 101, 108, 101, 116, 111, 110, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 103, 108, 105, 110, 0, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 108, 100, 101, 114, 95, 103, 117, 97, 114, 100, 105, 97, 110, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 97, 118, 97, 103, 101, 114, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 104, 97, 115, 116, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 111, 103, 108, 105, 110, 0, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 117, 97, 114, 100, 105, 97, 110, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 105, 110, 100, 105, 99, 97, 116, 111, 114, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 97, 103, 109, 97, 95, 99, 117, 98, 101, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 95, 98, 114, 117, 116, 101, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 100, 101, 114, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 114, 101, 101, 112, 101, 114, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 1, 0, 0, 1, 139, 131, 98, 204, 32, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 108, 105, 109, 101, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 118, 111, 107, 101, 114, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 104, 97, 110, 116, 111, 109, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 102, 105, 101, 100, 95, 112, 105, 103, 108, 105, 110, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 99, 104, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 95, 115, 107, 101, 108, 101, 116, 111, 110, 0, 14, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 117, 115, 107, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 95, 100, 114, 97, 103, 111, 110, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 104, 117, 108, 107, 101, 114, 0, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 97, 118, 101, 95, 115, 112, 105, 100, 101, 114, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 0, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 97, 110, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 118, 101, 114, 102, 105, 115, 104, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 114, 97, 121, 0, 19, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 105, 116, 101, 0, 13, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 114, 111, 119, 110, 101, 100, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 95, 118, 105, 108, 108, 97, 103, 101, 114, 0, 48, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 114, 101, 100, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 109, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 98, 97, 109, 98, 111, 111, 95, 114, 97, 102, 116, 2, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 56, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 121, 101, 108, 108, 111, 119, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 103, 114, 97, 121, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 99, 111, 109, 98, 97, 116, 47, 119, 111, 111, 100, 101, 110, 95, 115, 119, 111, 114, 100, 2, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 1, 0, 0, 1, 139, 126, 9, 136, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 114, 111, 119, 110, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 99, 97, 109, 112, 102, 105, 114, 101, 3, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 1, 0, 0, 1, 139, 126, 9, 136, 224, 8, 104, 97, 115, 95, 99, 111, 97, 108, 0, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 36, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 108, 97, 100, 100, 101, 114, 2, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 1, 0, 0, 1, 139, 126, 9, 136, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 46, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 109, 97, 110, 103, 114, 111, 118, 101, 95, 98, 111, 97, 116, 2, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 104, 114, 111, 119, 95, 116, 114, 105, 100, 101, 110, 116, 1, 12, 115, 104, 111, 116, 95, 116, 114, 105, 100, 101, 110, 116, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 121, 101, 108, 108, 111, 119, 95, 103, 108, 97, 122, 101, 100, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 21, 104, 97, 115, 95, 121, 101, 108, 108, 111, 119, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 223, 204, 248, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 104, 111, 110, 101, 121, 95, 98, 108, 111, 99, 107, 95, 115, 108, 105, 100, 101, 1, 17, 104, 111, 110, 101, 121, 95, 98, 108, 111, 99, 107, 95, 115, 108, 105, 100, 101, 0, 36, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 97, 100, 118, 101, 110, 116, 117, 114, 105, 110, 103, 95, 116, 105, 109, 101, 53, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 115, 108, 111, 112, 101, 115, 0, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 108, 100, 95, 103, 114, 111, 119, 116, 104, 95, 112, 105, 110, 101, 95, 116, 97, 105, 103, 97, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 117, 115, 104, 114, 111, 111, 109, 95, 102, 105, 101, 108, 100, 115, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 97, 105, 103, 97, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 111, 99, 101, 97, 110, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 114, 111, 100, 101, 100, 95, 98, 97, 100, 108, 97, 110, 100, 115, 1, 0, 0, 1, 139, 120, 164, 254, 232, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 114, 111, 122, 101, 110, 95, 114, 105, 118, 101, 114, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 104, 101, 114, 114, 121, 95, 103, 114, 111, 118, 101, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 117, 110, 102, 108, 111, 119, 101, 114, 95, 112, 108, 97, 105, 110, 115, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 105, 114, 99, 104, 95, 102, 111, 114, 101, 115, 116, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 104, 105, 108, 108, 115, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 111, 111, 100, 101, 100, 95, 98, 97, 100, 108, 97, 110, 100, 115, 1, 0, 0, 1, 139, 130, 220, 35, 120, 23, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 97, 109, 98, 111, 111, 95, 106, 117, 110, 103, 108, 101, 0, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 97, 100, 108, 97, 110, 100, 115, 1, 0, 0, 1, 139, 130, 217, 213, 160, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 97, 118, 97, 110, 110, 97, 95, 112, 108, 97, 116, 101, 97, 117, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 101, 97, 99, 104, 0, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 97, 114, 107, 95, 102, 111, 114, 101, 115, 116, 0, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 110, 121, 95, 112, 101, 97, 107, 115, 0, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 97, 110, 103, 114, 111, 118, 101, 95, 115, 119, 97, 109, 112, 0, 23, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 97, 114, 115, 101, 95, 106, 117, 110, 103, 108, 101, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 105, 118, 101, 114, 1, 0, 0, 1, 139, 126, 9, 199, 96, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 108, 117, 107, 101, 119, 97, 114, 109, 95, 111, 99, 101, 97, 110, 0, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 110, 121, 95, 115, 104, 111, 114, 101, 1, 0, 0, 1, 139, 126, 9, 191, 144, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 112, 108, 97, 105, 110, 115, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 114, 105, 112, 115, 116, 111, 110, 101, 95, 99, 97, 118, 101, 115, 0, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 116, 97, 105, 103, 97, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 114, 111, 118, 101, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 119, 97, 109, 112, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 106, 97, 103, 103, 101, 100, 95, 112, 101, 97, 107, 115, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 111, 108, 100, 95, 111, 99, 101, 97, 110, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 111, 114, 101, 115, 116, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 108, 117, 115, 104, 95, 99, 97, 118, 101, 115, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 99, 111, 108, 100, 95, 111, 99, 101, 97, 110, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 105, 99, 101, 95, 115, 112, 105, 107, 101, 115, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 114, 111, 122, 101, 110, 95, 111, 99, 101, 97, 110, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 115, 101, 114, 116, 1, 0, 0, 1, 139, 130, 217, 186, 72, 27, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 102, 114, 111, 122, 101, 110, 95, 111, 99, 101, 97, 110, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 102, 111, 114, 101, 115, 116, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 99, 101, 97, 110, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 106, 117, 110, 103, 108, 101, 0, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 108, 100, 95, 103, 114, 111, 119, 116, 104, 95, 115, 112, 114, 117, 99, 101, 95, 116, 97, 105, 103, 97, 0, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 98, 101, 97, 99, 104, 0, 27, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 115, 97, 118, 97, 110, 110, 97, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 114, 109, 95, 111, 99, 101, 97, 110, 0, 29, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 108, 117, 107, 101, 119, 97, 114, 109, 95, 111, 99, 101, 97, 110, 0, 23, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 108, 111, 119, 101, 114, 95, 102, 111, 114, 101, 115, 116, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 114, 111, 122, 101, 110, 95, 112, 101, 97, 107, 115, 0, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 108, 100, 95, 103, 114, 111, 119, 116, 104, 95, 98, 105, 114, 99, 104, 95, 102, 111, 114, 101, 115, 116, 0, 19, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 100, 97, 114, 107, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 101, 97, 100, 111, 119, 0, 34, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 103, 114, 97, 118, 101, 108, 108, 121, 95, 104, 105, 108, 108, 115, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 97, 118, 97, 110, 110, 97, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 108, 97, 105, 110, 115, 0, 48, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 109, 111, 98, 95, 110, 101, 97, 114, 95, 115, 99, 117, 108, 107, 95, 99, 97, 116, 97, 108, 121, 115, 116, 1, 28, 107, 105, 108, 108, 95, 109, 111, 98, 95, 110, 101, 97, 114, 95, 115, 99, 117, 108, 107, 95, 99, 97, 116, 97, 108, 121, 115, 116, 0, 60, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 102, 111, 111, 100, 47, 99, 111, 111, 107, 101, 100, 95, 112, 111, 114, 107, 99, 104, 111, 112, 95, 102, 114, 111, 109, 95, 99, 97, 109, 112, 102, 105, 114, 101, 95, 99, 111, 111, 107, 105, 110, 103, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 12, 104, 97, 115, 95, 112, 111, 114, 107, 99, 104, 111, 112, 1, 0, 0, 1, 139, 130, 234, 224, 232, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 119, 104, 105, 116, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 35, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 97, 118, 111, 105, 100, 95, 118, 105, 98, 114, 97, 116, 105, 111, 110, 1, 15, 97, 118, 111, 105, 100, 95, 118, 105, 98, 114, 97, 116, 105, 111, 110, 0, 58, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 108, 108, 95, 101, 120, 99, 108, 117, 115, 105, 118, 101, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 115, 8, 74, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 101, 110, 99, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 76, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 121, 102, 105, 110, 100, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 105, 100, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 114, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 114, 100, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 117, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 105, 98, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 111, 97, 107, 95, 98, 111, 97, 116, 2, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 115, 112, 114, 117, 99, 101, 95, 98, 111, 97, 116, 2, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 112, 97, 114, 114, 111, 116, 1, 18, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 112, 97, 114, 114, 111, 116, 0, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 104, 111, 111, 116, 95, 97, 114, 114, 111, 119, 1, 10, 115, 104, 111, 116, 95, 97, 114, 114, 111, 119, 0, 43, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 98, 105, 114, 99, 104, 95, 98, 111, 97, 116, 2, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 53, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 98, 114, 111, 119, 110, 95, 103, 108, 97, 122, 101, 100, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 20, 104, 97, 115, 95, 98, 114, 111, 119, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 131, 98, 126, 0, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 43, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 112, 108, 97, 121, 95, 106, 117, 107, 101, 98, 111, 120, 95, 105, 110, 95, 109, 101, 97, 100, 111, 119, 115, 1, 23, 112, 108, 97, 121, 95, 106, 117, 107, 101, 98, 111, 120, 95, 105, 110, 95, 109, 101, 97, 100, 111, 119, 115, 0, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 97, 99, 107, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 105, 110, 107, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 60, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 103, 114, 97, 121, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 58, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 119, 97, 108, 107, 95, 111, 110, 95, 112, 111, 119, 100, 101, 114, 95, 115, 110, 111, 119, 95, 119, 105, 116, 104, 95, 108, 101, 97, 116, 104, 101, 114, 95, 98, 111, 111, 116, 115, 1, 38, 119, 97, 108, 107, 95, 111, 110, 95, 112, 111, 119, 100, 101, 114, 95, 115, 110, 111, 119, 95, 119, 105, 116, 104, 95, 108, 101, 97, 116, 104, 101, 114, 95, 98, 111, 111, 116, 115, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 109, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 34, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 104, 111, 101, 2, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 1, 0, 0, 1, 139, 126, 9, 136, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 105, 114, 111, 110, 95, 116, 111, 111, 108, 115, 1, 12, 105, 114, 111, 110, 95, 112, 105, 99, 107, 97, 120, 101, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 114, 111, 111, 116, 1, 14, 99, 114, 97, 102, 116, 105, 110, 103, 95, 116, 97, 98, 108, 101, 0, 45, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 109, 105, 115, 99, 47, 102, 105, 114, 101, 119, 111, 114, 107, 95, 114, 111, 99, 107, 101, 116, 95, 115, 105, 109, 112, 108, 101, 2, 13, 104, 97, 115, 95, 103, 117, 110, 112, 111, 119, 100, 101, 114, 1, 0, 0, 1, 139, 134, 123, 176, 152, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 32, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 108, 101, 101, 112, 95, 105, 110, 95, 98, 101, 100, 1, 12, 115, 108, 101, 112, 116, 95, 105, 110, 95, 98, 101, 100, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 99, 104, 101, 114, 114, 121, 95, 98, 111, 97, 116, 2, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 56, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 111, 114, 97, 110, 103, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 97, 121, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 52, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 101, 97, 100, 95, 112, 111, 119, 101, 114, 95, 111, 102, 95, 99, 104, 105, 115, 101, 108, 101, 100, 95, 98, 111, 111, 107, 115, 104, 101, 108, 102, 2, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 0, 18, 99, 104, 105, 115, 101, 108, 101, 100, 95, 98, 111, 111, 107, 115, 104, 101, 108, 102, 0, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 118, 101, 114, 121, 95, 118, 101, 114, 121, 95, 102, 114, 105, 103, 104, 116, 101, 110, 105, 110, 103, 1, 15, 115, 116, 114, 117, 99, 107, 95, 118, 105, 108, 108, 97, 103, 101, 114, 0, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 114, 111, 119, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 102, 111, 111, 100, 47, 99, 111, 111, 107, 101, 100, 95, 112, 111, 114, 107, 99, 104, 111, 112, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 12, 104, 97, 115, 95, 112, 111, 114, 107, 99, 104, 111, 112, 1, 0, 0, 1, 139, 130, 234, 224, 232, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 99, 121, 97, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 36, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 111, 116, 101, 109, 95, 111, 102, 95, 117, 110, 100, 121, 105, 110, 103, 1, 10, 117, 115, 101, 100, 95, 116, 111, 116, 101, 109, 0, 57, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 99, 114, 97, 102, 116, 95, 100, 101, 99, 111, 114, 97, 116, 101, 100, 95, 112, 111, 116, 95, 117, 115, 105, 110, 103, 95, 111, 110, 108, 121, 95, 115, 104, 101, 114, 100, 115, 1, 29, 112, 111, 116, 95, 99, 114, 97, 102, 116, 101, 100, 95, 117, 115, 105, 110, 103, 95, 111, 110, 108, 121, 95, 115, 104, 101, 114, 100, 115, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 117, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 57, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 109, 97, 103, 101, 110, 116, 97, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 115, 104, 111, 118, 101, 108, 2, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 1, 0, 0, 1, 139, 126, 9, 136, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 98, 108, 117, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 47, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 114, 101, 100, 95, 115, 97, 110, 100, 115, 116, 111, 110, 101, 2, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 125, 134, 176, 200, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 39, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 119, 111, 95, 98, 105, 114, 100, 115, 95, 111, 110, 101, 95, 97, 114, 114, 111, 119, 1, 9, 116, 119, 111, 95, 98, 105, 114, 100, 115, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 108, 105, 103, 104, 116, 110, 105, 110, 103, 95, 114, 111, 100, 95, 119, 105, 116, 104, 95, 118, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 95, 102, 105, 114, 101, 1, 35, 108, 105, 103, 104, 116, 110, 105, 110, 103, 95, 114, 111, 100, 95, 119, 105, 116, 104, 95, 118, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 95, 102, 105, 114, 101, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 106, 117, 110, 103, 108, 101, 95, 98, 111, 97, 116, 2, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 42, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 102, 97, 108, 108, 95, 102, 114, 111, 109, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 1, 22, 102, 97, 108, 108, 95, 102, 114, 111, 109, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 119, 104, 105, 116, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 97, 99, 97, 99, 105, 97, 95, 98, 111, 97, 116, 2, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 97, 100, 101, 1, 6, 116, 114, 97, 100, 101, 100, 0];
+    let input = &[108, 1, 99, 36, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 97, 100, 118, 101, 110, 116, 117, 114, 105, 110, 103, 95, 116, 105, 109, 101, 1, 32, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 108, 101, 101, 112, 95, 105, 110, 95, 98, 101, 100, 1, 61, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 97, 100, 118, 101, 110, 116, 117, 114, 105, 110, 103, 95, 116, 105, 109, 101, 46, 116, 105, 116, 108, 101, 34, 125, 67, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 97, 100, 118, 101, 110, 116, 117, 114, 105, 110, 103, 95, 116, 105, 109, 101, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 191, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 64, 0, 0, 0, 65, 120, 0, 0, 53, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 117, 115, 104, 114, 111, 111, 109, 95, 102, 105, 101, 108, 100, 115, 1, 27, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 102, 114, 111, 122, 101, 110, 95, 111, 99, 101, 97, 110, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 114, 111, 122, 101, 110, 95, 111, 99, 101, 97, 110, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 99, 111, 108, 100, 95, 111, 99, 101, 97, 110, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 111, 108, 100, 95, 111, 99, 101, 97, 110, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 111, 99, 101, 97, 110, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 99, 101, 97, 110, 1, 29, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 108, 117, 107, 101, 119, 97, 114, 109, 95, 111, 99, 101, 97, 110, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 108, 117, 107, 101, 119, 97, 114, 109, 95, 111, 99, 101, 97, 110, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 114, 109, 95, 111, 99, 101, 97, 110, 1, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 110, 121, 95, 115, 104, 111, 114, 101, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 119, 97, 109, 112, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 97, 110, 103, 114, 111, 118, 101, 95, 115, 119, 97, 109, 112, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 115, 108, 111, 112, 101, 115, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 112, 108, 97, 105, 110, 115, 1, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 98, 101, 97, 99, 104, 1, 34, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 103, 114, 97, 118, 101, 108, 108, 121, 95, 104, 105, 108, 108, 115, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 114, 111, 118, 101, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 104, 105, 108, 108, 115, 1, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 116, 97, 105, 103, 97, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 102, 111, 114, 101, 115, 116, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 97, 105, 103, 97, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 108, 97, 105, 110, 115, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 101, 97, 100, 111, 119, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 101, 97, 99, 104, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 111, 114, 101, 115, 116, 1, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 108, 100, 95, 103, 114, 111, 119, 116, 104, 95, 115, 112, 114, 117, 99, 101, 95, 116, 97, 105, 103, 97, 1, 23, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 108, 111, 119, 101, 114, 95, 102, 111, 114, 101, 115, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 105, 114, 99, 104, 95, 102, 111, 114, 101, 115, 116, 1, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 97, 114, 107, 95, 102, 111, 114, 101, 115, 116, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 97, 118, 97, 110, 110, 97, 95, 112, 108, 97, 116, 101, 97, 117, 1, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 97, 118, 97, 110, 110, 97, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 106, 117, 110, 103, 108, 101, 1, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 97, 100, 108, 97, 110, 100, 115, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 115, 101, 114, 116, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 111, 111, 100, 101, 100, 95, 98, 97, 100, 108, 97, 110, 100, 115, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 106, 97, 103, 103, 101, 100, 95, 112, 101, 97, 107, 115, 1, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 110, 121, 95, 112, 101, 97, 107, 115, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 114, 111, 122, 101, 110, 95, 114, 105, 118, 101, 114, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 105, 118, 101, 114, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 105, 99, 101, 95, 115, 112, 105, 107, 101, 115, 1, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 108, 100, 95, 103, 114, 111, 119, 116, 104, 95, 112, 105, 110, 101, 95, 116, 97, 105, 103, 97, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 117, 110, 102, 108, 111, 119, 101, 114, 95, 112, 108, 97, 105, 110, 115, 1, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 108, 100, 95, 103, 114, 111, 119, 116, 104, 95, 98, 105, 114, 99, 104, 95, 102, 111, 114, 101, 115, 116, 1, 23, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 97, 114, 115, 101, 95, 106, 117, 110, 103, 108, 101, 1, 23, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 97, 109, 98, 111, 111, 95, 106, 117, 110, 103, 108, 101, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 114, 111, 100, 101, 100, 95, 98, 97, 100, 108, 97, 110, 100, 115, 1, 27, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 115, 97, 118, 97, 110, 110, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 104, 101, 114, 114, 121, 95, 103, 114, 111, 118, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 114, 111, 122, 101, 110, 95, 112, 101, 97, 107, 115, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 114, 105, 112, 115, 116, 111, 110, 101, 95, 99, 97, 118, 101, 115, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 108, 117, 115, 104, 95, 99, 97, 118, 101, 115, 1, 19, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 100, 97, 114, 107, 1, 35, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 97, 118, 111, 105, 100, 95, 118, 105, 98, 114, 97, 116, 105, 111, 110, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 60, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 97, 118, 111, 105, 100, 95, 118, 105, 98, 114, 97, 116, 105, 111, 110, 46, 116, 105, 116, 108, 101, 34, 125, 66, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 97, 118, 111, 105, 100, 95, 118, 105, 98, 114, 97, 116, 105, 111, 110, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 141, 5, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 65, 140, 0, 0, 1, 1, 15, 97, 118, 111, 105, 100, 95, 118, 105, 98, 114, 97, 116, 105, 111, 110, 1, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 98, 117, 108, 108, 115, 101, 121, 101, 1, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 104, 111, 111, 116, 95, 97, 114, 114, 111, 119, 1, 53, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 98, 117, 108, 108, 115, 101, 121, 101, 46, 116, 105, 116, 108, 101, 34, 125, 59, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 98, 117, 108, 108, 115, 101, 121, 101, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 137, 5, 1, 0, 1, 0, 0, 0, 2, 64, 64, 0, 0, 65, 56, 0, 0, 1, 1, 8, 98, 117, 108, 108, 115, 101, 121, 101, 1, 57, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 99, 114, 97, 102, 116, 95, 100, 101, 99, 111, 114, 97, 116, 101, 100, 95, 112, 111, 116, 95, 117, 115, 105, 110, 103, 95, 111, 110, 108, 121, 95, 115, 104, 101, 114, 100, 115, 1, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 97, 108, 118, 97, 103, 101, 95, 115, 104, 101, 114, 100, 1, 82, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 99, 114, 97, 102, 116, 95, 100, 101, 99, 111, 114, 97, 116, 101, 100, 95, 112, 111, 116, 95, 117, 115, 105, 110, 103, 95, 111, 110, 108, 121, 95, 115, 104, 101, 114, 100, 115, 46, 116, 105, 116, 108, 101, 34, 125, 88, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 99, 114, 97, 102, 116, 95, 100, 101, 99, 111, 114, 97, 116, 101, 100, 95, 112, 111, 116, 95, 117, 115, 105, 110, 103, 95, 111, 110, 108, 121, 95, 115, 104, 101, 114, 100, 115, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 138, 2, 1, 10, 10, 0, 14, 66, 108, 111, 99, 107, 69, 110, 116, 105, 116, 121, 84, 97, 103, 8, 0, 2, 105, 100, 0, 23, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 99, 111, 114, 97, 116, 101, 100, 95, 112, 111, 116, 9, 0, 6, 115, 104, 101, 114, 100, 115, 8, 0, 0, 0, 4, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 114, 105, 99, 107, 0, 29, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 101, 97, 114, 116, 95, 112, 111, 116, 116, 101, 114, 121, 95, 115, 104, 101, 114, 100, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 114, 105, 99, 107, 0, 32, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 120, 112, 108, 111, 114, 101, 114, 95, 112, 111, 116, 116, 101, 114, 121, 95, 115, 104, 101, 114, 100, 0, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 65, 148, 0, 0, 1, 1, 29, 112, 111, 116, 95, 99, 114, 97, 102, 116, 101, 100, 95, 117, 115, 105, 110, 103, 95, 111, 110, 108, 121, 95, 115, 104, 101, 114, 100, 115, 1, 42, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 102, 97, 108, 108, 95, 102, 114, 111, 109, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 67, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 102, 97, 108, 108, 95, 102, 114, 111, 109, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 46, 116, 105, 116, 108, 101, 34, 125, 73, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 102, 97, 108, 108, 95, 102, 114, 111, 109, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 229, 6, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 65, 156, 0, 0, 1, 1, 22, 102, 97, 108, 108, 95, 102, 114, 111, 109, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 1, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 104, 111, 110, 101, 121, 95, 98, 108, 111, 99, 107, 95, 115, 108, 105, 100, 101, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 62, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 104, 111, 110, 101, 121, 95, 98, 108, 111, 99, 107, 95, 115, 108, 105, 100, 101, 46, 116, 105, 116, 108, 101, 34, 125, 68, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 104, 111, 110, 101, 121, 95, 98, 108, 111, 99, 107, 95, 115, 108, 105, 100, 101, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 131, 5, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 64, 160, 0, 0, 1, 1, 17, 104, 111, 110, 101, 121, 95, 98, 108, 111, 99, 107, 95, 115, 108, 105, 100, 101, 1, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 55, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 46, 116, 105, 116, 108, 101, 34, 125, 61, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 152, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 65, 68, 0, 0, 1, 34, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 108, 97, 122, 101, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 97, 118, 101, 95, 115, 112, 105, 100, 101, 114, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 114, 101, 101, 112, 101, 114, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 114, 111, 119, 110, 101, 100, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 108, 100, 101, 114, 95, 103, 117, 97, 114, 100, 105, 97, 110, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 95, 100, 114, 97, 103, 111, 110, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 97, 110, 19, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 105, 116, 101, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 118, 111, 107, 101, 114, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 104, 97, 115, 116, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 117, 97, 114, 100, 105, 97, 110, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 111, 103, 108, 105, 110, 14, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 117, 115, 107, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 97, 103, 109, 97, 95, 99, 117, 98, 101, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 104, 97, 110, 116, 111, 109, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 95, 98, 114, 117, 116, 101, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 108, 108, 97, 103, 101, 114, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 97, 118, 97, 103, 101, 114, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 104, 117, 108, 107, 101, 114, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 118, 101, 114, 102, 105, 115, 104, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 107, 101, 108, 101, 116, 111, 110, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 108, 105, 109, 101, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 100, 101, 114, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 114, 97, 121, 13, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 105, 110, 100, 105, 99, 97, 116, 111, 114, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 99, 104, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 95, 115, 107, 101, 108, 101, 116, 111, 110, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 103, 108, 105, 110, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 95, 118, 105, 108, 108, 97, 103, 101, 114, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 102, 105, 101, 100, 95, 112, 105, 103, 108, 105, 110, 1, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 108, 108, 95, 109, 111, 98, 115, 1, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 1, 58, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 107, 105, 108, 108, 95, 97, 108, 108, 95, 109, 111, 98, 115, 46, 116, 105, 116, 108, 101, 34, 125, 64, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 107, 105, 108, 108, 95, 97, 108, 108, 95, 109, 111, 98, 115, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 157, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 64, 0, 0, 0, 65, 32, 0, 0, 34, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 108, 97, 122, 101, 1, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 97, 118, 101, 95, 115, 112, 105, 100, 101, 114, 1, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 114, 101, 101, 112, 101, 114, 1, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 114, 111, 119, 110, 101, 100, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 108, 100, 101, 114, 95, 103, 117, 97, 114, 100, 105, 97, 110, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 95, 100, 114, 97, 103, 111, 110, 1, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 97, 110, 1, 19, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 105, 116, 101, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 118, 111, 107, 101, 114, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 104, 97, 115, 116, 1, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 117, 97, 114, 100, 105, 97, 110, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 111, 103, 108, 105, 110, 1, 14, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 117, 115, 107, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 97, 103, 109, 97, 95, 99, 117, 98, 101, 1, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 104, 97, 110, 116, 111, 109, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 95, 98, 114, 117, 116, 101, 1, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 108, 108, 97, 103, 101, 114, 1, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 97, 118, 97, 103, 101, 114, 1, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 104, 117, 108, 107, 101, 114, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 118, 101, 114, 102, 105, 115, 104, 1, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 107, 101, 108, 101, 116, 111, 110, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 108, 105, 109, 101, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 100, 101, 114, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 114, 97, 121, 1, 13, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 105, 110, 100, 105, 99, 97, 116, 111, 114, 1, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 99, 104, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 95, 115, 107, 101, 108, 101, 116, 111, 110, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 103, 108, 105, 110, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 95, 118, 105, 108, 108, 97, 103, 101, 114, 1, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 102, 105, 101, 100, 95, 112, 105, 103, 108, 105, 110, 1, 48, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 109, 111, 98, 95, 110, 101, 97, 114, 95, 115, 99, 117, 108, 107, 95, 99, 97, 116, 97, 108, 121, 115, 116, 1, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 1, 73, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 107, 105, 108, 108, 95, 109, 111, 98, 95, 110, 101, 97, 114, 95, 115, 99, 117, 108, 107, 95, 99, 97, 116, 97, 108, 121, 115, 116, 46, 116, 105, 116, 108, 101, 34, 125, 79, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 107, 105, 108, 108, 95, 109, 111, 98, 95, 110, 101, 97, 114, 95, 115, 99, 117, 108, 107, 95, 99, 97, 116, 97, 108, 121, 115, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 223, 2, 1, 0, 1, 0, 0, 0, 2, 64, 0, 0, 0, 65, 48, 0, 0, 1, 1, 28, 107, 105, 108, 108, 95, 109, 111, 98, 95, 110, 101, 97, 114, 95, 115, 99, 117, 108, 107, 95, 99, 97, 116, 97, 108, 121, 115, 116, 1, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 108, 105, 103, 104, 116, 110, 105, 110, 103, 95, 114, 111, 100, 95, 119, 105, 116, 104, 95, 118, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 95, 102, 105, 114, 101, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 80, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 108, 105, 103, 104, 116, 110, 105, 110, 103, 95, 114, 111, 100, 95, 119, 105, 116, 104, 95, 118, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 95, 102, 105, 114, 101, 46, 116, 105, 116, 108, 101, 34, 125, 86, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 108, 105, 103, 104, 116, 110, 105, 110, 103, 95, 114, 111, 100, 95, 119, 105, 116, 104, 95, 118, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 95, 102, 105, 114, 101, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 139, 5, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 0, 0, 0, 0, 1, 1, 35, 108, 105, 103, 104, 116, 110, 105, 110, 103, 95, 114, 111, 100, 95, 119, 105, 116, 104, 95, 118, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 95, 102, 105, 114, 101, 1, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 111, 108, 95, 98, 101, 116, 115, 121, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 53, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 111, 108, 95, 98, 101, 116, 115, 121, 46, 116, 105, 116, 108, 101, 34, 125, 59, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 111, 108, 95, 98, 101, 116, 115, 121, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 247, 8, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 64, 128, 0, 0, 1, 1, 13, 115, 104, 111, 116, 95, 99, 114, 111, 115, 115, 98, 111, 119, 1, 43, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 112, 108, 97, 121, 95, 106, 117, 107, 101, 98, 111, 120, 95, 105, 110, 95, 109, 101, 97, 100, 111, 119, 115, 1, 32, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 108, 101, 101, 112, 95, 105, 110, 95, 98, 101, 100, 1, 68, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 112, 108, 97, 121, 95, 106, 117, 107, 101, 98, 111, 120, 95, 105, 110, 95, 109, 101, 97, 100, 111, 119, 115, 46, 116, 105, 116, 108, 101, 34, 125, 74, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 112, 108, 97, 121, 95, 106, 117, 107, 101, 98, 111, 120, 95, 105, 110, 95, 109, 101, 97, 100, 111, 119, 115, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 160, 2, 1, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 65, 140, 0, 0, 1, 1, 23, 112, 108, 97, 121, 95, 106, 117, 107, 101, 98, 111, 120, 95, 105, 110, 95, 109, 101, 97, 100, 111, 119, 115, 1, 52, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 101, 97, 100, 95, 112, 111, 119, 101, 114, 95, 111, 102, 95, 99, 104, 105, 115, 101, 108, 101, 100, 95, 98, 111, 111, 107, 115, 104, 101, 108, 102, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 79, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 114, 101, 97, 100, 95, 112, 111, 119, 101, 114, 95, 102, 114, 111, 109, 95, 99, 104, 105, 115, 101, 108, 101, 100, 95, 98, 111, 111, 107, 115, 104, 101, 108, 102, 46, 116, 105, 116, 108, 101, 34, 125, 85, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 114, 101, 97, 100, 95, 112, 111, 119, 101, 114, 95, 102, 114, 111, 109, 95, 99, 104, 105, 115, 101, 108, 101, 100, 95, 98, 111, 111, 107, 115, 104, 101, 108, 102, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 137, 2, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 64, 192, 0, 0, 1, 2, 18, 99, 104, 105, 115, 101, 108, 101, 100, 95, 98, 111, 111, 107, 115, 104, 101, 108, 102, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 0, 1, 49, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 114, 111, 111, 116, 46, 116, 105, 116, 108, 101, 34, 125, 55, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 114, 111, 111, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 159, 8, 1, 0, 0, 0, 0, 0, 1, 61, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 101, 120, 116, 117, 114, 101, 115, 47, 103, 117, 105, 47, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 47, 98, 97, 99, 107, 103, 114, 111, 117, 110, 100, 115, 47, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 112, 110, 103, 0, 0, 0, 0, 65, 28, 0, 0, 1, 2, 16, 107, 105, 108, 108, 101, 100, 95, 115, 111, 109, 101, 116, 104, 105, 110, 103, 19, 107, 105, 108, 108, 101, 100, 95, 98, 121, 95, 115, 111, 109, 101, 116, 104, 105, 110, 103, 1, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 97, 108, 118, 97, 103, 101, 95, 115, 104, 101, 114, 100, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 58, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 97, 108, 118, 97, 103, 101, 95, 115, 104, 101, 114, 100, 46, 116, 105, 116, 108, 101, 34, 125, 64, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 97, 108, 118, 97, 103, 101, 95, 115, 104, 101, 114, 100, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 193, 9, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 65, 148, 0, 0, 2, 6, 11, 100, 101, 115, 101, 114, 116, 95, 119, 101, 108, 108, 18, 116, 114, 97, 105, 108, 95, 114, 117, 105, 110, 115, 95, 99, 111, 109, 109, 111, 110, 15, 111, 99, 101, 97, 110, 95, 114, 117, 105, 110, 95, 119, 97, 114, 109, 14, 100, 101, 115, 101, 114, 116, 95, 112, 121, 114, 97, 109, 105, 100, 15, 111, 99, 101, 97, 110, 95, 114, 117, 105, 110, 95, 99, 111, 108, 100, 16, 116, 114, 97, 105, 108, 95, 114, 117, 105, 110, 115, 95, 114, 97, 114, 101, 1, 9, 104, 97, 115, 95, 115, 104, 101, 114, 100, 1, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 104, 111, 111, 116, 95, 97, 114, 114, 111, 119, 1, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 1, 56, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 104, 111, 111, 116, 95, 97, 114, 114, 111, 119, 46, 116, 105, 116, 108, 101, 34, 125, 62, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 104, 111, 111, 116, 95, 97, 114, 114, 111, 119, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 248, 5, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 65, 64, 0, 0, 1, 1, 10, 115, 104, 111, 116, 95, 97, 114, 114, 111, 119, 1, 32, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 108, 101, 101, 112, 95, 105, 110, 95, 98, 101, 100, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 57, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 108, 101, 101, 112, 95, 105, 110, 95, 98, 101, 100, 46, 116, 105, 116, 108, 101, 34, 125, 63, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 108, 101, 101, 112, 95, 105, 110, 95, 98, 101, 100, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 170, 7, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 65, 132, 0, 0, 1, 1, 12, 115, 108, 101, 112, 116, 95, 105, 110, 95, 98, 101, 100, 1, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 110, 105, 112, 101, 114, 95, 100, 117, 101, 108, 1, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 104, 111, 111, 116, 95, 97, 114, 114, 111, 119, 1, 56, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 110, 105, 112, 101, 114, 95, 100, 117, 101, 108, 46, 116, 105, 116, 108, 101, 34, 125, 62, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 110, 105, 112, 101, 114, 95, 100, 117, 101, 108, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 249, 5, 1, 0, 1, 0, 0, 0, 2, 64, 64, 0, 0, 65, 72, 0, 0, 1, 1, 15, 107, 105, 108, 108, 101, 100, 95, 115, 107, 101, 108, 101, 116, 111, 110, 1, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 103, 104, 97, 115, 116, 1, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 112, 97, 114, 114, 111, 116, 1, 62, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 103, 104, 97, 115, 116, 46, 116, 105, 116, 108, 101, 34, 125, 68, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 103, 104, 97, 115, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 253, 6, 1, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 64, 224, 0, 0, 1, 1, 17, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 103, 104, 97, 115, 116, 1, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 112, 97, 114, 114, 111, 116, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 63, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 112, 97, 114, 114, 111, 116, 46, 116, 105, 116, 108, 101, 34, 125, 69, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 112, 97, 114, 114, 111, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 253, 6, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 64, 224, 0, 0, 1, 1, 18, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 112, 97, 114, 114, 111, 116, 1, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 117, 109, 109, 111, 110, 95, 105, 114, 111, 110, 95, 103, 111, 108, 101, 109, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 97, 100, 101, 1, 62, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 117, 109, 109, 111, 110, 95, 105, 114, 111, 110, 95, 103, 111, 108, 101, 109, 46, 116, 105, 116, 108, 101, 34, 125, 68, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 115, 117, 109, 109, 111, 110, 95, 105, 114, 111, 110, 95, 103, 111, 108, 101, 109, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 173, 2, 1, 0, 2, 0, 0, 0, 2, 64, 0, 0, 0, 65, 0, 0, 0, 1, 1, 14, 115, 117, 109, 109, 111, 110, 101, 100, 95, 103, 111, 108, 101, 109, 1, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 104, 114, 111, 119, 95, 116, 114, 105, 100, 101, 110, 116, 1, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 1, 58, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 104, 114, 111, 119, 95, 116, 114, 105, 100, 101, 110, 116, 46, 116, 105, 116, 108, 101, 34, 125, 64, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 104, 114, 111, 119, 95, 116, 114, 105, 100, 101, 110, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 243, 8, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 65, 88, 0, 0, 1, 1, 12, 115, 104, 111, 116, 95, 116, 114, 105, 100, 101, 110, 116, 1, 36, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 111, 116, 101, 109, 95, 111, 102, 95, 117, 110, 100, 121, 105, 110, 103, 1, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 1, 61, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 111, 116, 101, 109, 95, 111, 102, 95, 117, 110, 100, 121, 105, 110, 103, 46, 116, 105, 116, 108, 101, 34, 125, 67, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 111, 116, 101, 109, 95, 111, 102, 95, 117, 110, 100, 121, 105, 110, 103, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 221, 8, 1, 0, 2, 0, 0, 0, 2, 64, 0, 0, 0, 65, 104, 0, 0, 1, 1, 10, 117, 115, 101, 100, 95, 116, 111, 116, 101, 109, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 97, 100, 101, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 50, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 97, 100, 101, 46, 116, 105, 116, 108, 101, 34, 125, 56, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 97, 100, 101, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 253, 5, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 65, 8, 0, 0, 1, 1, 6, 116, 114, 97, 100, 101, 100, 1, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 97, 100, 101, 95, 97, 116, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 1, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 97, 100, 101, 1, 66, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 97, 100, 101, 95, 97, 116, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 46, 116, 105, 116, 108, 101, 34, 125, 72, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 97, 100, 101, 95, 97, 116, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 253, 5, 1, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 65, 16, 0, 0, 1, 1, 21, 116, 114, 97, 100, 101, 95, 97, 116, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 1, 58, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 108, 108, 95, 101, 120, 99, 108, 117, 115, 105, 118, 101, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 115, 1, 47, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 110, 121, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 1, 83, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 108, 108, 95, 101, 120, 99, 108, 117, 115, 105, 118, 101, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 115, 46, 116, 105, 116, 108, 101, 34, 125, 89, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 108, 108, 95, 101, 120, 99, 108, 117, 115, 105, 118, 101, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 115, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 208, 9, 1, 0, 1, 0, 0, 0, 2, 64, 0, 0, 0, 63, 128, 0, 0, 8, 1, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 114, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 117, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 105, 98, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 114, 100, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 74, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 101, 110, 99, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 105, 100, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 76, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 121, 102, 105, 110, 100, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 47, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 110, 121, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 1, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 1, 72, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 110, 121, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 46, 116, 105, 116, 108, 101, 34, 125, 78, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 110, 121, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 196, 9, 1, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 63, 128, 0, 0, 1, 16, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 105, 100, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 114, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 114, 100, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 111, 115, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 117, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 74, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 101, 110, 99, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 76, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 121, 102, 105, 110, 100, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 121, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 105, 98, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 73, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 101, 110, 116, 114, 121, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 111, 97, 115, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 73, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 97, 105, 115, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 108, 100, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 73, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 104, 97, 112, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 117, 110, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 1, 39, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 119, 111, 95, 98, 105, 114, 100, 115, 95, 111, 110, 101, 95, 97, 114, 114, 111, 119, 1, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 111, 108, 95, 98, 101, 116, 115, 121, 1, 64, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 119, 111, 95, 98, 105, 114, 100, 115, 95, 111, 110, 101, 95, 97, 114, 114, 111, 119, 46, 116, 105, 116, 108, 101, 34, 125, 70, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 116, 119, 111, 95, 98, 105, 114, 100, 115, 95, 111, 110, 101, 95, 97, 114, 114, 111, 119, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 247, 8, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 64, 0, 0, 0, 64, 128, 0, 0, 1, 1, 9, 116, 119, 111, 95, 98, 105, 114, 100, 115, 1, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 118, 101, 114, 121, 95, 118, 101, 114, 121, 95, 102, 114, 105, 103, 104, 116, 101, 110, 105, 110, 103, 1, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 104, 114, 111, 119, 95, 116, 114, 105, 100, 101, 110, 116, 1, 66, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 118, 101, 114, 121, 95, 118, 101, 114, 121, 95, 102, 114, 105, 103, 104, 116, 101, 110, 105, 110, 103, 46, 116, 105, 116, 108, 101, 34, 125, 72, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 118, 101, 114, 121, 95, 118, 101, 114, 121, 95, 102, 114, 105, 103, 104, 116, 101, 110, 105, 110, 103, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 243, 8, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 64, 0, 0, 65, 88, 0, 0, 1, 1, 15, 115, 116, 114, 117, 99, 107, 95, 118, 105, 108, 108, 97, 103, 101, 114, 1, 58, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 119, 97, 108, 107, 95, 111, 110, 95, 112, 111, 119, 100, 101, 114, 95, 115, 110, 111, 119, 95, 119, 105, 116, 104, 95, 108, 101, 97, 116, 104, 101, 114, 95, 98, 111, 111, 116, 115, 1, 32, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 108, 101, 101, 112, 95, 105, 110, 95, 98, 101, 100, 1, 83, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 119, 97, 108, 107, 95, 111, 110, 95, 112, 111, 119, 100, 101, 114, 95, 115, 110, 111, 119, 95, 119, 105, 116, 104, 95, 108, 101, 97, 116, 104, 101, 114, 95, 98, 111, 111, 116, 115, 46, 116, 105, 116, 108, 101, 34, 125, 89, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 119, 97, 108, 107, 95, 111, 110, 95, 112, 111, 119, 100, 101, 114, 95, 115, 110, 111, 119, 95, 119, 105, 116, 104, 95, 108, 101, 97, 116, 104, 101, 114, 95, 98, 111, 111, 116, 115, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 179, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 65, 132, 0, 0, 1, 1, 38, 119, 97, 108, 107, 95, 111, 110, 95, 112, 111, 119, 100, 101, 114, 95, 115, 110, 111, 119, 95, 119, 105, 116, 104, 95, 108, 101, 97, 116, 104, 101, 114, 95, 98, 111, 111, 116, 115, 1, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 119, 104, 111, 115, 95, 116, 104, 101, 95, 112, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 119, 1, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 111, 108, 95, 98, 101, 116, 115, 121, 1, 66, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 119, 104, 111, 115, 95, 116, 104, 101, 95, 112, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 119, 46, 116, 105, 116, 108, 101, 34, 125, 72, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 97, 100, 118, 101, 110, 116, 117, 114, 101, 46, 119, 104, 111, 115, 95, 116, 104, 101, 95, 112, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 119, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 247, 8, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 64, 160, 0, 0, 1, 1, 13, 107, 105, 108, 108, 95, 112, 105, 108, 108, 97, 103, 101, 114, 1, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 97, 99, 107, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 97, 99, 107, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 117, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 117, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 114, 111, 119, 110, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 114, 111, 119, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 99, 121, 97, 110, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 99, 121, 97, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 39, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 108, 97, 115, 115, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 19, 104, 97, 115, 95, 115, 109, 101, 108, 116, 115, 95, 116, 111, 95, 103, 108, 97, 115, 115, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 97, 121, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 97, 121, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 101, 101, 110, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 101, 101, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 60, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 98, 108, 117, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 98, 108, 117, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 60, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 103, 114, 97, 121, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 103, 114, 97, 121, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 109, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 109, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 57, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 109, 97, 103, 101, 110, 116, 97, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 52, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 109, 97, 103, 101, 110, 116, 97, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 56, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 111, 114, 97, 110, 103, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 111, 114, 97, 110, 103, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 105, 110, 107, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 105, 110, 107, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 56, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 117, 114, 112, 108, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 117, 114, 112, 108, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 53, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 114, 101, 100, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 47, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 114, 101, 100, 95, 115, 97, 110, 100, 115, 116, 111, 110, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 0, 48, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 114, 101, 100, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 43, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 115, 97, 110, 100, 115, 116, 111, 110, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 119, 104, 105, 116, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 119, 104, 105, 116, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 56, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 121, 101, 108, 108, 111, 119, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 104, 97, 115, 95, 115, 97, 110, 100, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 121, 101, 108, 108, 111, 119, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 99, 111, 109, 98, 97, 116, 47, 119, 111, 111, 100, 101, 110, 95, 115, 119, 111, 114, 100, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 0, 53, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 98, 114, 111, 119, 110, 95, 103, 108, 97, 122, 101, 100, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 20, 104, 97, 115, 95, 98, 114, 111, 119, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 99, 97, 109, 112, 102, 105, 114, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 3, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 8, 104, 97, 115, 95, 99, 111, 97, 108, 0, 35, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 99, 104, 101, 115, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 17, 104, 97, 115, 95, 108, 111, 116, 115, 95, 111, 102, 95, 105, 116, 101, 109, 115, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 99, 114, 97, 102, 116, 105, 110, 103, 95, 116, 97, 98, 108, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 17, 117, 110, 108, 111, 99, 107, 95, 114, 105, 103, 104, 116, 95, 97, 119, 97, 121, 0, 36, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 108, 97, 100, 100, 101, 114, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 121, 101, 108, 108, 111, 119, 95, 103, 108, 97, 122, 101, 100, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 21, 104, 97, 115, 95, 121, 101, 108, 108, 111, 119, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 0, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 102, 111, 111, 100, 47, 99, 111, 111, 107, 101, 100, 95, 112, 111, 114, 107, 99, 104, 111, 112, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 12, 104, 97, 115, 95, 112, 111, 114, 107, 99, 104, 111, 112, 0, 60, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 102, 111, 111, 100, 47, 99, 111, 111, 107, 101, 100, 95, 112, 111, 114, 107, 99, 104, 111, 112, 95, 102, 114, 111, 109, 95, 99, 97, 109, 112, 102, 105, 114, 101, 95, 99, 111, 111, 107, 105, 110, 103, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 12, 104, 97, 115, 95, 112, 111, 114, 107, 99, 104, 111, 112, 0, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 102, 111, 111, 100, 47, 99, 111, 111, 107, 101, 100, 95, 112, 111, 114, 107, 99, 104, 111, 112, 95, 102, 114, 111, 109, 95, 115, 109, 111, 107, 105, 110, 103, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 12, 104, 97, 115, 95, 112, 111, 114, 107, 99, 104, 111, 112, 0, 45, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 109, 105, 115, 99, 47, 102, 105, 114, 101, 119, 111, 114, 107, 95, 114, 111, 99, 107, 101, 116, 95, 115, 105, 109, 112, 108, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 13, 104, 97, 115, 95, 103, 117, 110, 112, 111, 119, 100, 101, 114, 0, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 101, 100, 115, 116, 111, 110, 101, 47, 116, 110, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 13, 104, 97, 115, 95, 103, 117, 110, 112, 111, 119, 100, 101, 114, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 0, 1, 1, 10, 105, 109, 112, 111, 115, 115, 105, 98, 108, 101, 0, 34, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 97, 120, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 0, 34, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 104, 111, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 0, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 112, 105, 99, 107, 97, 120, 101, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 0, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 115, 104, 111, 118, 101, 108, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 97, 99, 97, 99, 105, 97, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 98, 97, 109, 98, 111, 111, 95, 114, 97, 102, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 43, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 98, 105, 114, 99, 104, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 99, 104, 101, 114, 114, 121, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 46, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 100, 97, 114, 107, 95, 111, 97, 107, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 106, 117, 110, 103, 108, 101, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 46, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 109, 97, 110, 103, 114, 111, 118, 101, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 111, 97, 107, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 115, 112, 114, 117, 99, 101, 95, 98, 111, 97, 116, 1, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 0, 1, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 8, 105, 110, 95, 119, 97, 116, 101, 114, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 105, 114, 111, 110, 95, 116, 111, 111, 108, 115, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 115, 109, 101, 108, 116, 95, 105, 114, 111, 110, 1, 51, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 105, 114, 111, 110, 95, 116, 111, 111, 108, 115, 46, 116, 105, 116, 108, 101, 34, 125, 57, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 105, 114, 111, 110, 95, 116, 111, 111, 108, 115, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 154, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 128, 0, 0, 63, 128, 0, 0, 1, 1, 12, 105, 114, 111, 110, 95, 112, 105, 99, 107, 97, 120, 101, 1, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 109, 105, 110, 101, 95, 100, 105, 97, 109, 111, 110, 100, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 105, 114, 111, 110, 95, 116, 111, 111, 108, 115, 1, 53, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 109, 105, 110, 101, 95, 100, 105, 97, 109, 111, 110, 100, 46, 116, 105, 116, 108, 101, 34, 125, 59, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 109, 105, 110, 101, 95, 100, 105, 97, 109, 111, 110, 100, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 252, 5, 1, 0, 0, 0, 0, 0, 2, 64, 160, 0, 0, 63, 128, 0, 0, 1, 1, 7, 100, 105, 97, 109, 111, 110, 100, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 109, 105, 110, 101, 95, 115, 116, 111, 110, 101, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 114, 111, 111, 116, 1, 51, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 109, 105, 110, 101, 95, 115, 116, 111, 110, 101, 46, 116, 105, 116, 108, 101, 34, 125, 57, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 109, 105, 110, 101, 95, 115, 116, 111, 110, 101, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 139, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 63, 128, 0, 0, 63, 160, 0, 0, 1, 1, 9, 103, 101, 116, 95, 115, 116, 111, 110, 101, 1, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 114, 111, 111, 116, 0, 1, 45, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 114, 111, 111, 116, 46, 116, 105, 116, 108, 101, 34, 125, 51, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 114, 111, 111, 116, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 14, 1, 0, 0, 0, 0, 0, 1, 57, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 101, 120, 116, 117, 114, 101, 115, 47, 103, 117, 105, 47, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 47, 98, 97, 99, 107, 103, 114, 111, 117, 110, 100, 115, 47, 115, 116, 111, 110, 101, 46, 112, 110, 103, 0, 0, 0, 0, 63, 160, 0, 0, 1, 1, 14, 99, 114, 97, 102, 116, 105, 110, 103, 95, 116, 97, 98, 108, 101, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 115, 104, 105, 110, 121, 95, 103, 101, 97, 114, 1, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 109, 105, 110, 101, 95, 100, 105, 97, 109, 111, 110, 100, 1, 51, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 115, 104, 105, 110, 121, 95, 103, 101, 97, 114, 46, 116, 105, 116, 108, 101, 34, 125, 57, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 115, 104, 105, 110, 121, 95, 103, 101, 97, 114, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 189, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 192, 0, 0, 63, 0, 0, 0, 1, 4, 14, 100, 105, 97, 109, 111, 110, 100, 95, 104, 101, 108, 109, 101, 116, 18, 100, 105, 97, 109, 111, 110, 100, 95, 99, 104, 101, 115, 116, 112, 108, 97, 116, 101, 16, 100, 105, 97, 109, 111, 110, 100, 95, 108, 101, 103, 103, 105, 110, 103, 115, 13, 100, 105, 97, 109, 111, 110, 100, 95, 98, 111, 111, 116, 115, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 115, 109, 101, 108, 116, 95, 105, 114, 111, 110, 1, 29, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 117, 112, 103, 114, 97, 100, 101, 95, 116, 111, 111, 108, 115, 1, 51, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 115, 109, 101, 108, 116, 95, 105, 114, 111, 110, 46, 116, 105, 116, 108, 101, 34, 125, 57, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 115, 109, 101, 108, 116, 95, 105, 114, 111, 110, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 130, 6, 1, 0, 0, 0, 0, 0, 2, 64, 64, 0, 0, 63, 160, 0, 0, 1, 1, 4, 105, 114, 111, 110, 1, 29, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 117, 112, 103, 114, 97, 100, 101, 95, 116, 111, 111, 108, 115, 1, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 109, 105, 110, 101, 95, 115, 116, 111, 110, 101, 1, 54, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 117, 112, 103, 114, 97, 100, 101, 95, 116, 111, 111, 108, 115, 46, 116, 105, 116, 108, 101, 34, 125, 60, 123, 34, 116, 114, 97, 110, 115, 108, 97, 116, 101, 34, 58, 34, 97, 100, 118, 97, 110, 99, 101, 109, 101, 110, 116, 115, 46, 115, 116, 111, 114, 121, 46, 117, 112, 103, 114, 97, 100, 101, 95, 116, 111, 111, 108, 115, 46, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 34, 125, 1, 144, 6, 1, 10, 3, 0, 6, 68, 97, 109, 97, 103, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 64, 0, 0, 0, 63, 160, 0, 0, 1, 1, 13, 115, 116, 111, 110, 101, 95, 112, 105, 99, 107, 97, 120, 101, 1, 0, 99, 36, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 97, 100, 118, 101, 110, 116, 117, 114, 105, 110, 103, 95, 116, 105, 109, 101, 53, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 97, 100, 108, 97, 110, 100, 115, 1, 0, 0, 1, 139, 130, 217, 213, 160, 23, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 97, 109, 98, 111, 111, 95, 106, 117, 110, 103, 108, 101, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 101, 97, 99, 104, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 105, 114, 99, 104, 95, 102, 111, 114, 101, 115, 116, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 104, 101, 114, 114, 121, 95, 103, 114, 111, 118, 101, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 111, 108, 100, 95, 111, 99, 101, 97, 110, 0, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 97, 114, 107, 95, 102, 111, 114, 101, 115, 116, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 99, 111, 108, 100, 95, 111, 99, 101, 97, 110, 0, 19, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 100, 97, 114, 107, 0, 27, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 102, 114, 111, 122, 101, 110, 95, 111, 99, 101, 97, 110, 0, 29, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 108, 117, 107, 101, 119, 97, 114, 109, 95, 111, 99, 101, 97, 110, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 101, 112, 95, 111, 99, 101, 97, 110, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 101, 115, 101, 114, 116, 1, 0, 0, 1, 139, 130, 217, 186, 72, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 114, 105, 112, 115, 116, 111, 110, 101, 95, 99, 97, 118, 101, 115, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 114, 111, 100, 101, 100, 95, 98, 97, 100, 108, 97, 110, 100, 115, 1, 0, 0, 1, 139, 120, 164, 254, 232, 23, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 108, 111, 119, 101, 114, 95, 102, 111, 114, 101, 115, 116, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 111, 114, 101, 115, 116, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 114, 111, 122, 101, 110, 95, 111, 99, 101, 97, 110, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 114, 111, 122, 101, 110, 95, 112, 101, 97, 107, 115, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 102, 114, 111, 122, 101, 110, 95, 114, 105, 118, 101, 114, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 114, 111, 118, 101, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 105, 99, 101, 95, 115, 112, 105, 107, 101, 115, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 106, 97, 103, 103, 101, 100, 95, 112, 101, 97, 107, 115, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 106, 117, 110, 103, 108, 101, 0, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 108, 117, 107, 101, 119, 97, 114, 109, 95, 111, 99, 101, 97, 110, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 108, 117, 115, 104, 95, 99, 97, 118, 101, 115, 0, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 97, 110, 103, 114, 111, 118, 101, 95, 115, 119, 97, 109, 112, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 101, 97, 100, 111, 119, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 117, 115, 104, 114, 111, 111, 109, 95, 102, 105, 101, 108, 100, 115, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 99, 101, 97, 110, 0, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 108, 100, 95, 103, 114, 111, 119, 116, 104, 95, 98, 105, 114, 99, 104, 95, 102, 111, 114, 101, 115, 116, 0, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 108, 100, 95, 103, 114, 111, 119, 116, 104, 95, 112, 105, 110, 101, 95, 116, 97, 105, 103, 97, 0, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 111, 108, 100, 95, 103, 114, 111, 119, 116, 104, 95, 115, 112, 114, 117, 99, 101, 95, 116, 97, 105, 103, 97, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 108, 97, 105, 110, 115, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 105, 118, 101, 114, 1, 0, 0, 1, 139, 126, 9, 199, 96, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 97, 118, 97, 110, 110, 97, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 97, 118, 97, 110, 110, 97, 95, 112, 108, 97, 116, 101, 97, 117, 0, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 98, 101, 97, 99, 104, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 112, 108, 97, 105, 110, 115, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 115, 108, 111, 112, 101, 115, 0, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 119, 121, 95, 116, 97, 105, 103, 97, 0, 23, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 97, 114, 115, 101, 95, 106, 117, 110, 103, 108, 101, 0, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 110, 121, 95, 112, 101, 97, 107, 115, 0, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 110, 121, 95, 115, 104, 111, 114, 101, 1, 0, 0, 1, 139, 126, 9, 191, 144, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 117, 110, 102, 108, 111, 119, 101, 114, 95, 112, 108, 97, 105, 110, 115, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 119, 97, 109, 112, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 97, 105, 103, 97, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 114, 109, 95, 111, 99, 101, 97, 110, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 102, 111, 114, 101, 115, 116, 0, 34, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 103, 114, 97, 118, 101, 108, 108, 121, 95, 104, 105, 108, 108, 115, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 104, 105, 108, 108, 115, 0, 27, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 110, 100, 115, 119, 101, 112, 116, 95, 115, 97, 118, 97, 110, 110, 97, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 111, 111, 100, 101, 100, 95, 98, 97, 100, 108, 97, 110, 100, 115, 1, 0, 0, 1, 139, 130, 220, 35, 120, 35, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 97, 118, 111, 105, 100, 95, 118, 105, 98, 114, 97, 116, 105, 111, 110, 1, 15, 97, 118, 111, 105, 100, 95, 118, 105, 98, 114, 97, 116, 105, 111, 110, 0, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 98, 117, 108, 108, 115, 101, 121, 101, 1, 8, 98, 117, 108, 108, 115, 101, 121, 101, 0, 57, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 99, 114, 97, 102, 116, 95, 100, 101, 99, 111, 114, 97, 116, 101, 100, 95, 112, 111, 116, 95, 117, 115, 105, 110, 103, 95, 111, 110, 108, 121, 95, 115, 104, 101, 114, 100, 115, 1, 29, 112, 111, 116, 95, 99, 114, 97, 102, 116, 101, 100, 95, 117, 115, 105, 110, 103, 95, 111, 110, 108, 121, 95, 115, 104, 101, 114, 100, 115, 0, 42, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 102, 97, 108, 108, 95, 102, 114, 111, 109, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 1, 22, 102, 97, 108, 108, 95, 102, 114, 111, 109, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 0, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 104, 111, 110, 101, 121, 95, 98, 108, 111, 99, 107, 95, 115, 108, 105, 100, 101, 1, 17, 104, 111, 110, 101, 121, 95, 98, 108, 111, 99, 107, 95, 115, 108, 105, 100, 101, 0, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 95, 109, 111, 98, 34, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 108, 97, 122, 101, 0, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 97, 118, 101, 95, 115, 112, 105, 100, 101, 114, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 114, 101, 101, 112, 101, 114, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 114, 111, 119, 110, 101, 100, 0, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 108, 100, 101, 114, 95, 103, 117, 97, 114, 100, 105, 97, 110, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 95, 100, 114, 97, 103, 111, 110, 0, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 97, 110, 0, 19, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 105, 116, 101, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 118, 111, 107, 101, 114, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 104, 97, 115, 116, 0, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 117, 97, 114, 100, 105, 97, 110, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 111, 103, 108, 105, 110, 0, 14, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 117, 115, 107, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 97, 103, 109, 97, 95, 99, 117, 98, 101, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 104, 97, 110, 116, 111, 109, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 95, 98, 114, 117, 116, 101, 0, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 108, 108, 97, 103, 101, 114, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 97, 118, 97, 103, 101, 114, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 104, 117, 108, 107, 101, 114, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 118, 101, 114, 102, 105, 115, 104, 0, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 107, 101, 108, 101, 116, 111, 110, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 108, 105, 109, 101, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 100, 101, 114, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 114, 97, 121, 0, 13, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 105, 110, 100, 105, 99, 97, 116, 111, 114, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 99, 104, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 1, 0, 0, 1, 139, 131, 98, 204, 32, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 95, 115, 107, 101, 108, 101, 116, 111, 110, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 103, 108, 105, 110, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 95, 118, 105, 108, 108, 97, 103, 101, 114, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 102, 105, 101, 100, 95, 112, 105, 103, 108, 105, 110, 0, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 97, 108, 108, 95, 109, 111, 98, 115, 34, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 98, 108, 97, 122, 101, 0, 21, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 97, 118, 101, 95, 115, 112, 105, 100, 101, 114, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 114, 101, 101, 112, 101, 114, 1, 0, 0, 1, 139, 134, 123, 172, 176, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 114, 111, 119, 110, 101, 100, 0, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 108, 100, 101, 114, 95, 103, 117, 97, 114, 100, 105, 97, 110, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 95, 100, 114, 97, 103, 111, 110, 0, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 97, 110, 0, 19, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 110, 100, 101, 114, 109, 105, 116, 101, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 118, 111, 107, 101, 114, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 104, 97, 115, 116, 0, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 103, 117, 97, 114, 100, 105, 97, 110, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 111, 103, 108, 105, 110, 0, 14, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 117, 115, 107, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 109, 97, 103, 109, 97, 95, 99, 117, 98, 101, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 104, 97, 110, 116, 111, 109, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 103, 108, 105, 110, 95, 98, 114, 117, 116, 101, 0, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 112, 105, 108, 108, 97, 103, 101, 114, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 97, 118, 97, 103, 101, 114, 0, 17, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 104, 117, 108, 107, 101, 114, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 118, 101, 114, 102, 105, 115, 104, 0, 18, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 107, 101, 108, 101, 116, 111, 110, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 108, 105, 109, 101, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 100, 101, 114, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 114, 97, 121, 0, 13, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 105, 110, 100, 105, 99, 97, 116, 111, 114, 0, 15, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 99, 104, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 1, 0, 0, 1, 139, 131, 98, 204, 32, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 116, 104, 101, 114, 95, 115, 107, 101, 108, 101, 116, 111, 110, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 103, 108, 105, 110, 0, 16, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 101, 95, 118, 105, 108, 108, 97, 103, 101, 114, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 122, 111, 109, 98, 105, 102, 105, 101, 100, 95, 112, 105, 103, 108, 105, 110, 0, 48, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 107, 105, 108, 108, 95, 109, 111, 98, 95, 110, 101, 97, 114, 95, 115, 99, 117, 108, 107, 95, 99, 97, 116, 97, 108, 121, 115, 116, 1, 28, 107, 105, 108, 108, 95, 109, 111, 98, 95, 110, 101, 97, 114, 95, 115, 99, 117, 108, 107, 95, 99, 97, 116, 97, 108, 121, 115, 116, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 108, 105, 103, 104, 116, 110, 105, 110, 103, 95, 114, 111, 100, 95, 119, 105, 116, 104, 95, 118, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 95, 102, 105, 114, 101, 1, 35, 108, 105, 103, 104, 116, 110, 105, 110, 103, 95, 114, 111, 100, 95, 119, 105, 116, 104, 95, 118, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 95, 102, 105, 114, 101, 0, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 111, 108, 95, 98, 101, 116, 115, 121, 1, 13, 115, 104, 111, 116, 95, 99, 114, 111, 115, 115, 98, 111, 119, 0, 43, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 112, 108, 97, 121, 95, 106, 117, 107, 101, 98, 111, 120, 95, 105, 110, 95, 109, 101, 97, 100, 111, 119, 115, 1, 23, 112, 108, 97, 121, 95, 106, 117, 107, 101, 98, 111, 120, 95, 105, 110, 95, 109, 101, 97, 100, 111, 119, 115, 0, 52, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 101, 97, 100, 95, 112, 111, 119, 101, 114, 95, 111, 102, 95, 99, 104, 105, 115, 101, 108, 101, 100, 95, 98, 111, 111, 107, 115, 104, 101, 108, 102, 2, 18, 99, 104, 105, 115, 101, 108, 101, 100, 95, 98, 111, 111, 107, 115, 104, 101, 108, 102, 0, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 0, 24, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 114, 111, 111, 116, 2, 19, 107, 105, 108, 108, 101, 100, 95, 98, 121, 95, 115, 111, 109, 101, 116, 104, 105, 110, 103, 1, 0, 0, 1, 139, 126, 8, 123, 88, 16, 107, 105, 108, 108, 101, 100, 95, 115, 111, 109, 101, 116, 104, 105, 110, 103, 0, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 97, 108, 118, 97, 103, 101, 95, 115, 104, 101, 114, 100, 7, 14, 100, 101, 115, 101, 114, 116, 95, 112, 121, 114, 97, 109, 105, 100, 0, 11, 100, 101, 115, 101, 114, 116, 95, 119, 101, 108, 108, 0, 9, 104, 97, 115, 95, 115, 104, 101, 114, 100, 0, 15, 111, 99, 101, 97, 110, 95, 114, 117, 105, 110, 95, 99, 111, 108, 100, 0, 15, 111, 99, 101, 97, 110, 95, 114, 117, 105, 110, 95, 119, 97, 114, 109, 0, 18, 116, 114, 97, 105, 108, 95, 114, 117, 105, 110, 115, 95, 99, 111, 109, 109, 111, 110, 0, 16, 116, 114, 97, 105, 108, 95, 114, 117, 105, 110, 115, 95, 114, 97, 114, 101, 0, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 104, 111, 111, 116, 95, 97, 114, 114, 111, 119, 1, 10, 115, 104, 111, 116, 95, 97, 114, 114, 111, 119, 0, 32, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 108, 101, 101, 112, 95, 105, 110, 95, 98, 101, 100, 1, 12, 115, 108, 101, 112, 116, 95, 105, 110, 95, 98, 101, 100, 0, 31, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 110, 105, 112, 101, 114, 95, 100, 117, 101, 108, 1, 15, 107, 105, 108, 108, 101, 100, 95, 115, 107, 101, 108, 101, 116, 111, 110, 0, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 103, 104, 97, 115, 116, 1, 17, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 103, 104, 97, 115, 116, 0, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 112, 97, 114, 114, 111, 116, 1, 18, 115, 112, 121, 103, 108, 97, 115, 115, 95, 97, 116, 95, 112, 97, 114, 114, 111, 116, 0, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 115, 117, 109, 109, 111, 110, 95, 105, 114, 111, 110, 95, 103, 111, 108, 101, 109, 1, 14, 115, 117, 109, 109, 111, 110, 101, 100, 95, 103, 111, 108, 101, 109, 0, 33, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 104, 114, 111, 119, 95, 116, 114, 105, 100, 101, 110, 116, 1, 12, 115, 104, 111, 116, 95, 116, 114, 105, 100, 101, 110, 116, 0, 36, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 111, 116, 101, 109, 95, 111, 102, 95, 117, 110, 100, 121, 105, 110, 103, 1, 10, 117, 115, 101, 100, 95, 116, 111, 116, 101, 109, 0, 25, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 97, 100, 101, 1, 6, 116, 114, 97, 100, 101, 100, 0, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 97, 100, 101, 95, 97, 116, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 1, 21, 116, 114, 97, 100, 101, 95, 97, 116, 95, 119, 111, 114, 108, 100, 95, 104, 101, 105, 103, 104, 116, 0, 58, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 108, 108, 95, 101, 120, 99, 108, 117, 115, 105, 118, 101, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 115, 8, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 105, 98, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 74, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 101, 110, 99, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 117, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 114, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 105, 100, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 114, 100, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 76, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 121, 102, 105, 110, 100, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 47, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 114, 105, 109, 95, 119, 105, 116, 104, 95, 97, 110, 121, 95, 97, 114, 109, 111, 114, 95, 112, 97, 116, 116, 101, 114, 110, 16, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 99, 111, 97, 115, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 100, 117, 110, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 101, 121, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 104, 111, 115, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 73, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 97, 105, 115, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 105, 98, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 73, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 101, 110, 116, 114, 121, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 73, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 104, 97, 112, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 74, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 105, 108, 101, 110, 99, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 110, 111, 117, 116, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 72, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 112, 105, 114, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 116, 105, 100, 101, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 70, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 118, 101, 120, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 114, 100, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 76, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 97, 121, 102, 105, 110, 100, 101, 114, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 71, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 109, 101, 100, 95, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 119, 105, 108, 100, 95, 97, 114, 109, 111, 114, 95, 116, 114, 105, 109, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 101, 109, 112, 108, 97, 116, 101, 95, 115, 109, 105, 116, 104, 105, 110, 103, 95, 116, 114, 105, 109, 0, 39, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 116, 119, 111, 95, 98, 105, 114, 100, 115, 95, 111, 110, 101, 95, 97, 114, 114, 111, 119, 1, 9, 116, 119, 111, 95, 98, 105, 114, 100, 115, 0, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 118, 101, 114, 121, 95, 118, 101, 114, 121, 95, 102, 114, 105, 103, 104, 116, 101, 110, 105, 110, 103, 1, 15, 115, 116, 114, 117, 99, 107, 95, 118, 105, 108, 108, 97, 103, 101, 114, 0, 58, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 119, 97, 108, 107, 95, 111, 110, 95, 112, 111, 119, 100, 101, 114, 95, 115, 110, 111, 119, 95, 119, 105, 116, 104, 95, 108, 101, 97, 116, 104, 101, 114, 95, 98, 111, 111, 116, 115, 1, 38, 119, 97, 108, 107, 95, 111, 110, 95, 112, 111, 119, 100, 101, 114, 95, 115, 110, 111, 119, 95, 119, 105, 116, 104, 95, 108, 101, 97, 116, 104, 101, 114, 95, 98, 111, 111, 116, 115, 0, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 97, 100, 118, 101, 110, 116, 117, 114, 101, 47, 119, 104, 111, 115, 95, 116, 104, 101, 95, 112, 105, 108, 108, 97, 103, 101, 114, 95, 110, 111, 119, 1, 13, 107, 105, 108, 108, 95, 112, 105, 108, 108, 97, 103, 101, 114, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 97, 99, 107, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 97, 99, 107, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 117, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 108, 117, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 114, 111, 119, 110, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 98, 114, 111, 119, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 99, 121, 97, 110, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 99, 121, 97, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 39, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 108, 97, 115, 115, 2, 19, 104, 97, 115, 95, 115, 109, 101, 108, 116, 115, 95, 116, 111, 95, 103, 108, 97, 115, 115, 1, 0, 0, 1, 139, 125, 134, 176, 200, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 97, 121, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 97, 121, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 101, 101, 110, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 103, 114, 101, 101, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 60, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 98, 108, 117, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 98, 108, 117, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 60, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 103, 114, 97, 121, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 103, 104, 116, 95, 103, 114, 97, 121, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 109, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 108, 105, 109, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 57, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 109, 97, 103, 101, 110, 116, 97, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 52, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 109, 97, 103, 101, 110, 116, 97, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 56, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 111, 114, 97, 110, 103, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 111, 114, 97, 110, 103, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 105, 110, 107, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 49, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 105, 110, 107, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 56, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 117, 114, 112, 108, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 112, 117, 114, 112, 108, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 53, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 114, 101, 100, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 47, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 114, 101, 100, 95, 115, 97, 110, 100, 115, 116, 111, 110, 101, 2, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 125, 134, 176, 200, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 48, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 114, 101, 100, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 43, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 115, 97, 110, 100, 115, 116, 111, 110, 101, 2, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 55, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 119, 104, 105, 116, 101, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 50, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 119, 104, 105, 116, 101, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 56, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 121, 101, 108, 108, 111, 119, 95, 99, 111, 110, 99, 114, 101, 116, 101, 95, 112, 111, 119, 100, 101, 114, 3, 10, 104, 97, 115, 95, 103, 114, 97, 118, 101, 108, 0, 8, 104, 97, 115, 95, 115, 97, 110, 100, 1, 0, 0, 1, 139, 130, 217, 237, 16, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 98, 117, 105, 108, 100, 105, 110, 103, 95, 98, 108, 111, 99, 107, 115, 47, 121, 101, 108, 108, 111, 119, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 221, 220, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 99, 111, 109, 98, 97, 116, 47, 119, 111, 111, 100, 101, 110, 95, 115, 119, 111, 114, 100, 2, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 1, 0, 0, 1, 139, 126, 9, 136, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 53, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 98, 114, 111, 119, 110, 95, 103, 108, 97, 122, 101, 100, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 20, 104, 97, 115, 95, 98, 114, 111, 119, 110, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 131, 98, 126, 0, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 99, 97, 109, 112, 102, 105, 114, 101, 3, 8, 104, 97, 115, 95, 99, 111, 97, 108, 0, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 1, 0, 0, 1, 139, 126, 9, 136, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 35, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 99, 104, 101, 115, 116, 2, 17, 104, 97, 115, 95, 108, 111, 116, 115, 95, 111, 102, 95, 105, 116, 101, 109, 115, 1, 0, 0, 1, 139, 130, 234, 84, 72, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 99, 114, 97, 102, 116, 105, 110, 103, 95, 116, 97, 98, 108, 101, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 17, 117, 110, 108, 111, 99, 107, 95, 114, 105, 103, 104, 116, 95, 97, 119, 97, 121, 1, 0, 0, 1, 139, 120, 164, 251, 0, 36, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 108, 97, 100, 100, 101, 114, 2, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 1, 0, 0, 1, 139, 126, 9, 136, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 54, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 100, 101, 99, 111, 114, 97, 116, 105, 111, 110, 115, 47, 121, 101, 108, 108, 111, 119, 95, 103, 108, 97, 122, 101, 100, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 21, 104, 97, 115, 95, 121, 101, 108, 108, 111, 119, 95, 116, 101, 114, 114, 97, 99, 111, 116, 116, 97, 1, 0, 0, 1, 139, 130, 223, 204, 248, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 102, 111, 111, 100, 47, 99, 111, 111, 107, 101, 100, 95, 112, 111, 114, 107, 99, 104, 111, 112, 2, 12, 104, 97, 115, 95, 112, 111, 114, 107, 99, 104, 111, 112, 1, 0, 0, 1, 139, 130, 234, 224, 232, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 60, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 102, 111, 111, 100, 47, 99, 111, 111, 107, 101, 100, 95, 112, 111, 114, 107, 99, 104, 111, 112, 95, 102, 114, 111, 109, 95, 99, 97, 109, 112, 102, 105, 114, 101, 95, 99, 111, 111, 107, 105, 110, 103, 2, 12, 104, 97, 115, 95, 112, 111, 114, 107, 99, 104, 111, 112, 1, 0, 0, 1, 139, 130, 234, 224, 232, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 51, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 102, 111, 111, 100, 47, 99, 111, 111, 107, 101, 100, 95, 112, 111, 114, 107, 99, 104, 111, 112, 95, 102, 114, 111, 109, 95, 115, 109, 111, 107, 105, 110, 103, 2, 12, 104, 97, 115, 95, 112, 111, 114, 107, 99, 104, 111, 112, 1, 0, 0, 1, 139, 130, 234, 224, 232, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 45, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 109, 105, 115, 99, 47, 102, 105, 114, 101, 119, 111, 114, 107, 95, 114, 111, 99, 107, 101, 116, 95, 115, 105, 109, 112, 108, 101, 2, 13, 104, 97, 115, 95, 103, 117, 110, 112, 111, 119, 100, 101, 114, 1, 0, 0, 1, 139, 134, 123, 176, 152, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 30, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 101, 100, 115, 116, 111, 110, 101, 47, 116, 110, 116, 2, 13, 104, 97, 115, 95, 103, 117, 110, 112, 111, 119, 100, 101, 114, 1, 0, 0, 1, 139, 134, 123, 176, 152, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 22, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 114, 111, 111, 116, 1, 10, 105, 109, 112, 111, 115, 115, 105, 98, 108, 101, 0, 34, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 97, 120, 101, 2, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 1, 0, 0, 1, 139, 126, 9, 136, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 34, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 104, 111, 101, 2, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 1, 0, 0, 1, 139, 126, 9, 136, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 38, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 112, 105, 99, 107, 97, 120, 101, 2, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 1, 0, 0, 1, 139, 126, 9, 136, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 37, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 111, 111, 108, 115, 47, 119, 111, 111, 100, 101, 110, 95, 115, 104, 111, 118, 101, 108, 2, 9, 104, 97, 115, 95, 115, 116, 105, 99, 107, 1, 0, 0, 1, 139, 126, 9, 136, 224, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 97, 99, 97, 99, 105, 97, 95, 98, 111, 97, 116, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 98, 97, 109, 98, 111, 111, 95, 114, 97, 102, 116, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 43, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 98, 105, 114, 99, 104, 95, 98, 111, 97, 116, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 99, 104, 101, 114, 114, 121, 95, 98, 111, 97, 116, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 46, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 100, 97, 114, 107, 95, 111, 97, 107, 95, 98, 111, 97, 116, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 106, 117, 110, 103, 108, 101, 95, 98, 111, 97, 116, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 46, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 109, 97, 110, 103, 114, 111, 118, 101, 95, 98, 111, 97, 116, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 41, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 111, 97, 107, 95, 98, 111, 97, 116, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 44, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 114, 101, 99, 105, 112, 101, 115, 47, 116, 114, 97, 110, 115, 112, 111, 114, 116, 97, 116, 105, 111, 110, 47, 115, 112, 114, 117, 99, 101, 95, 98, 111, 97, 116, 2, 14, 104, 97, 115, 95, 116, 104, 101, 95, 114, 101, 99, 105, 112, 101, 0, 8, 105, 110, 95, 119, 97, 116, 101, 114, 1, 0, 0, 1, 139, 126, 8, 96, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 105, 114, 111, 110, 95, 116, 111, 111, 108, 115, 1, 12, 105, 114, 111, 110, 95, 112, 105, 99, 107, 97, 120, 101, 0, 28, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 109, 105, 110, 101, 95, 100, 105, 97, 109, 111, 110, 100, 1, 7, 100, 105, 97, 109, 111, 110, 100, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 109, 105, 110, 101, 95, 115, 116, 111, 110, 101, 1, 9, 103, 101, 116, 95, 115, 116, 111, 110, 101, 0, 20, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 114, 111, 111, 116, 1, 14, 99, 114, 97, 102, 116, 105, 110, 103, 95, 116, 97, 98, 108, 101, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 115, 104, 105, 110, 121, 95, 103, 101, 97, 114, 4, 13, 100, 105, 97, 109, 111, 110, 100, 95, 98, 111, 111, 116, 115, 0, 18, 100, 105, 97, 109, 111, 110, 100, 95, 99, 104, 101, 115, 116, 112, 108, 97, 116, 101, 1, 0, 0, 1, 139, 130, 234, 41, 80, 14, 100, 105, 97, 109, 111, 110, 100, 95, 104, 101, 108, 109, 101, 116, 0, 16, 100, 105, 97, 109, 111, 110, 100, 95, 108, 101, 103, 103, 105, 110, 103, 115, 0, 26, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 115, 109, 101, 108, 116, 95, 105, 114, 111, 110, 1, 4, 105, 114, 111, 110, 0, 29, 109, 105, 110, 101, 99, 114, 97, 102, 116, 58, 115, 116, 111, 114, 121, 47, 117, 112, 103, 114, 97, 100, 101, 95, 116, 111, 111, 108, 115, 1, 13, 115, 116, 111, 110, 101, 95, 112, 105, 99, 107, 97, 120, 101, 0];
     let packet_deserialized = ClientboundPacket::deserialize_uncompressed_minecraft_packet(input).unwrap();
 
     match packet_deserialized.serialize_minecraft_packet() {
         Ok(packet) => {
-            //reserialize let _reserialized = ClientboundPacket::deserialize_uncompressed_minecraft_packet(&packet).unwrap();
-            //reserialize assert!(matches!(ClientboundPacket::deserialize_uncompressed_minecraft_packet(input).unwrap(), _reserialized));
+            assert_eq!(packet, input);
+            let reparsed = ClientboundPacket::deserialize_uncompressed_minecraft_packet(&packet).unwrap();
+            assert_eq!(reparsed.serialize_minecraft_packet().unwrap(), packet);
+
+            let mut frame = Vec::new();
+            send_packet(&mut frame, packet.clone(), Some(0)).unwrap();
+            assert_eq!(read_packet(frame.as_slice(), Some(0)).unwrap(), packet);
         }
         Err(e) => panic!("Failed to serialize packet: {:?}", e),
     };
@@ -25,7 +25,7 @@ pub fn generate_entity_enum(entities: &Vec<Entity>, file: &mut File) {
 
     // Generate the code
     let code = format!(
-        r#"use minecraft_protocol::{{packets::VarInt, MinecraftPacketPart}};
+        r#"use minecraft_protocol::{{packets::VarInt, MinecraftPacketPart, ParseError}};
 
 /// See [implementations](#implementations) for useful methods.
 #[repr(u32)]
@@ -67,12 +67,17 @@ impl Entity {{
 
 impl<'a> MinecraftPacketPart<'a> for Entity {{
     #[inline]
-    fn serialize_minecraft_packet_part(self, output: &mut Vec<u8>) -> Result<(), &'static str> {{
-        VarInt((self as u32) as i32).serialize_minecraft_packet_part(output)
+    fn serialize_minecraft_packet_part(&self, output: &mut Vec<u8>) -> Result<(), &'static str> {{
+        VarInt((*self as u32) as i32).serialize_minecraft_packet_part(output)
     }}
 
     #[inline]
-    fn deserialize_minecraft_packet_part(input: &'a[u8]) -> Result<(Self, &'a[u8]), &'static str> {{
+    fn serialized_size(&self) -> usize {{
+        VarInt((*self as u32) as i32).serialized_size()
+    }}
+
+    #[inline]
+    fn deserialize_minecraft_packet_part(input: &'a[u8]) -> Result<(Self, &'a[u8]), ParseError> {{
         let (id, input) = VarInt::deserialize_minecraft_packet_part(input)?;
         let id = std::cmp::max(id.0, 0) as u32;
         let entity = Entity::from_id(id).ok_or("No entity corresponding to the specified numeric ID.")?;